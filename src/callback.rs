@@ -0,0 +1,18 @@
+//! Type erasure of Rust closures into callbacks for the C++ wrapper, shared by the modules
+//! whose algorithms evaluate a real function, e.g. integrators, root finders and minimizers.
+
+use crate::ffi;
+use core::ffi::c_void;
+
+/// Calls the closure that `f` points to; passed to the C++ wrapper as [`ffi::Function`].
+///
+/// A panic in the closure unwinds through the C++ algorithm, which `extern "C-unwind"` permits.
+unsafe extern "C-unwind" fn call<F: FnMut(f64) -> f64>(f: *mut c_void, x: f64) -> f64 {
+    let f = unsafe { &mut *f.cast::<F>() };
+    f(x)
+}
+
+/// Type-erases a closure into a callback and a context pointer for the C++ wrapper.
+pub(crate) fn erase<F: FnMut(f64) -> f64>(f: &mut F) -> (ffi::Function, *mut c_void) {
+    (call::<F>, (f as *mut F).cast())
+}
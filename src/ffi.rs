@@ -1,6 +1,9 @@
 //! Raw FFI declarations for wrapper.cpp
 
-use core::ffi::{c_int, c_uint};
+use core::ffi::{c_int, c_uint, c_void};
+use core::sync::atomic::AtomicBool;
+#[cfg(feature = "num-complex")]
+use num_complex::Complex;
 
 // ccmath
 unsafe extern "C-unwind" {
@@ -8,6 +11,650 @@ unsafe extern "C-unwind" {
     pub(crate) fn math_ccmath_sqrt(x: f64) -> f64;
 }
 
+// distributions
+unsafe extern "C-unwind" {
+    // boost/math/distributions/arcsine.hpp
+    pub(crate) fn math_arcsine_distribution(x_min: f64, x_max: f64, op: c_int, x: f64) -> f64;
+    // boost/math/distributions/bernoulli.hpp
+    pub(crate) fn math_bernoulli_distribution(p: f64, op: c_int, x: f64) -> f64;
+    // boost/math/distributions/beta.hpp
+    pub(crate) fn math_beta_distribution(alpha: f64, beta: f64, op: c_int, x: f64) -> f64;
+    // boost/math/distributions/binomial.hpp
+    pub(crate) fn math_binomial_distribution(
+        n: f64,
+        p: f64,
+        rounding: c_int,
+        op: c_int,
+        x: f64,
+    ) -> f64;
+    pub(crate) fn math_binomial_find_lower_bound_on_p(
+        trials: f64,
+        successes: f64,
+        alpha: f64,
+        interval: c_int,
+    ) -> f64;
+    pub(crate) fn math_binomial_find_upper_bound_on_p(
+        trials: f64,
+        successes: f64,
+        alpha: f64,
+        interval: c_int,
+    ) -> f64;
+    pub(crate) fn math_binomial_find_minimum_number_of_trials(k: f64, p: f64, alpha: f64) -> f64;
+    // boost/math/distributions/cauchy.hpp
+    pub(crate) fn math_cauchy_distribution(location: f64, scale: f64, op: c_int, x: f64) -> f64;
+    pub(crate) fn math_cauchy_find(op: c_int, z: f64, p: f64, param: f64) -> f64;
+    // boost/math/distributions/chi_squared.hpp
+    pub(crate) fn math_chi_squared_distribution(df: f64, op: c_int, x: f64) -> f64;
+    pub(crate) fn math_chi_squared_find_degrees_of_freedom(
+        difference_from_variance: f64,
+        alpha: f64,
+        beta: f64,
+        variance: f64,
+    ) -> f64;
+    // boost/math/distributions/empirical_cumulative_distribution_function.hpp
+    pub(crate) fn math_empirical_cumulative_distribution_function(
+        sorted: *const f64,
+        len: usize,
+        x: f64,
+    ) -> f64;
+    // boost/math/distributions/exponential.hpp
+    pub(crate) fn math_exponential_distribution(lambda: f64, op: c_int, x: f64) -> f64;
+    // boost/math/distributions/extreme_value.hpp
+    pub(crate) fn math_extreme_value_distribution(
+        location: f64,
+        scale: f64,
+        op: c_int,
+        x: f64,
+    ) -> f64;
+    pub(crate) fn math_extreme_value_find(op: c_int, z: f64, p: f64, param: f64) -> f64;
+    // boost/math/distributions/fisher_f.hpp
+    pub(crate) fn math_fisher_f_distribution(df1: f64, df2: f64, op: c_int, x: f64) -> f64;
+    // boost/math/distributions/gamma.hpp
+    pub(crate) fn math_gamma_distribution(shape: f64, scale: f64, op: c_int, x: f64) -> f64;
+    // boost/math/distributions/geometric.hpp
+    pub(crate) fn math_geometric_distribution(p: f64, rounding: c_int, op: c_int, x: f64) -> f64;
+    // boost/math/distributions/holtsmark.hpp
+    pub(crate) fn math_holtsmark_distribution(location: f64, scale: f64, op: c_int, x: f64) -> f64;
+    // boost/math/distributions/hyperexponential.hpp
+    pub(crate) fn math_hyperexponential_distribution(
+        probabilities: *const f64,
+        rates: *const f64,
+        len: usize,
+        op: c_int,
+        x: f64,
+    ) -> f64;
+    // boost/math/distributions/hypergeometric.hpp
+    pub(crate) fn math_hypergeometric_distribution(
+        r: c_uint,
+        n: c_uint,
+        total: c_uint,
+        rounding: c_int,
+        op: c_int,
+        x: f64,
+    ) -> f64;
+    // boost/math/distributions/inverse_chi_squared.hpp
+    pub(crate) fn math_inverse_chi_squared_distribution(
+        df: f64,
+        scale: f64,
+        op: c_int,
+        x: f64,
+    ) -> f64;
+    // boost/math/distributions/inverse_gamma.hpp
+    pub(crate) fn math_inverse_gamma_distribution(shape: f64, scale: f64, op: c_int, x: f64)
+    -> f64;
+    // boost/math/distributions/inverse_gaussian.hpp
+    pub(crate) fn math_inverse_gaussian_distribution(
+        mean: f64,
+        scale: f64,
+        op: c_int,
+        x: f64,
+    ) -> f64;
+    // boost/math/distributions/kolmogorov_smirnov.hpp
+    pub(crate) fn math_kolmogorov_smirnov_distribution(n: f64, op: c_int, x: f64) -> f64;
+    // boost/math/distributions/landau.hpp
+    pub(crate) fn math_landau_distribution(location: f64, scale: f64, op: c_int, x: f64) -> f64;
+    // boost/math/distributions/laplace.hpp
+    pub(crate) fn math_laplace_distribution(location: f64, scale: f64, op: c_int, x: f64) -> f64;
+    pub(crate) fn math_laplace_find(op: c_int, z: f64, p: f64, param: f64) -> f64;
+    // boost/math/distributions/logistic.hpp
+    pub(crate) fn math_logistic_distribution(location: f64, scale: f64, op: c_int, x: f64) -> f64;
+    pub(crate) fn math_logistic_find(op: c_int, z: f64, p: f64, param: f64) -> f64;
+    // boost/math/distributions/lognormal.hpp
+    pub(crate) fn math_lognormal_distribution(location: f64, scale: f64, op: c_int, x: f64) -> f64;
+    // boost/math/distributions/mapairy.hpp
+    pub(crate) fn math_mapairy_distribution(location: f64, scale: f64, op: c_int, x: f64) -> f64;
+    // boost/math/distributions/negative_binomial.hpp
+    pub(crate) fn math_negative_binomial_distribution(
+        r: f64,
+        p: f64,
+        rounding: c_int,
+        op: c_int,
+        x: f64,
+    ) -> f64;
+    pub(crate) fn math_negative_binomial_find_minimum_number_of_trials(
+        k: f64,
+        p: f64,
+        alpha: f64,
+    ) -> f64;
+    // boost/math/distributions/non_central_beta.hpp
+    pub(crate) fn math_non_central_beta_distribution(
+        alpha: f64,
+        beta: f64,
+        lambda: f64,
+        op: c_int,
+        x: f64,
+    ) -> f64;
+    // boost/math/distributions/non_central_chi_squared.hpp
+    pub(crate) fn math_non_central_chi_squared_distribution(
+        df: f64,
+        lambda: f64,
+        op: c_int,
+        x: f64,
+    ) -> f64;
+    pub(crate) fn math_non_central_chi_squared_find_degrees_of_freedom(
+        lambda: f64,
+        x: f64,
+        p: f64,
+    ) -> f64;
+    pub(crate) fn math_non_central_chi_squared_find_degrees_of_freedom_c(
+        lambda: f64,
+        x: f64,
+        q: f64,
+    ) -> f64;
+    pub(crate) fn math_non_central_chi_squared_find_non_centrality(df: f64, x: f64, p: f64) -> f64;
+    pub(crate) fn math_non_central_chi_squared_find_non_centrality_c(
+        df: f64,
+        x: f64,
+        q: f64,
+    ) -> f64;
+    // boost/math/distributions/non_central_f.hpp
+    pub(crate) fn math_non_central_f_distribution(
+        df1: f64,
+        df2: f64,
+        lambda: f64,
+        op: c_int,
+        x: f64,
+    ) -> f64;
+    // boost/math/distributions/non_central_t.hpp
+    pub(crate) fn math_non_central_t_distribution(df: f64, delta: f64, op: c_int, x: f64) -> f64;
+    // boost/math/distributions/normal.hpp
+    pub(crate) fn math_normal_distribution(mean: f64, sd: f64, op: c_int, x: f64) -> f64;
+    pub(crate) fn math_normal_find(op: c_int, z: f64, p: f64, param: f64) -> f64;
+    // boost/math/distributions/pareto.hpp
+    pub(crate) fn math_pareto_distribution(scale: f64, shape: f64, op: c_int, x: f64) -> f64;
+    // boost/math/distributions/poisson.hpp
+    pub(crate) fn math_poisson_distribution(lambda: f64, rounding: c_int, op: c_int, x: f64)
+    -> f64;
+    // boost/math/distributions/rayleigh.hpp
+    pub(crate) fn math_rayleigh_distribution(sigma: f64, op: c_int, x: f64) -> f64;
+    // boost/math/distributions/saspoint5.hpp
+    pub(crate) fn math_saspoint5_distribution(location: f64, scale: f64, op: c_int, x: f64) -> f64;
+    // boost/math/distributions/skew_normal.hpp
+    pub(crate) fn math_skew_normal_distribution(
+        location: f64,
+        scale: f64,
+        shape: f64,
+        op: c_int,
+        x: f64,
+    ) -> f64;
+    // boost/math/distributions/students_t.hpp
+    pub(crate) fn math_students_t_distribution(df: f64, op: c_int, x: f64) -> f64;
+    pub(crate) fn math_students_t_find_degrees_of_freedom(
+        difference_from_mean: f64,
+        alpha: f64,
+        beta: f64,
+        sd: f64,
+    ) -> f64;
+    // boost/math/distributions/triangular.hpp
+    pub(crate) fn math_triangular_distribution(
+        lower: f64,
+        mode: f64,
+        upper: f64,
+        op: c_int,
+        x: f64,
+    ) -> f64;
+    // boost/math/distributions/uniform.hpp
+    pub(crate) fn math_uniform_distribution(lower: f64, upper: f64, op: c_int, x: f64) -> f64;
+    // boost/math/distributions/weibull.hpp
+    pub(crate) fn math_weibull_distribution(shape: f64, scale: f64, op: c_int, x: f64) -> f64;
+}
+
+// interpolators
+unsafe extern "C-unwind" {
+    // boost/math/interpolators/barycentric_rational.hpp
+    pub(crate) fn math_barycentric_rational_new(
+        x: *const f64,
+        y: *const f64,
+        n: usize,
+        order: usize,
+    ) -> *mut c_void;
+    pub(crate) fn math_barycentric_rational_delete(interpolator: *mut c_void);
+    pub(crate) fn math_barycentric_rational(interpolator: *const c_void, x: f64) -> f64;
+    pub(crate) fn math_barycentric_rational_prime(interpolator: *const c_void, x: f64) -> f64;
+
+    // boost/math/interpolators/cardinal_cubic_b_spline.hpp
+    pub(crate) fn math_cardinal_cubic_b_spline_new(
+        y: *const f64,
+        n: usize,
+        t0: f64,
+        h: f64,
+        left_derivative: f64,
+        right_derivative: f64,
+    ) -> *mut c_void;
+    pub(crate) fn math_cardinal_cubic_b_spline_clone(interpolator: *const c_void) -> *mut c_void;
+    pub(crate) fn math_cardinal_cubic_b_spline_delete(interpolator: *mut c_void);
+    pub(crate) fn math_cardinal_cubic_b_spline(interpolator: *const c_void, t: f64) -> f64;
+    pub(crate) fn math_cardinal_cubic_b_spline_prime(interpolator: *const c_void, t: f64) -> f64;
+    pub(crate) fn math_cardinal_cubic_b_spline_double_prime(
+        interpolator: *const c_void,
+        t: f64,
+    ) -> f64;
+
+    // boost/math/interpolators/cardinal_quadratic_b_spline.hpp
+    pub(crate) fn math_cardinal_quadratic_b_spline_new(
+        y: *const f64,
+        n: usize,
+        t0: f64,
+        h: f64,
+        left_derivative: f64,
+        right_derivative: f64,
+    ) -> *mut c_void;
+    pub(crate) fn math_cardinal_quadratic_b_spline_clone(
+        interpolator: *const c_void,
+    ) -> *mut c_void;
+    pub(crate) fn math_cardinal_quadratic_b_spline_delete(interpolator: *mut c_void);
+    pub(crate) fn math_cardinal_quadratic_b_spline(interpolator: *const c_void, t: f64) -> f64;
+    pub(crate) fn math_cardinal_quadratic_b_spline_prime(
+        interpolator: *const c_void,
+        t: f64,
+    ) -> f64;
+
+    // boost/math/interpolators/cardinal_quintic_b_spline.hpp
+    pub(crate) fn math_cardinal_quintic_b_spline_new(
+        y: *const f64,
+        n: usize,
+        t0: f64,
+        h: f64,
+        left_prime: f64,
+        left_double_prime: f64,
+        right_prime: f64,
+        right_double_prime: f64,
+    ) -> *mut c_void;
+    pub(crate) fn math_cardinal_quintic_b_spline_clone(interpolator: *const c_void) -> *mut c_void;
+    pub(crate) fn math_cardinal_quintic_b_spline_delete(interpolator: *mut c_void);
+    pub(crate) fn math_cardinal_quintic_b_spline(interpolator: *const c_void, t: f64) -> f64;
+    pub(crate) fn math_cardinal_quintic_b_spline_prime(interpolator: *const c_void, t: f64) -> f64;
+    pub(crate) fn math_cardinal_quintic_b_spline_double_prime(
+        interpolator: *const c_void,
+        t: f64,
+    ) -> f64;
+}
+
+// optimization
+
+/// Objective function callback, called concurrently with the context pointer and the point
+///
+/// This is `extern "C"`, so that a panic in the objective aborts instead of unwinding into the
+/// worker threads of the C++ optimizers.
+pub(crate) type Objective = unsafe extern "C" fn(*const c_void, *const f64, usize) -> f64;
+
+unsafe extern "C-unwind" {
+    // boost/math/optimization/cma_es.hpp
+    pub(crate) fn math_cma_es(
+        f: Objective,
+        context: *const c_void,
+        lower: *const f64,
+        upper: *const f64,
+        dimension: usize,
+        population_size: usize,
+        max_generations: usize,
+        learning_rate: f64,
+        seed: u64,
+        target_value: f64,
+        cancellation: *const AtomicBool,
+        x: *mut f64,
+        value: *mut f64,
+        history: *mut f64,
+        history_len: usize,
+    ) -> usize;
+
+    // boost/math/optimization/differential_evolution.hpp
+    pub(crate) fn math_differential_evolution(
+        f: Objective,
+        context: *const c_void,
+        lower: *const f64,
+        upper: *const f64,
+        dimension: usize,
+        mutation_factor: f64,
+        crossover_probability: f64,
+        population_size: usize,
+        max_generations: usize,
+        threads: usize,
+        seed: u64,
+        target_value: f64,
+        cancellation: *const AtomicBool,
+        x: *mut f64,
+        value: *mut f64,
+        history: *mut f64,
+        history_len: usize,
+    ) -> usize;
+
+    // boost/math/optimization/jso.hpp
+    pub(crate) fn math_jso(
+        f: Objective,
+        context: *const c_void,
+        lower: *const f64,
+        upper: *const f64,
+        dimension: usize,
+        initial_population_size: usize,
+        max_function_evaluations: usize,
+        threads: usize,
+        seed: u64,
+        target_value: f64,
+        cancellation: *const AtomicBool,
+        x: *mut f64,
+        value: *mut f64,
+    );
+
+    // boost/math/optimization/random_search.hpp
+    pub(crate) fn math_random_search(
+        f: Objective,
+        context: *const c_void,
+        lower: *const f64,
+        upper: *const f64,
+        dimension: usize,
+        max_function_calls: usize,
+        threads: usize,
+        seed: u64,
+        target_value: f64,
+        cancellation: *const AtomicBool,
+        x: *mut f64,
+        value: *mut f64,
+    );
+}
+
+// quadrature
+
+/// Real function callback, e.g. an integrand, called with the context pointer and the argument
+pub(crate) type Function = unsafe extern "C-unwind" fn(*mut c_void, f64) -> f64;
+
+/// Complex-valued integrand callback, called with the context pointer and the abscissa, which
+/// writes the real and imaginary parts of the value to the out pointers
+#[cfg(feature = "num-complex")]
+pub(crate) type ComplexIntegrand =
+    unsafe extern "C-unwind" fn(*mut c_void, f64, *mut f64, *mut f64);
+
+/// Multivariate integrand callback, called concurrently with the context pointer and the point
+///
+/// This does not unwind, since it is called from the worker threads of the C++ integrator, so
+/// that a panic aborts the process.
+pub(crate) type MultivariateIntegrand =
+    unsafe extern "C" fn(*const c_void, *const f64, usize) -> f64;
+
+unsafe extern "C-unwind" {
+    // boost/math/quadrature/exp_sinh.hpp
+    pub(crate) fn math_exp_sinh_new(max_refinements: usize) -> *mut c_void;
+    pub(crate) fn math_exp_sinh_delete(integrator: *mut c_void);
+    pub(crate) fn math_exp_sinh(
+        integrator: *mut c_void,
+        f: Function,
+        context: *mut c_void,
+        a: f64,
+        b: f64,
+        tolerance: f64,
+        error: *mut f64,
+        l1_norm: *mut f64,
+        levels: *mut usize,
+    ) -> f64;
+    #[cfg(feature = "num-complex")]
+    pub(crate) fn math_exp_sinh_complex(
+        integrator: *mut c_void,
+        f: ComplexIntegrand,
+        context: *mut c_void,
+        a: f64,
+        b: f64,
+        tolerance: f64,
+        out_re: *mut f64,
+        out_im: *mut f64,
+        error: *mut f64,
+        l1_norm: *mut f64,
+        levels: *mut usize,
+    );
+
+    // boost/math/quadrature/gauss.hpp
+    pub(crate) fn math_gauss(
+        points: c_uint,
+        f: Function,
+        context: *mut c_void,
+        a: f64,
+        b: f64,
+        l1_norm: *mut f64,
+    ) -> f64;
+    pub(crate) fn math_gauss_abscissa(points: c_uint) -> *const f64;
+    pub(crate) fn math_gauss_weights(points: c_uint) -> *const f64;
+
+    // boost/math/quadrature/gauss_kronrod.hpp
+    pub(crate) fn math_gauss_kronrod(
+        points: c_uint,
+        f: Function,
+        context: *mut c_void,
+        a: f64,
+        b: f64,
+        max_depth: c_uint,
+        tolerance: f64,
+        error: *mut f64,
+        l1_norm: *mut f64,
+    ) -> f64;
+    pub(crate) fn math_gauss_kronrod_abscissa(points: c_uint) -> *const f64;
+    pub(crate) fn math_gauss_kronrod_weights(points: c_uint) -> *const f64;
+    pub(crate) fn math_gauss_kronrod_gauss_weights(points: c_uint) -> *const f64;
+
+    // boost/math/quadrature/naive_monte_carlo.hpp
+    pub(crate) fn math_naive_monte_carlo_new(
+        f: MultivariateIntegrand,
+        context: *const c_void,
+        lower: *const f64,
+        upper: *const f64,
+        dimension: usize,
+        error_goal: f64,
+        threads: usize,
+        seed: u64,
+    ) -> *mut c_void;
+    pub(crate) fn math_naive_monte_carlo_delete(integrator: *mut c_void);
+    pub(crate) fn math_naive_monte_carlo_integrate(integrator: *mut c_void) -> f64;
+    pub(crate) fn math_naive_monte_carlo_cancel(integrator: *mut c_void);
+    pub(crate) fn math_naive_monte_carlo_update_target_error(integrator: *mut c_void, error: f64);
+    pub(crate) fn math_naive_monte_carlo_current_estimate(integrator: *mut c_void) -> f64;
+    pub(crate) fn math_naive_monte_carlo_current_error_estimate(integrator: *mut c_void) -> f64;
+    pub(crate) fn math_naive_monte_carlo_progress(integrator: *mut c_void) -> f64;
+    pub(crate) fn math_naive_monte_carlo_calls(integrator: *mut c_void) -> u64;
+
+    // boost/math/quadrature/ooura_fourier_integrals.hpp
+    pub(crate) fn math_ooura_fourier_sin_new(tolerance: f64, levels: usize) -> *mut c_void;
+    pub(crate) fn math_ooura_fourier_sin_delete(integrator: *mut c_void);
+    pub(crate) fn math_ooura_fourier_sin(
+        integrator: *mut c_void,
+        f: Function,
+        context: *mut c_void,
+        omega: f64,
+        error: *mut f64,
+    ) -> f64;
+    pub(crate) fn math_ooura_fourier_cos_new(tolerance: f64, levels: usize) -> *mut c_void;
+    pub(crate) fn math_ooura_fourier_cos_delete(integrator: *mut c_void);
+    pub(crate) fn math_ooura_fourier_cos(
+        integrator: *mut c_void,
+        f: Function,
+        context: *mut c_void,
+        omega: f64,
+        error: *mut f64,
+    ) -> f64;
+
+    // boost/math/quadrature/sinh_sinh.hpp
+    pub(crate) fn math_sinh_sinh_new(max_refinements: usize) -> *mut c_void;
+    pub(crate) fn math_sinh_sinh_delete(integrator: *mut c_void);
+    pub(crate) fn math_sinh_sinh(
+        integrator: *mut c_void,
+        f: Function,
+        context: *mut c_void,
+        tolerance: f64,
+        error: *mut f64,
+        l1_norm: *mut f64,
+        levels: *mut usize,
+    ) -> f64;
+    #[cfg(feature = "num-complex")]
+    pub(crate) fn math_sinh_sinh_complex(
+        integrator: *mut c_void,
+        f: ComplexIntegrand,
+        context: *mut c_void,
+        tolerance: f64,
+        out_re: *mut f64,
+        out_im: *mut f64,
+        error: *mut f64,
+        l1_norm: *mut f64,
+        levels: *mut usize,
+    );
+
+    // boost/math/quadrature/tanh_sinh.hpp
+    pub(crate) fn math_tanh_sinh_new(max_refinements: usize) -> *mut c_void;
+    pub(crate) fn math_tanh_sinh_delete(integrator: *mut c_void);
+    pub(crate) fn math_tanh_sinh(
+        integrator: *mut c_void,
+        f: Function,
+        context: *mut c_void,
+        a: f64,
+        b: f64,
+        tolerance: f64,
+        error: *mut f64,
+        l1_norm: *mut f64,
+        levels: *mut usize,
+    ) -> f64;
+    #[cfg(feature = "num-complex")]
+    pub(crate) fn math_tanh_sinh_complex(
+        integrator: *mut c_void,
+        f: ComplexIntegrand,
+        context: *mut c_void,
+        a: f64,
+        b: f64,
+        tolerance: f64,
+        out_re: *mut f64,
+        out_im: *mut f64,
+        error: *mut f64,
+        l1_norm: *mut f64,
+        levels: *mut usize,
+    );
+
+    // boost/math/quadrature/trapezoidal.hpp
+    pub(crate) fn math_trapezoidal(
+        f: Function,
+        context: *mut c_void,
+        a: f64,
+        b: f64,
+        tolerance: f64,
+        max_refinements: usize,
+        error: *mut f64,
+        l1_norm: *mut f64,
+    ) -> f64;
+}
+
+// roots
+
+/// Callback of a function and its derivatives, called with the context pointer and the abscissa,
+/// which writes the function value and the derivatives to the out array
+pub(crate) type Derivatives = unsafe extern "C-unwind" fn(*mut c_void, f64, *mut f64);
+
+unsafe extern "C-unwind" {
+    // boost/math/tools/cubic_roots.hpp
+    pub(crate) fn math_cubic_roots(a: f64, b: f64, c: f64, d: f64, roots: *mut f64);
+    pub(crate) fn math_cubic_root_residual(
+        a: f64,
+        b: f64,
+        c: f64,
+        d: f64,
+        root: f64,
+        bound: *mut f64,
+    ) -> f64;
+    pub(crate) fn math_cubic_root_condition_number(
+        a: f64,
+        b: f64,
+        c: f64,
+        d: f64,
+        root: f64,
+    ) -> f64;
+
+    // boost/math/tools/minima.hpp
+    pub(crate) fn math_brent_find_minima(
+        f: Function,
+        context: *mut c_void,
+        min: f64,
+        max: f64,
+        bits: c_int,
+        max_iter: *mut usize,
+        value: *mut f64,
+    ) -> f64;
+
+    // boost/math/tools/quartic_roots.hpp
+    pub(crate) fn math_quartic_roots(a: f64, b: f64, c: f64, d: f64, e: f64, roots: *mut f64);
+
+    // boost/math/tools/roots.hpp
+    pub(crate) fn math_bisect(
+        f: Function,
+        context: *mut c_void,
+        min: f64,
+        max: f64,
+        bits: c_uint,
+        max_iter: *mut usize,
+        upper: *mut f64,
+    ) -> f64;
+    pub(crate) fn math_newton_raphson_iterate(
+        f: Derivatives,
+        context: *mut c_void,
+        guess: f64,
+        min: f64,
+        max: f64,
+        digits: c_int,
+        max_iter: *mut usize,
+    ) -> f64;
+    pub(crate) fn math_halley_iterate(
+        f: Derivatives,
+        context: *mut c_void,
+        guess: f64,
+        min: f64,
+        max: f64,
+        digits: c_int,
+        max_iter: *mut usize,
+    ) -> f64;
+    pub(crate) fn math_schroder_iterate(
+        f: Derivatives,
+        context: *mut c_void,
+        guess: f64,
+        min: f64,
+        max: f64,
+        digits: c_int,
+        max_iter: *mut usize,
+    ) -> f64;
+
+    // boost/math/tools/toms748_solve.hpp
+    pub(crate) fn math_toms748_solve(
+        f: Function,
+        context: *mut c_void,
+        a: f64,
+        b: f64,
+        bits: c_uint,
+        max_iter: *mut usize,
+        upper: *mut f64,
+    ) -> f64;
+    pub(crate) fn math_bracket_and_solve_root(
+        f: Function,
+        context: *mut c_void,
+        guess: f64,
+        factor: f64,
+        rising: bool,
+        bits: c_uint,
+        max_iter: *mut usize,
+        upper: *mut f64,
+    ) -> f64;
+}
+
 // special_functions
 unsafe extern "C-unwind" {
     // boost/math/special_functions/acosh.hpp
@@ -274,6 +921,142 @@ unsafe extern "C-unwind" {
     pub(crate) fn math_zeta(s: f64) -> f64;
 }
 
+// statistics
+unsafe extern "C-unwind" {
+    // boost/math/statistics/anderson_darling.hpp
+    pub(crate) fn math_anderson_darling_normality_statistic(
+        sorted: *const f64,
+        len: usize,
+        mu: f64,
+        sd: f64,
+    ) -> f64;
+
+    // boost/math/statistics/bivariate_statistics.hpp
+    pub(crate) fn math_covariance(u: *const f64, v: *const f64, len: usize) -> f64;
+    pub(crate) fn math_means_and_covariance(
+        u: *const f64,
+        v: *const f64,
+        len: usize,
+        mu_u: *mut f64,
+        mu_v: *mut f64,
+    ) -> f64;
+    pub(crate) fn math_correlation_coefficient(u: *const f64, v: *const f64, len: usize) -> f64;
+
+    // boost/math/statistics/chatterjee_correlation.hpp
+    pub(crate) fn math_chatterjee_correlation(u: *const f64, v: *const f64, len: usize) -> f64;
+
+    // boost/math/statistics/linear_regression.hpp
+    pub(crate) fn math_simple_ordinary_least_squares(
+        x: *const f64,
+        y: *const f64,
+        len: usize,
+        c1: *mut f64,
+    ) -> f64;
+    pub(crate) fn math_simple_ordinary_least_squares_with_R_squared(
+        x: *const f64,
+        y: *const f64,
+        len: usize,
+        c1: *mut f64,
+        r_squared: *mut f64,
+    ) -> f64;
+
+    // boost/math/statistics/ljung_box.hpp
+    pub(crate) fn math_ljung_box(
+        v: *const f64,
+        len: usize,
+        lags: i64,
+        fit_dof: i64,
+        p_value: *mut f64,
+    ) -> f64;
+
+    // boost/math/statistics/runs_test.hpp
+    pub(crate) fn math_runs_above_and_below_threshold(
+        v: *const f64,
+        len: usize,
+        threshold: f64,
+        p_value: *mut f64,
+    ) -> f64;
+    pub(crate) fn math_runs_above_and_below_median(
+        v: *const f64,
+        len: usize,
+        p_value: *mut f64,
+    ) -> f64;
+
+    // boost/math/statistics/signal_statistics.hpp
+    pub(crate) fn math_absolute_gini_coefficient(v: *mut f64, len: usize) -> f64;
+    pub(crate) fn math_sample_absolute_gini_coefficient(v: *mut f64, len: usize) -> f64;
+    pub(crate) fn math_hoyer_sparsity(v: *const f64, len: usize) -> f64;
+    pub(crate) fn math_oracle_snr(signal: *const f64, noisy_signal: *const f64, len: usize) -> f64;
+    pub(crate) fn math_oracle_snr_db(
+        signal: *const f64,
+        noisy_signal: *const f64,
+        len: usize,
+    ) -> f64;
+    pub(crate) fn math_m2m4_snr_estimator(
+        v: *const f64,
+        len: usize,
+        signal_kurtosis: f64,
+        noise_kurtosis: f64,
+    ) -> f64;
+    pub(crate) fn math_m2m4_snr_estimator_db(
+        v: *const f64,
+        len: usize,
+        signal_kurtosis: f64,
+        noise_kurtosis: f64,
+    ) -> f64;
+
+    // boost/math/statistics/t_test.hpp
+    pub(crate) fn math_one_sample_t_test(
+        v: *const f64,
+        len: usize,
+        assumed_mean: f64,
+        p_value: *mut f64,
+    ) -> f64;
+    pub(crate) fn math_two_sample_t_test(
+        u: *const f64,
+        u_len: usize,
+        v: *const f64,
+        v_len: usize,
+        p_value: *mut f64,
+    ) -> f64;
+    pub(crate) fn math_paired_samples_t_test(
+        u: *const f64,
+        v: *const f64,
+        len: usize,
+        p_value: *mut f64,
+    ) -> f64;
+
+    // boost/math/statistics/univariate_statistics.hpp
+    pub(crate) fn math_mean(v: *const f64, len: usize) -> f64;
+    pub(crate) fn math_variance(v: *const f64, len: usize) -> f64;
+    pub(crate) fn math_sample_variance(v: *const f64, len: usize) -> f64;
+    pub(crate) fn math_mean_and_sample_variance(v: *const f64, len: usize, var: *mut f64) -> f64;
+    pub(crate) fn math_skewness(v: *const f64, len: usize) -> f64;
+    pub(crate) fn math_kurtosis(v: *const f64, len: usize) -> f64;
+    pub(crate) fn math_excess_kurtosis(v: *const f64, len: usize) -> f64;
+    pub(crate) fn math_median(v: *mut f64, len: usize) -> f64;
+    pub(crate) fn math_median_absolute_deviation(v: *mut f64, len: usize, center: f64) -> f64;
+    pub(crate) fn math_interquartile_range(v: *mut f64, len: usize) -> f64;
+    pub(crate) fn math_gini_coefficient(v: *mut f64, len: usize) -> f64;
+    pub(crate) fn math_sample_gini_coefficient(v: *mut f64, len: usize) -> f64;
+    pub(crate) fn math_mode(v: *mut f64, len: usize, out: *mut f64) -> usize;
+
+    // boost/math/statistics/z_test.hpp
+    pub(crate) fn math_one_sample_z_test(
+        v: *const f64,
+        len: usize,
+        assumed_mean: f64,
+        p_value: *mut f64,
+    ) -> f64;
+    pub(crate) fn math_two_sample_z_test(
+        u: *const f64,
+        u_len: usize,
+        v: *const f64,
+        v_len: usize,
+        p_value: *mut f64,
+    ) -> f64;
+}
+
 #[cfg(feature = "num-complex")]
 unsafe extern "C-unwind" {
     // boost/math/special_functions/hankel.hpp
@@ -281,4 +1064,34 @@ unsafe extern "C-unwind" {
     pub(crate) fn math_cyl_hankel_2(nu: f64, x: f64, out_re: *mut f64, out_im: *mut f64);
     pub(crate) fn math_sph_hankel_1(nu: f64, x: f64, out_re: *mut f64, out_im: *mut f64);
     pub(crate) fn math_sph_hankel_2(nu: f64, x: f64, out_re: *mut f64, out_im: *mut f64);
+
+    // boost/math/statistics/signal_statistics.hpp
+    pub(crate) fn math_absolute_gini_coefficient_complex(v: *mut Complex<f64>, len: usize) -> f64;
+    pub(crate) fn math_sample_absolute_gini_coefficient_complex(
+        v: *mut Complex<f64>,
+        len: usize,
+    ) -> f64;
+    pub(crate) fn math_hoyer_sparsity_complex(v: *const Complex<f64>, len: usize) -> f64;
+    pub(crate) fn math_oracle_snr_complex(
+        signal: *const Complex<f64>,
+        noisy_signal: *const Complex<f64>,
+        len: usize,
+    ) -> f64;
+    pub(crate) fn math_oracle_snr_db_complex(
+        signal: *const Complex<f64>,
+        noisy_signal: *const Complex<f64>,
+        len: usize,
+    ) -> f64;
+    pub(crate) fn math_m2m4_snr_estimator_complex(
+        v: *const Complex<f64>,
+        len: usize,
+        signal_kurtosis: f64,
+        noise_kurtosis: f64,
+    ) -> f64;
+    pub(crate) fn math_m2m4_snr_estimator_db_complex(
+        v: *const Complex<f64>,
+        len: usize,
+        signal_kurtosis: f64,
+        noise_kurtosis: f64,
+    ) -> f64;
 }
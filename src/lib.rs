@@ -24,5 +24,6 @@ extern crate alloc;
 #[macro_use]
 extern crate approx;
 
+mod callback;
 mod ffi;
 pub mod math;
@@ -0,0 +1,76 @@
+//! boost/math/distributions/arcsine.hpp
+
+use super::{
+    Distribution,
+    sealed::{Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::c_int;
+
+/// Arcsine distribution
+///
+/// Corresponds to `boost::math::arcsine_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/arcsine_dist.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "arcsine_distribution")]
+pub struct Arcsine {
+    x_min: f64,
+    x_max: f64,
+}
+
+impl Arcsine {
+    /// Arcsine distribution on *[x<sub>min</sub>, x<sub>max</sub>]*
+    pub fn new(x_min: f64, x_max: f64) -> Self {
+        Self { x_min, x_max }
+    }
+
+    /// Lower bound of the support *x<sub>min</sub>*
+    pub fn x_min(&self) -> f64 {
+        self.x_min
+    }
+
+    /// Upper bound of the support *x<sub>max</sub>*
+    pub fn x_max(&self) -> f64 {
+        self.x_max
+    }
+}
+
+/// Standard arcsine distribution on *[0, 1]*
+impl Default for Arcsine {
+    fn default() -> Self {
+        Self::new(0.0, 1.0)
+    }
+}
+
+impl Sealed for Arcsine {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe { ffi::math_arcsine_distribution(self.x_min, self.x_max, op as c_int, x) }
+    }
+}
+
+impl Distribution for Arcsine {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{Arcsine, Distribution};
+    use core::f64::consts::FRAC_1_PI;
+
+    const RTOL: f64 = 4.0 * f64::EPSILON;
+
+    #[test]
+    fn test_arcsine() {
+        // F(x) = 2/π arcsin(√x)
+        let d = Arcsine::default();
+        assert_relative_eq!(d.pdf(0.5), 2.0 * FRAC_1_PI, max_relative = RTOL);
+        assert_relative_eq!(d.cdf(0.5), 0.5, max_relative = RTOL);
+        assert_relative_eq!(d.cdf(0.25), 1.0 / 3.0, max_relative = RTOL);
+        assert_relative_eq!(
+            d.quantile(0.25),
+            0.146_446_609_406_726_24,
+            max_relative = RTOL
+        );
+        assert_eq!(d.mean(), 0.5);
+        assert_eq!(d.variance(), 0.125);
+        assert_eq!(d.support(), (0.0, 1.0));
+    }
+}
@@ -0,0 +1,76 @@
+//! boost/math/distributions/bernoulli.hpp
+
+use super::{
+    DiscreteDistribution, Distribution,
+    sealed::{Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::c_int;
+
+/// Bernoulli distribution
+///
+/// A single trial with success probability *p*, i.e. a [`Binomial`](super::Binomial)
+/// distribution with *n = 1*.
+///
+/// The quantile is always either 0 or 1, so there is no
+/// [`DiscreteQuantile`](super::DiscreteQuantile) rounding policy.
+///
+/// Corresponds to `boost::math::bernoulli_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/bernoulli_dist.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "bernoulli_distribution")]
+pub struct Bernoulli {
+    p: f64,
+}
+
+impl Bernoulli {
+    /// Bernoulli distribution with success fraction *0 ≤ p ≤ 1*
+    pub fn new(p: f64) -> Self {
+        Self { p }
+    }
+
+    /// Success fraction *p*
+    pub fn success_fraction(&self) -> f64 {
+        self.p
+    }
+}
+
+/// Fair coin flip, i.e. a Bernoulli distribution with *p = 0.5*
+impl Default for Bernoulli {
+    fn default() -> Self {
+        Self::new(0.5)
+    }
+}
+
+impl Sealed for Bernoulli {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe { ffi::math_bernoulli_distribution(self.p, op as c_int, x) }
+    }
+}
+
+impl Distribution for Bernoulli {}
+impl DiscreteDistribution for Bernoulli {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{Bernoulli, DiscreteDistribution, Distribution};
+
+    #[test]
+    fn test_bernoulli() {
+        let d = Bernoulli::new(0.25);
+        assert_eq!(d.pmf(0.0), 0.75);
+        assert_eq!(d.pmf(1.0), 0.25);
+        assert_eq!(d.cdf(0.0), 0.75);
+        assert_eq!(d.cdf_complement(0.0), 0.25);
+        assert_eq!(d.quantile(0.5), 0.0);
+        assert_eq!(d.quantile(0.8), 1.0);
+        assert_eq!(d.mean(), 0.25);
+        assert_eq!(d.variance(), 0.1875);
+        assert_eq!(d.support(), (0.0, 1.0));
+    }
+
+    #[test]
+    fn test_bernoulli_invalid() {
+        assert!(Bernoulli::new(1.5).pmf(1.0).is_nan());
+    }
+}
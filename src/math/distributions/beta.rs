@@ -0,0 +1,77 @@
+//! boost/math/distributions/beta.hpp
+
+use super::{
+    Distribution,
+    sealed::{Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::c_int;
+
+/// Beta distribution *Beta(α, β)* on *[0, 1]*
+///
+/// Corresponds to `boost::math::beta_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/beta_dist.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "beta_distribution")]
+pub struct Beta {
+    alpha: f64,
+    beta: f64,
+}
+
+impl Beta {
+    /// Beta distribution with shape parameters *α > 0* and *β > 0*
+    pub fn new(alpha: f64, beta: f64) -> Self {
+        Self { alpha, beta }
+    }
+
+    /// Shape parameter *α*
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Shape parameter *β*
+    pub fn beta(&self) -> f64 {
+        self.beta
+    }
+}
+
+/// Standard uniform distribution *Beta(1, 1)*
+impl Default for Beta {
+    fn default() -> Self {
+        Self::new(1.0, 1.0)
+    }
+}
+
+impl Sealed for Beta {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe { ffi::math_beta_distribution(self.alpha, self.beta, op as c_int, x) }
+    }
+}
+
+impl Distribution for Beta {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{Beta, Distribution};
+
+    const RTOL: f64 = 4.0 * f64::EPSILON;
+
+    #[test]
+    fn test_beta() {
+        // F(x) = 3x² - 2x³
+        let d = Beta::new(2.0, 2.0);
+        assert_relative_eq!(d.pdf(0.25), 1.125, max_relative = RTOL);
+        assert_relative_eq!(d.cdf(0.25), 0.156_25, max_relative = RTOL);
+        assert_relative_eq!(d.cdf_complement(0.25), 0.843_75, max_relative = RTOL);
+        assert_relative_eq!(d.quantile(0.156_25), 0.25, max_relative = RTOL);
+        assert_relative_eq!(d.mean(), 0.5, max_relative = RTOL);
+        assert_relative_eq!(d.variance(), 0.05, max_relative = RTOL);
+        assert_relative_eq!(d.mode(), 0.5, max_relative = RTOL);
+        assert_eq!(d.support(), (0.0, 1.0));
+    }
+
+    #[test]
+    fn test_beta_invalid() {
+        assert!(Beta::new(0.0, 1.0).cdf(0.5).is_nan());
+    }
+}
@@ -0,0 +1,197 @@
+//! boost/math/distributions/binomial.hpp
+
+use super::{
+    DiscreteDistribution, DiscreteQuantile, Distribution,
+    sealed::{Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::c_int;
+
+/// Binomial distribution *B(n, p)*
+///
+/// The number of successes in *n* independent trials, each with success probability *p*.
+///
+/// Corresponds to `boost::math::binomial_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/binomial_dist.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "binomial_distribution")]
+pub struct Binomial {
+    n: f64,
+    p: f64,
+    rounding: DiscreteQuantile,
+}
+
+impl Binomial {
+    /// Binomial distribution with *n ≥ 0* trials and success fraction *0 ≤ p ≤ 1*
+    pub fn new(n: f64, p: f64) -> Self {
+        Self {
+            n,
+            p,
+            rounding: DiscreteQuantile::default(),
+        }
+    }
+
+    /// Use the given rounding policy for the quantiles
+    pub fn with_rounding(self, rounding: DiscreteQuantile) -> Self {
+        Self { rounding, ..self }
+    }
+
+    /// Number of trials *n*
+    pub fn trials(&self) -> f64 {
+        self.n
+    }
+
+    /// Success fraction *p*
+    pub fn success_fraction(&self) -> f64 {
+        self.p
+    }
+
+    /// Rounding policy for the quantiles
+    pub fn rounding(&self) -> DiscreteQuantile {
+        self.rounding
+    }
+
+    /// Lower bound on the success fraction *p*, given `successes` out of `trials`
+    ///
+    /// The true success fraction is below the returned bound with probability `alpha`. For a
+    /// two-sided interval with coverage *1 - α*, use `alpha / 2`.
+    ///
+    /// Corresponds to `boost::math::binomial_distribution<double>::find_lower_bound_on_p` in C++.
+    pub fn find_lower_bound_on_p(
+        trials: f64,
+        successes: f64,
+        alpha: f64,
+        interval: BinomialInterval,
+    ) -> f64 {
+        let interval = interval as c_int;
+        unsafe { ffi::math_binomial_find_lower_bound_on_p(trials, successes, alpha, interval) }
+    }
+
+    /// Upper bound on the success fraction *p*, given `successes` out of `trials`
+    ///
+    /// The true success fraction is above the returned bound with probability `alpha`. For a
+    /// two-sided interval with coverage *1 - α*, use `alpha / 2`.
+    ///
+    /// Corresponds to `boost::math::binomial_distribution<double>::find_upper_bound_on_p` in C++.
+    pub fn find_upper_bound_on_p(
+        trials: f64,
+        successes: f64,
+        alpha: f64,
+        interval: BinomialInterval,
+    ) -> f64 {
+        let interval = interval as c_int;
+        unsafe { ffi::math_binomial_find_upper_bound_on_p(trials, successes, alpha, interval) }
+    }
+
+    /// Minimum number of trials *n* such that the probability of observing `k` or fewer events
+    /// is at most `alpha`, given the success fraction `p`
+    ///
+    /// The result is not rounded, and generally has to be rounded up.
+    ///
+    /// Corresponds to `boost::math::binomial_distribution<double>::find_minimum_number_of_trials`
+    /// in C++.
+    pub fn find_minimum_number_of_trials(k: f64, p: f64, alpha: f64) -> f64 {
+        unsafe { ffi::math_binomial_find_minimum_number_of_trials(k, p, alpha) }
+    }
+}
+
+/// Method used by [`Binomial::find_lower_bound_on_p`] and [`Binomial::find_upper_bound_on_p`]
+///
+/// Corresponds to `boost::math::binomial_distribution<double>::interval_type` in C++.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum BinomialInterval {
+    /// Exact Clopper-Pearson interval, which is conservative
+    ///
+    /// Corresponds to `clopper_pearson_exact_interval` in C++.
+    #[default]
+    ClopperPearsonExact = 0,
+    /// Jeffreys prior interval, which has better coverage on average
+    ///
+    /// Corresponds to `jeffreys_prior_interval` in C++.
+    JeffreysPrior,
+}
+
+impl Sealed for Binomial {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe {
+            ffi::math_binomial_distribution(self.n, self.p, self.rounding as c_int, op as c_int, x)
+        }
+    }
+}
+
+impl Distribution for Binomial {}
+impl DiscreteDistribution for Binomial {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{
+        Binomial, BinomialInterval, DiscreteDistribution, DiscreteQuantile, Distribution,
+    };
+
+    const RTOL: f64 = 8.0 * f64::EPSILON;
+
+    #[test]
+    fn test_binomial() {
+        let d = Binomial::new(10.0, 0.5);
+        assert_relative_eq!(d.pmf(5.0), 252.0 / 1024.0, max_relative = RTOL);
+        assert_relative_eq!(d.cdf(5.0), 638.0 / 1024.0, max_relative = RTOL);
+        assert_relative_eq!(d.cdf_complement(5.0), 386.0 / 1024.0, max_relative = RTOL);
+        assert_eq!(d.mean(), 5.0);
+        assert_eq!(d.variance(), 2.5);
+        assert_eq!(d.mode(), 5.0);
+        assert_eq!(d.support(), (0.0, 10.0));
+    }
+
+    #[test]
+    fn test_binomial_quantile_rounding() {
+        // F(3) = 176/1024 < 0.3 < F(4) = 386/1024
+        let d = Binomial::new(10.0, 0.5);
+        assert_eq!(d.rounding(), DiscreteQuantile::IntegerRoundOutwards);
+        assert_eq!(d.quantile(0.3), 3.0);
+
+        let quantile = |rounding| d.with_rounding(rounding).quantile(0.3);
+        assert_eq!(quantile(DiscreteQuantile::IntegerRoundOutwards), 3.0);
+        assert_eq!(quantile(DiscreteQuantile::IntegerRoundInwards), 4.0);
+        assert_eq!(quantile(DiscreteQuantile::IntegerRoundDown), 3.0);
+        assert_eq!(quantile(DiscreteQuantile::IntegerRoundUp), 4.0);
+
+        let q = quantile(DiscreteQuantile::Real);
+        assert!(q > 3.0 && q < 4.0);
+        let q = quantile(DiscreteQuantile::IntegerRoundNearest);
+        assert!(q == 3.0 || q == 4.0);
+    }
+
+    #[test]
+    fn test_binomial_find_bounds_on_p() {
+        const ATOL: f64 = 1e-12;
+        let exact = BinomialInterval::ClopperPearsonExact;
+
+        // Clopper-Pearson: P(X ≥ k | p_lower) = α and P(X ≤ k | p_upper) = α
+        let lower = Binomial::find_lower_bound_on_p(20.0, 7.0, 0.05, exact);
+        let upper = Binomial::find_upper_bound_on_p(20.0, 7.0, 0.05, exact);
+        assert!(lower < 0.35 && 0.35 < upper);
+        let d = Binomial::new(20.0, lower);
+        assert_abs_diff_eq!(d.cdf_complement(6.0), 0.05, epsilon = ATOL);
+        let d = Binomial::new(20.0, upper);
+        assert_abs_diff_eq!(d.cdf(7.0), 0.05, epsilon = ATOL);
+
+        assert_eq!(Binomial::find_lower_bound_on_p(20.0, 0.0, 0.05, exact), 0.0);
+        assert_eq!(
+            Binomial::find_upper_bound_on_p(20.0, 20.0, 0.05, exact),
+            1.0
+        );
+
+        let jeffreys = BinomialInterval::JeffreysPrior;
+        let lower = Binomial::find_lower_bound_on_p(20.0, 7.0, 0.05, jeffreys);
+        let upper = Binomial::find_upper_bound_on_p(20.0, 7.0, 0.05, jeffreys);
+        assert!(lower < 0.35 && 0.35 < upper);
+    }
+
+    #[test]
+    fn test_binomial_find_minimum_number_of_trials() {
+        let n = Binomial::find_minimum_number_of_trials(5.0, 0.5, 0.05).ceil();
+        assert!(Binomial::new(n, 0.5).cdf(5.0) <= 0.05);
+        assert!(Binomial::new(n - 1.0, 0.5).cdf(5.0) > 0.05);
+    }
+}
@@ -0,0 +1,84 @@
+//! boost/math/distributions/cauchy.hpp
+
+use super::{
+    Distribution, LocationScale,
+    sealed::{Find, FindOp, Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::c_int;
+
+/// Cauchy-Lorentz distribution
+///
+/// Note that the mean, variance, skewness and kurtosis of this distribution are undefined, so
+/// those accessors return NaN.
+///
+/// Corresponds to `boost::math::cauchy_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/cauchy_dist.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "cauchy_distribution")]
+#[doc(alias = "Lorentz")]
+pub struct Cauchy {
+    location: f64,
+    scale: f64,
+}
+
+impl Cauchy {
+    /// Cauchy distribution with location *x<sub>0</sub>* and scale *γ > 0*
+    pub fn new(location: f64, scale: f64) -> Self {
+        Self { location, scale }
+    }
+
+    /// Location parameter *x<sub>0</sub>*, i.e. the median
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Scale parameter *γ*, i.e. the half width at half maximum
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+/// Standard Cauchy distribution with location 0 and scale 1
+impl Default for Cauchy {
+    fn default() -> Self {
+        Self::new(0.0, 1.0)
+    }
+}
+
+impl Sealed for Cauchy {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe { ffi::math_cauchy_distribution(self.location, self.scale, op as c_int, x) }
+    }
+}
+
+impl Distribution for Cauchy {}
+
+impl Find for Cauchy {
+    fn find(op: FindOp, z: f64, p: f64, param: f64) -> f64 {
+        unsafe { ffi::math_cauchy_find(op as c_int, z, p, param) }
+    }
+}
+
+impl LocationScale for Cauchy {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{Cauchy, Distribution};
+    use core::f64::consts::FRAC_1_PI;
+
+    const RTOL: f64 = 4.0 * f64::EPSILON;
+
+    #[test]
+    fn test_cauchy() {
+        let d = Cauchy::default();
+        assert_relative_eq!(d.pdf(0.0), FRAC_1_PI, max_relative = RTOL);
+        assert_relative_eq!(d.cdf(1.0), 0.75, max_relative = RTOL);
+        assert_relative_eq!(d.cdf_complement(1.0), 0.25, max_relative = RTOL);
+        assert_relative_eq!(d.quantile(0.75), 1.0, max_relative = RTOL);
+        assert_eq!(d.median(), 0.0);
+        assert_eq!(d.mode(), 0.0);
+        assert!(d.mean().is_nan());
+        assert!(d.variance().is_nan());
+    }
+}
@@ -0,0 +1,97 @@
+//! boost/math/distributions/chi_squared.hpp
+
+use super::{
+    Distribution,
+    sealed::{Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::c_int;
+
+/// Chi-squared distribution *χ<sup>2</sup>(k)*
+///
+/// Corresponds to `boost::math::chi_squared_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/chi_squared_dist.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "chi_squared_distribution")]
+pub struct ChiSquared {
+    df: f64,
+}
+
+impl ChiSquared {
+    /// Chi-squared distribution with *k > 0* degrees of freedom
+    pub fn new(df: f64) -> Self {
+        Self { df }
+    }
+
+    /// Degrees of freedom *k*
+    pub fn degrees_of_freedom(&self) -> f64 {
+        self.df
+    }
+
+    /// Degrees of freedom *k* required for a chi-squared test to detect a difference
+    /// `difference_from_variance` from the nominal `variance`
+    ///
+    /// A positive difference tests for a variance larger than the nominal one, a negative
+    /// difference for a smaller one. `alpha` and `beta` are the acceptable probabilities of type I
+    /// and type II errors, respectively. The required sample size is *k + 1*.
+    ///
+    /// Corresponds to `boost::math::chi_squared_distribution<double>::find_degrees_of_freedom` in
+    /// C++.
+    pub fn find_degrees_of_freedom(
+        difference_from_variance: f64,
+        alpha: f64,
+        beta: f64,
+        variance: f64,
+    ) -> f64 {
+        unsafe {
+            ffi::math_chi_squared_find_degrees_of_freedom(
+                difference_from_variance,
+                alpha,
+                beta,
+                variance,
+            )
+        }
+    }
+}
+
+impl Sealed for ChiSquared {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe { ffi::math_chi_squared_distribution(self.df, op as c_int, x) }
+    }
+}
+
+impl Distribution for ChiSquared {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{ChiSquared, Distribution};
+
+    const RTOL: f64 = 4.0 * f64::EPSILON;
+
+    #[test]
+    fn test_chi_squared() {
+        // for k = 2: F(x) = 1 - exp(-x/2)
+        let d = ChiSquared::new(2.0);
+        assert_relative_eq!(d.pdf(2.0), 0.5 * (-1.0f64).exp(), max_relative = RTOL);
+        assert_relative_eq!(d.cdf(2.0), -(-1.0f64).exp_m1(), max_relative = RTOL);
+        assert_relative_eq!(d.cdf_complement(2.0), (-1.0f64).exp(), max_relative = RTOL);
+        assert_relative_eq!(d.quantile(-(-1.0f64).exp_m1()), 2.0, max_relative = RTOL);
+        assert_eq!(d.mean(), 2.0);
+        assert_eq!(d.variance(), 4.0);
+        assert_eq!(d.mode(), 0.0);
+    }
+
+    #[test]
+    fn test_chi_squared_invalid() {
+        assert!(ChiSquared::new(-1.0).cdf(1.0).is_nan());
+    }
+
+    #[test]
+    fn test_chi_squared_find_degrees_of_freedom() {
+        let df = ChiSquared::find_degrees_of_freedom(0.5, 0.05, 0.1, 1.0);
+        assert!(df > 0.0);
+        // smaller differences need more samples
+        assert!(ChiSquared::find_degrees_of_freedom(0.25, 0.05, 0.1, 1.0) > df);
+        assert!(ChiSquared::find_degrees_of_freedom(-0.25, 0.05, 0.1, 1.0) > 0.0);
+    }
+}
@@ -0,0 +1,116 @@
+//! boost/math/distributions/empirical_cumulative_distribution_function.hpp
+
+use crate::ffi;
+use alloc::vec::Vec;
+
+/// Empirical cumulative distribution function (ECDF) of a set of samples
+///
+/// *F<sub>n</sub>(x) = #{i : x<sub>i</sub> ≤ x} / n*
+///
+/// Unlike the other distributions, this does not implement [`Distribution`](super::Distribution),
+/// since Boost only defines the cdf itself.
+///
+/// Corresponds to `boost::math::empirical_cumulative_distribution_function` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/empirical_cdf.html>
+#[derive(Clone, Debug, PartialEq)]
+#[doc(alias = "empirical_cumulative_distribution_function")]
+#[doc(alias = "ecdf")]
+pub struct EmpiricalCdf {
+    // sorted in ascending order
+    samples: Vec<f64>,
+}
+
+impl EmpiricalCdf {
+    /// Empirical cdf of the given samples, which need not be sorted
+    ///
+    /// The samples must not contain NaN.
+    pub fn new(samples: &[f64]) -> Self {
+        let mut samples = samples.to_vec();
+        samples.sort_unstable_by(f64::total_cmp);
+        Self { samples }
+    }
+
+    /// The samples, sorted in ascending order
+    pub fn samples(&self) -> &[f64] {
+        &self.samples
+    }
+
+    /// Number of samples *n*
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Whether there are no samples, in which case the cdf and quantiles are NaN
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Cumulative distribution function *F<sub>n</sub>(x)*, i.e. the fraction of samples *≤ x*
+    ///
+    /// Corresponds to `boost::math::empirical_cumulative_distribution_function::operator()` in
+    /// C++.
+    pub fn cdf(&self, x: f64) -> f64 {
+        if self.samples.is_empty() {
+            // special casing to avoid an exception in Boost
+            f64::NAN
+        } else {
+            let (samples, n) = (self.samples.as_ptr(), self.samples.len());
+            unsafe { ffi::math_empirical_cumulative_distribution_function(samples, n, x) }
+        }
+    }
+
+    /// Smallest sample *x* for which *F<sub>n</sub>(x) ≥ p*, with *0 ≤ p ≤ 1*
+    ///
+    /// This is the generalized inverse of [`cdf`](Self::cdf), which is not provided by Boost.
+    #[doc(alias = "ppf")]
+    pub fn quantile(&self, p: f64) -> f64 {
+        if self.samples.is_empty() || !(0.0..=1.0).contains(&p) {
+            return f64::NAN;
+        }
+        // k = ⌈pn⌉, without `f64::ceil` in no_std
+        let pn = p * self.samples.len() as f64;
+        let mut k = pn as usize;
+        if (k as f64) < pn {
+            k += 1;
+        }
+        self.samples[k.saturating_sub(1)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::EmpiricalCdf;
+
+    #[test]
+    fn test_empirical_cdf() {
+        let ecdf = EmpiricalCdf::new(&[3.0, 1.0, 2.0, 2.0]);
+        assert_eq!(ecdf.samples(), &[1.0, 2.0, 2.0, 3.0]);
+        assert_eq!(ecdf.len(), 4);
+        assert_eq!(ecdf.cdf(0.0), 0.0);
+        assert_eq!(ecdf.cdf(1.0), 0.25);
+        assert_eq!(ecdf.cdf(1.5), 0.25);
+        assert_eq!(ecdf.cdf(2.0), 0.75);
+        assert_eq!(ecdf.cdf(3.0), 1.0);
+        assert_eq!(ecdf.cdf(f64::INFINITY), 1.0);
+    }
+
+    #[test]
+    fn test_empirical_cdf_quantile() {
+        let ecdf = EmpiricalCdf::new(&[3.0, 1.0, 2.0, 2.0]);
+        assert_eq!(ecdf.quantile(0.0), 1.0);
+        assert_eq!(ecdf.quantile(0.25), 1.0);
+        assert_eq!(ecdf.quantile(0.3), 2.0);
+        assert_eq!(ecdf.quantile(0.75), 2.0);
+        assert_eq!(ecdf.quantile(0.8), 3.0);
+        assert_eq!(ecdf.quantile(1.0), 3.0);
+        assert!(ecdf.quantile(1.5).is_nan());
+    }
+
+    #[test]
+    fn test_empirical_cdf_empty() {
+        let ecdf = EmpiricalCdf::new(&[]);
+        assert!(ecdf.is_empty());
+        assert!(ecdf.cdf(0.0).is_nan());
+        assert!(ecdf.quantile(0.5).is_nan());
+    }
+}
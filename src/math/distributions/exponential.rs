@@ -0,0 +1,69 @@
+//! boost/math/distributions/exponential.hpp
+
+use super::{
+    Distribution,
+    sealed::{Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::c_int;
+
+/// Exponential distribution *Exp(λ)*
+///
+/// Corresponds to `boost::math::exponential_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/exp_dist.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "exponential_distribution")]
+pub struct Exponential {
+    lambda: f64,
+}
+
+impl Exponential {
+    /// Exponential distribution with rate parameter *λ > 0*
+    pub fn new(lambda: f64) -> Self {
+        Self { lambda }
+    }
+
+    /// Rate parameter *λ*
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+}
+
+/// Standard exponential distribution *Exp(1)*
+impl Default for Exponential {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl Sealed for Exponential {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe { ffi::math_exponential_distribution(self.lambda, op as c_int, x) }
+    }
+}
+
+impl Distribution for Exponential {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{Distribution, Exponential};
+    use core::f64::consts::LN_2;
+
+    const RTOL: f64 = 4.0 * f64::EPSILON;
+
+    #[test]
+    fn test_exponential() {
+        let d = Exponential::new(2.0);
+        assert_relative_eq!(d.pdf(0.5), 2.0 * (-1.0f64).exp(), max_relative = RTOL);
+        assert_relative_eq!(d.cdf(0.5), -(-1.0f64).exp_m1(), max_relative = RTOL);
+        assert_relative_eq!(d.cdf_complement(0.5), (-1.0f64).exp(), max_relative = RTOL);
+        assert_relative_eq!(d.hazard(0.5), 2.0, max_relative = RTOL);
+        assert_relative_eq!(d.chf(0.5), 1.0, max_relative = RTOL);
+        assert_relative_eq!(d.median(), 0.5 * LN_2, max_relative = RTOL);
+        assert_eq!(d.mean(), 0.5);
+        assert_eq!(d.variance(), 0.25);
+        assert_eq!(d.skewness(), 2.0);
+        assert_eq!(d.kurtosis_excess(), 6.0);
+        assert_eq!(d.mode(), 0.0);
+    }
+}
@@ -0,0 +1,84 @@
+//! boost/math/distributions/extreme_value.hpp
+
+use super::{
+    Distribution, LocationScale,
+    sealed::{Find, FindOp, Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::c_int;
+
+/// Extreme value (Gumbel) distribution
+///
+/// This is the distribution of the maximum of many samples from a distribution with an
+/// exponential tail.
+///
+/// Corresponds to `boost::math::extreme_value_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/extreme_dist.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "extreme_value_distribution")]
+#[doc(alias = "Gumbel")]
+pub struct ExtremeValue {
+    location: f64,
+    scale: f64,
+}
+
+impl ExtremeValue {
+    /// Extreme value distribution with location *μ* and scale *β > 0*
+    pub fn new(location: f64, scale: f64) -> Self {
+        Self { location, scale }
+    }
+
+    /// Location parameter *μ*, i.e. the mode
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Scale parameter *β*
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+/// Standard Gumbel distribution with location 0 and scale 1
+impl Default for ExtremeValue {
+    fn default() -> Self {
+        Self::new(0.0, 1.0)
+    }
+}
+
+impl Sealed for ExtremeValue {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe { ffi::math_extreme_value_distribution(self.location, self.scale, op as c_int, x) }
+    }
+}
+
+impl Distribution for ExtremeValue {}
+
+impl Find for ExtremeValue {
+    fn find(op: FindOp, z: f64, p: f64, param: f64) -> f64 {
+        unsafe { ffi::math_extreme_value_find(op as c_int, z, p, param) }
+    }
+}
+
+impl LocationScale for ExtremeValue {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::constants::{EULER, EXTREME_VALUE_SKEWNESS};
+    use crate::math::distributions::{Distribution, ExtremeValue};
+
+    const RTOL: f64 = 4.0 * f64::EPSILON;
+
+    #[test]
+    fn test_extreme_value() {
+        // F(x) = exp(-exp(-(x - μ) / β))
+        let d = ExtremeValue::default();
+        assert_relative_eq!(d.cdf(0.0), (-1.0f64).exp(), max_relative = RTOL);
+        assert_relative_eq!(d.pdf(0.0), (-1.0f64).exp(), max_relative = RTOL);
+        assert_relative_eq!(d.quantile((-1.0f64).exp()), 0.0, epsilon = RTOL);
+        assert_relative_eq!(d.mean(), EULER, max_relative = RTOL);
+        assert_relative_eq!(d.skewness(), EXTREME_VALUE_SKEWNESS, max_relative = RTOL);
+        assert_relative_eq!(d.kurtosis_excess(), 2.4, max_relative = RTOL);
+        assert_eq!(d.mode(), 0.0);
+    }
+}
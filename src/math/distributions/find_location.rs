@@ -0,0 +1,69 @@
+//! boost/math/distributions/find_location.hpp
+
+use super::{LocationScale, sealed::FindOp};
+
+/// Location parameter for which *F(z) = p*, given the scale
+///
+/// # Examples
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use boost::math::distributions::{Distribution, Normal, find_location};
+///
+/// // shift N(μ, 2²) so that 95% of it lies below 10
+/// let mean = find_location::<Normal>(10.0, 0.95, 2.0);
+/// assert_relative_eq!(Normal::new(mean, 2.0).cdf(10.0), 0.95, max_relative = 1e-15);
+/// ```
+///
+/// Corresponds to `boost::math::find_location<Dist>(z, p, scale)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dist_algorithms.html>
+pub fn find_location<D: LocationScale>(z: f64, p: f64, scale: f64) -> f64 {
+    D::find(FindOp::Location, z, p, scale)
+}
+
+/// Location parameter for which *1 - F(z) = q*, given the scale
+///
+/// Corresponds to `boost::math::find_location<Dist>(complement(z, q, scale))` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dist_algorithms.html>
+pub fn find_location_complement<D: LocationScale>(z: f64, q: f64, scale: f64) -> f64 {
+    D::find(FindOp::LocationComplement, z, q, scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::*;
+
+    const RTOL: f64 = 1e-14;
+
+    #[test]
+    fn test_find_location() {
+        let mean = find_location::<Normal>(2.0, 0.975, 1.0);
+        assert_relative_eq!(mean, 2.0 - 1.959_963_984_540_054, max_relative = RTOL);
+        let mean = find_location_complement::<Normal>(2.0, 0.025, 1.0);
+        assert_relative_eq!(mean, 2.0 - 1.959_963_984_540_054, max_relative = RTOL);
+
+        let location = find_location::<Cauchy>(3.0, 0.75, 2.0);
+        assert_relative_eq!(
+            Cauchy::new(location, 2.0).cdf(3.0),
+            0.75,
+            max_relative = RTOL
+        );
+        let location = find_location::<Logistic>(1.0, 0.25, 0.5);
+        assert_relative_eq!(
+            Logistic::new(location, 0.5).cdf(1.0),
+            0.25,
+            max_relative = RTOL
+        );
+        let location = find_location_complement::<Laplace>(1.0, 0.1, 2.0);
+        let d = Laplace::new(location, 2.0);
+        assert_relative_eq!(d.cdf_complement(1.0), 0.1, max_relative = RTOL);
+        let location = find_location::<ExtremeValue>(0.0, 0.5, 1.0);
+        assert_relative_eq!(
+            ExtremeValue::new(location, 1.0).median(),
+            0.0,
+            epsilon = RTOL
+        );
+
+        assert!(find_location::<Normal>(2.0, 1.5, 1.0).is_nan());
+    }
+}
@@ -0,0 +1,66 @@
+//! boost/math/distributions/find_scale.hpp
+
+use super::{LocationScale, sealed::FindOp};
+
+/// Scale parameter for which *F(z) = p*, given the location
+///
+/// Returns NaN if there is no such positive scale, i.e. if *z* lies on the wrong side of the
+/// location for *p*.
+///
+/// # Examples
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use boost::math::distributions::{Distribution, Normal, find_scale};
+///
+/// // widen N(0, σ²) so that 5% of it lies below -10
+/// let sd = find_scale::<Normal>(-10.0, 0.05, 0.0);
+/// assert_relative_eq!(Normal::new(0.0, sd).cdf(-10.0), 0.05, max_relative = 1e-14);
+/// ```
+///
+/// Corresponds to `boost::math::find_scale<Dist>(z, p, location)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dist_algorithms.html>
+pub fn find_scale<D: LocationScale>(z: f64, p: f64, location: f64) -> f64 {
+    positive(D::find(FindOp::Scale, z, p, location))
+}
+
+/// Scale parameter for which *1 - F(z) = q*, given the location
+///
+/// Returns NaN if there is no such positive scale.
+///
+/// Corresponds to `boost::math::find_scale<Dist>(complement(z, q, location))` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dist_algorithms.html>
+pub fn find_scale_complement<D: LocationScale>(z: f64, q: f64, location: f64) -> f64 {
+    positive(D::find(FindOp::ScaleComplement, z, q, location))
+}
+
+/// Boost raises an evaluation error for a computed scale `<= 0`, which the `ignore_error` policy
+/// turns into a returned value instead of NaN
+fn positive(scale: f64) -> f64 {
+    if scale > 0.0 { scale } else { f64::NAN }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::*;
+
+    const RTOL: f64 = 1e-14;
+
+    #[test]
+    fn test_find_scale() {
+        let sd = find_scale::<Normal>(1.959_963_984_540_054, 0.975, 0.0);
+        assert_relative_eq!(sd, 1.0, max_relative = RTOL);
+        let sd = find_scale_complement::<Normal>(1.959_963_984_540_054, 0.025, 0.0);
+        assert_relative_eq!(sd, 1.0, max_relative = RTOL);
+
+        let scale = find_scale::<Cauchy>(3.0, 0.75, 1.0);
+        assert_relative_eq!(scale, 2.0, max_relative = RTOL);
+        let scale = find_scale_complement::<Logistic>(2.0, 0.1, 0.0);
+        let d = Logistic::new(0.0, scale);
+        assert_relative_eq!(d.cdf_complement(2.0), 0.1, max_relative = RTOL);
+
+        // the scale must be positive
+        assert!(find_scale::<Normal>(1.0, 0.25, 0.0).is_nan());
+        assert!(find_scale_complement::<Normal>(1.0, 0.75, 0.0).is_nan());
+    }
+}
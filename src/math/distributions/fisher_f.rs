@@ -0,0 +1,66 @@
+//! boost/math/distributions/fisher_f.hpp
+
+use super::{
+    Distribution,
+    sealed::{Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::c_int;
+
+/// Fisher F distribution *F(d<sub>1</sub>, d<sub>2</sub>)*
+///
+/// Corresponds to `boost::math::fisher_f_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/f_dist.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "fisher_f_distribution")]
+#[doc(alias = "Snedecor")]
+pub struct FisherF {
+    df1: f64,
+    df2: f64,
+}
+
+impl FisherF {
+    /// Fisher F distribution with *d<sub>1</sub> > 0* and *d<sub>2</sub> > 0* degrees of freedom
+    pub fn new(df1: f64, df2: f64) -> Self {
+        Self { df1, df2 }
+    }
+
+    /// Degrees of freedom of the numerator *d<sub>1</sub>*
+    pub fn degrees_of_freedom1(&self) -> f64 {
+        self.df1
+    }
+
+    /// Degrees of freedom of the denominator *d<sub>2</sub>*
+    pub fn degrees_of_freedom2(&self) -> f64 {
+        self.df2
+    }
+}
+
+impl Sealed for FisherF {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe { ffi::math_fisher_f_distribution(self.df1, self.df2, op as c_int, x) }
+    }
+}
+
+impl Distribution for FisherF {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{Distribution, FisherF};
+
+    const RTOL: f64 = 8.0 * f64::EPSILON;
+
+    #[test]
+    fn test_fisher_f() {
+        // for d1 = d2 = 2: f(x) = 1 / (1 + x)², F(x) = x / (1 + x)
+        let d = FisherF::new(2.0, 2.0);
+        assert_relative_eq!(d.pdf(1.0), 0.25, max_relative = RTOL);
+        assert_relative_eq!(d.cdf(3.0), 0.75, max_relative = RTOL);
+        assert_relative_eq!(d.cdf_complement(3.0), 0.25, max_relative = RTOL);
+        assert_relative_eq!(d.quantile(0.75), 3.0, max_relative = RTOL);
+        assert_relative_eq!(d.median(), 1.0, max_relative = RTOL);
+        assert_relative_eq!(FisherF::new(4.0, 6.0).mean(), 1.5, max_relative = RTOL);
+        // the mean is undefined for d2 <= 2
+        assert!(d.mean().is_nan());
+    }
+}
@@ -0,0 +1,76 @@
+//! boost/math/distributions/gamma.hpp
+
+use super::{
+    Distribution,
+    sealed::{Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::c_int;
+
+/// Gamma distribution *Γ(k, θ)*
+///
+/// See also [`gamma_p`](crate::math::gamma_p) and
+/// [`gamma_p_derivative`](crate::math::gamma_p_derivative) for the cdf and pdf of the standard
+/// gamma distribution.
+///
+/// Corresponds to `boost::math::gamma_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/gamma_dist.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "gamma_distribution")]
+pub struct Gamma {
+    shape: f64,
+    scale: f64,
+}
+
+impl Gamma {
+    /// Gamma distribution with shape *k > 0* and scale *θ > 0*
+    pub fn new(shape: f64, scale: f64) -> Self {
+        Self { shape, scale }
+    }
+
+    /// Shape parameter *k*
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    /// Scale parameter *θ*
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+impl Sealed for Gamma {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe { ffi::math_gamma_distribution(self.shape, self.scale, op as c_int, x) }
+    }
+}
+
+impl Distribution for Gamma {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{Distribution, Gamma};
+    use crate::math::{gamma_p, gamma_p_derivative};
+
+    const RTOL: f64 = 4.0 * f64::EPSILON;
+
+    #[test]
+    fn test_gamma() {
+        let d = Gamma::new(3.0, 2.0);
+        assert_relative_eq!(
+            d.pdf(4.0),
+            gamma_p_derivative(3.0, 2.0) / 2.0,
+            max_relative = RTOL
+        );
+        assert_relative_eq!(d.cdf(4.0), gamma_p(3.0, 2.0), max_relative = RTOL);
+        assert_relative_eq!(d.quantile(d.cdf(4.0)), 4.0, max_relative = RTOL);
+        assert_eq!(d.mean(), 6.0);
+        assert_eq!(d.variance(), 12.0);
+        assert_eq!(d.mode(), 4.0);
+    }
+
+    #[test]
+    fn test_gamma_invalid() {
+        assert!(Gamma::new(3.0, 0.0).pdf(1.0).is_nan());
+    }
+}
@@ -0,0 +1,95 @@
+//! boost/math/distributions/geometric.hpp
+
+use super::{
+    DiscreteDistribution, DiscreteQuantile, Distribution,
+    sealed::{Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::c_int;
+
+/// Geometric distribution
+///
+/// The number of failures before the first success in independent trials, each with success
+/// probability *p*. This is a special case of the [`NegativeBinomial`](super::NegativeBinomial)
+/// distribution with *r = 1*.
+///
+/// Corresponds to `boost::math::geometric_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/geometric_dist.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "geometric_distribution")]
+pub struct Geometric {
+    p: f64,
+    rounding: DiscreteQuantile,
+}
+
+impl Geometric {
+    /// Geometric distribution with success fraction *0 ≤ p ≤ 1*
+    pub fn new(p: f64) -> Self {
+        Self {
+            p,
+            rounding: DiscreteQuantile::default(),
+        }
+    }
+
+    /// Use the given rounding policy for the quantiles
+    pub fn with_rounding(self, rounding: DiscreteQuantile) -> Self {
+        Self { rounding, ..self }
+    }
+
+    /// Success fraction *p*
+    pub fn success_fraction(&self) -> f64 {
+        self.p
+    }
+
+    /// Rounding policy for the quantiles
+    pub fn rounding(&self) -> DiscreteQuantile {
+        self.rounding
+    }
+}
+
+impl Sealed for Geometric {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe { ffi::math_geometric_distribution(self.p, self.rounding as c_int, op as c_int, x) }
+    }
+}
+
+impl Distribution for Geometric {}
+impl DiscreteDistribution for Geometric {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{
+        DiscreteDistribution, DiscreteQuantile, Distribution, Geometric,
+    };
+
+    const RTOL: f64 = 8.0 * f64::EPSILON;
+
+    #[test]
+    fn test_geometric() {
+        // P(X = k) = p (1 - p)^k
+        let d = Geometric::new(0.5);
+        assert_relative_eq!(d.pmf(0.0), 0.5, max_relative = RTOL);
+        assert_relative_eq!(d.pmf(2.0), 0.125, max_relative = RTOL);
+        assert_relative_eq!(d.cdf(1.0), 0.75, max_relative = RTOL);
+        assert_relative_eq!(d.cdf_complement(1.0), 0.25, max_relative = RTOL);
+        assert_eq!(d.mean(), 1.0);
+        assert_eq!(d.variance(), 2.0);
+        assert_eq!(d.mode(), 0.0);
+    }
+
+    #[test]
+    fn test_geometric_quantile_rounding() {
+        // F(0) = 0.5 < 0.6 < F(1) = 0.75
+        let d = Geometric::new(0.5);
+        assert_eq!(
+            d.with_rounding(DiscreteQuantile::IntegerRoundUp)
+                .quantile(0.6),
+            1.0
+        );
+        assert_eq!(
+            d.with_rounding(DiscreteQuantile::IntegerRoundDown)
+                .quantile(0.6),
+            0.0
+        );
+    }
+}
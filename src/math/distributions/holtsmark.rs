@@ -0,0 +1,71 @@
+//! boost/math/distributions/holtsmark.hpp
+
+use super::{
+    Distribution,
+    sealed::{Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::c_int;
+
+/// Holtsmark distribution
+///
+/// This is the symmetric stable distribution with *α = 3/2*. Its variance and higher moments are
+/// undefined.
+///
+/// Corresponds to `boost::math::holtsmark_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/holtsmark_dist.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "holtsmark_distribution")]
+pub struct Holtsmark {
+    location: f64,
+    scale: f64,
+}
+
+impl Holtsmark {
+    /// Holtsmark distribution with location *μ* and scale *c > 0*
+    pub fn new(location: f64, scale: f64) -> Self {
+        Self { location, scale }
+    }
+
+    /// Location parameter *μ*
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Scale parameter *c*
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+/// Holtsmark distribution with location 0 and scale 1
+impl Default for Holtsmark {
+    fn default() -> Self {
+        Self::new(0.0, 1.0)
+    }
+}
+
+impl Sealed for Holtsmark {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe { ffi::math_holtsmark_distribution(self.location, self.scale, op as c_int, x) }
+    }
+}
+
+impl Distribution for Holtsmark {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{Distribution, Holtsmark};
+
+    const RTOL: f64 = 1e-12;
+
+    #[test]
+    fn test_holtsmark() {
+        let d = Holtsmark::default();
+        assert_eq!(d.cdf(0.0), 0.5);
+        assert_relative_eq!(d.cdf(-1.5), d.cdf_complement(1.5), max_relative = RTOL);
+        assert_relative_eq!(d.quantile(d.cdf(1.5)), 1.5, max_relative = RTOL);
+        assert_eq!(d.mean(), 0.0);
+        assert_eq!(d.median(), 0.0);
+    }
+}
@@ -0,0 +1,114 @@
+//! boost/math/distributions/hyperexponential.hpp
+
+use super::{
+    Distribution,
+    sealed::{Op, Sealed},
+};
+use crate::ffi;
+use alloc::{vec, vec::Vec};
+use core::ffi::c_int;
+
+/// Hyperexponential distribution
+///
+/// A mixture of *k* [`Exponential`](super::Exponential) distributions (phases) with rates
+/// *λ<sub>i</sub>*, each chosen with probability *α<sub>i</sub>*.
+///
+/// Corresponds to `boost::math::hyperexponential_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/hyperexponential_dist.html>
+#[derive(Clone, Debug, PartialEq)]
+#[doc(alias = "hyperexponential_distribution")]
+pub struct Hyperexponential {
+    probabilities: Vec<f64>,
+    rates: Vec<f64>,
+}
+
+impl Hyperexponential {
+    /// Hyperexponential distribution with phase probabilities *α<sub>i</sub> ≥ 0* and rates
+    /// *λ<sub>i</sub> > 0*
+    ///
+    /// The probabilities are normalized so that they sum to 1. Both slices must have the same,
+    /// non-zero length.
+    pub fn new(probabilities: &[f64], rates: &[f64]) -> Self {
+        let sum: f64 = probabilities.iter().sum();
+        Self {
+            probabilities: probabilities.iter().map(|p| p / sum).collect(),
+            rates: rates.to_vec(),
+        }
+    }
+
+    /// Hyperexponential distribution with rates *λ<sub>i</sub> > 0*, where all phases are
+    /// equally likely
+    pub fn from_rates(rates: &[f64]) -> Self {
+        let p = 1.0 / rates.len() as f64;
+        Self {
+            probabilities: vec![p; rates.len()],
+            rates: rates.to_vec(),
+        }
+    }
+
+    /// Normalized phase probabilities *α<sub>i</sub>*
+    pub fn probabilities(&self) -> &[f64] {
+        &self.probabilities
+    }
+
+    /// Rates *λ<sub>i</sub>* of the phases
+    pub fn rates(&self) -> &[f64] {
+        &self.rates
+    }
+
+    /// Number of phases *k*
+    pub fn num_phases(&self) -> usize {
+        self.rates.len()
+    }
+}
+
+impl Sealed for Hyperexponential {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        let k = self.rates.len();
+        if k == 0 || self.probabilities.len() != k {
+            // special casing to avoid out-of-bounds reads in Boost
+            return f64::NAN;
+        }
+        let (probabilities, rates) = (self.probabilities.as_ptr(), self.rates.as_ptr());
+        unsafe { ffi::math_hyperexponential_distribution(probabilities, rates, k, op as c_int, x) }
+    }
+}
+
+impl Distribution for Hyperexponential {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{Distribution, Exponential, Hyperexponential};
+
+    const RTOL: f64 = 8.0 * f64::EPSILON;
+
+    #[test]
+    fn test_hyperexponential() {
+        // f(x) = 0.3 exp(-x) + 1.4 exp(-2x)
+        let d = Hyperexponential::new(&[3.0, 7.0], &[1.0, 2.0]);
+        assert_eq!(d.probabilities(), &[0.3, 0.7]);
+        assert_eq!(d.num_phases(), 2);
+        let (e1, e2) = ((-1.0f64).exp(), (-2.0f64).exp());
+        assert_relative_eq!(d.pdf(1.0), 0.3 * e1 + 1.4 * e2, max_relative = RTOL);
+        assert_relative_eq!(
+            d.cdf_complement(1.0),
+            0.3 * e1 + 0.7 * e2,
+            max_relative = RTOL
+        );
+        assert_relative_eq!(d.quantile(d.cdf(1.0)), 1.0, max_relative = 1e-12);
+        assert_relative_eq!(d.mean(), 0.65, max_relative = RTOL);
+
+        // a single phase is an exponential distribution
+        let d = Hyperexponential::from_rates(&[2.0]);
+        let e = Exponential::new(2.0);
+        assert_relative_eq!(d.cdf(0.5), e.cdf(0.5), max_relative = RTOL);
+        assert_relative_eq!(d.variance(), e.variance(), max_relative = RTOL);
+    }
+
+    #[test]
+    fn test_hyperexponential_invalid() {
+        assert!(Hyperexponential::new(&[0.5, 0.5], &[1.0]).pdf(1.0).is_nan());
+        assert!(Hyperexponential::from_rates(&[]).pdf(1.0).is_nan());
+        assert!(Hyperexponential::from_rates(&[1.0, -1.0]).pdf(1.0).is_nan());
+    }
+}
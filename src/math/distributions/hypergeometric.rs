@@ -0,0 +1,100 @@
+//! boost/math/distributions/hypergeometric.hpp
+
+use super::{
+    DiscreteDistribution, DiscreteQuantile, Distribution,
+    sealed::{Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::{c_int, c_uint};
+
+/// Hypergeometric distribution
+///
+/// The number of "defective" items in a sample of size *n*, drawn without replacement from a
+/// population of *N* items, of which *r* are defective.
+///
+/// Corresponds to `boost::math::hypergeometric_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/hypergeometric_dist.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "hypergeometric_distribution")]
+pub struct Hypergeometric {
+    r: u32,
+    n: u32,
+    total: u32,
+    rounding: DiscreteQuantile,
+}
+
+impl Hypergeometric {
+    /// Hypergeometric distribution with *r* defective items, sample size *n*, and population
+    /// size *N*, where *r ≤ N* and *n ≤ N*
+    pub fn new(r: u32, n: u32, total: u32) -> Self {
+        Self {
+            r,
+            n,
+            total,
+            rounding: DiscreteQuantile::default(),
+        }
+    }
+
+    /// Use the given rounding policy for the quantiles
+    pub fn with_rounding(self, rounding: DiscreteQuantile) -> Self {
+        Self { rounding, ..self }
+    }
+
+    /// Number of defective items in the population *r*
+    pub fn defective(&self) -> u32 {
+        self.r
+    }
+
+    /// Sample size *n*
+    pub fn sample_count(&self) -> u32 {
+        self.n
+    }
+
+    /// Population size *N*
+    pub fn total(&self) -> u32 {
+        self.total
+    }
+
+    /// Rounding policy for the quantiles
+    pub fn rounding(&self) -> DiscreteQuantile {
+        self.rounding
+    }
+}
+
+impl Sealed for Hypergeometric {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe {
+            ffi::math_hypergeometric_distribution(
+                self.r as c_uint,
+                self.n as c_uint,
+                self.total as c_uint,
+                self.rounding as c_int,
+                op as c_int,
+                x,
+            )
+        }
+    }
+}
+
+impl Distribution for Hypergeometric {}
+impl DiscreteDistribution for Hypergeometric {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{DiscreteDistribution, Distribution, Hypergeometric};
+
+    const RTOL: f64 = 8.0 * f64::EPSILON;
+
+    #[test]
+    fn test_hypergeometric() {
+        // P(X = k) = C(2, k) C(2, 2 - k) / C(4, 2)
+        let d = Hypergeometric::new(2, 2, 4);
+        assert_relative_eq!(d.pmf(0.0), 1.0 / 6.0, max_relative = RTOL);
+        assert_relative_eq!(d.pmf(1.0), 4.0 / 6.0, max_relative = RTOL);
+        assert_relative_eq!(d.pmf(2.0), 1.0 / 6.0, max_relative = RTOL);
+        assert_relative_eq!(d.cdf(1.0), 5.0 / 6.0, max_relative = RTOL);
+        assert_relative_eq!(d.cdf_complement(1.0), 1.0 / 6.0, max_relative = RTOL);
+        assert_relative_eq!(d.mean(), 1.0, max_relative = RTOL);
+        assert_eq!(d.support(), (0.0, 2.0));
+    }
+}
@@ -0,0 +1,71 @@
+//! boost/math/distributions/kolmogorov_smirnov.hpp
+
+use super::{
+    Distribution,
+    sealed::{Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::c_int;
+
+/// Kolmogorov-Smirnov distribution
+///
+/// The asymptotic distribution of the one-sample Kolmogorov-Smirnov statistic *D<sub>n</sub>*, i.e.
+/// the supremum of the difference between the empirical and the true cdf of *n* observations.
+///
+/// Corresponds to `boost::math::kolmogorov_smirnov_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/kolmogorov_smirnov_dist.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "kolmogorov_smirnov_distribution")]
+pub struct KolmogorovSmirnov {
+    n: f64,
+}
+
+impl KolmogorovSmirnov {
+    /// Kolmogorov-Smirnov distribution for *n > 0* observations
+    pub fn new(n: f64) -> Self {
+        Self { n }
+    }
+
+    /// Number of observations *n*
+    pub fn number_of_observations(&self) -> f64 {
+        self.n
+    }
+}
+
+impl Sealed for KolmogorovSmirnov {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe { ffi::math_kolmogorov_smirnov_distribution(self.n, op as c_int, x) }
+    }
+}
+
+impl Distribution for KolmogorovSmirnov {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{Distribution, KolmogorovSmirnov};
+    use core::f64::consts::{FRAC_PI_2, LN_2};
+
+    const RTOL: f64 = 1e-13;
+
+    #[test]
+    fn test_kolmogorov_smirnov() {
+        // K(x) = 1 - 2 Σ (-1)^(k-1) exp(-2k²x²)
+        let kolmogorov = |x: f64| {
+            let sum: f64 = (1..10)
+                .map(|k| (-1.0f64).powi(k - 1) * (-2.0 * f64::from(k * k) * x * x).exp())
+                .sum();
+            1.0 - 2.0 * sum
+        };
+
+        let d = KolmogorovSmirnov::new(1.0);
+        assert_relative_eq!(d.cdf(1.0), kolmogorov(1.0), max_relative = RTOL);
+        assert_relative_eq!(d.mean(), FRAC_PI_2.sqrt() * LN_2, max_relative = RTOL);
+
+        // D_n scales with 1/√n
+        let d = KolmogorovSmirnov::new(100.0);
+        assert_relative_eq!(d.cdf(0.1), kolmogorov(1.0), max_relative = RTOL);
+        assert_relative_eq!(d.quantile(d.cdf(0.15)), 0.15, max_relative = RTOL);
+        // critical value for α = 0.05
+        assert_relative_eq!(d.quantile_complement(0.05), 0.135_81, max_relative = 1e-4);
+    }
+}
@@ -0,0 +1,71 @@
+//! boost/math/distributions/landau.hpp
+
+use super::{
+    Distribution,
+    sealed::{Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::c_int;
+
+/// Landau distribution
+///
+/// This is the stable distribution with *α = 1* and *β = 1*. Its mean and higher moments are
+/// undefined, so those accessors return NaN.
+///
+/// Corresponds to `boost::math::landau_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/landau_dist.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "landau_distribution")]
+pub struct Landau {
+    location: f64,
+    scale: f64,
+}
+
+impl Landau {
+    /// Landau distribution with location *μ* and scale *c > 0*
+    pub fn new(location: f64, scale: f64) -> Self {
+        Self { location, scale }
+    }
+
+    /// Location parameter *μ*
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Scale parameter *c*
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+/// Landau distribution with location 0 and scale 1
+impl Default for Landau {
+    fn default() -> Self {
+        Self::new(0.0, 1.0)
+    }
+}
+
+impl Sealed for Landau {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe { ffi::math_landau_distribution(self.location, self.scale, op as c_int, x) }
+    }
+}
+
+impl Distribution for Landau {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{Distribution, Landau};
+
+    const RTOL: f64 = 1e-12;
+
+    #[test]
+    fn test_landau() {
+        let d = Landau::default();
+        assert!(d.pdf(0.0) > 0.0);
+        assert_relative_eq!(d.quantile(d.cdf(1.5)), 1.5, max_relative = RTOL);
+        assert_relative_eq!(d.cdf(1.5) + d.cdf_complement(1.5), 1.0, max_relative = RTOL);
+        assert!(d.mean().is_nan());
+        assert!(d.variance().is_nan());
+    }
+}
@@ -0,0 +1,84 @@
+//! boost/math/distributions/laplace.hpp
+
+use super::{
+    Distribution, LocationScale,
+    sealed::{Find, FindOp, Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::c_int;
+
+/// Laplace (double exponential) distribution
+///
+/// Corresponds to `boost::math::laplace_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/laplace_dist.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "laplace_distribution")]
+#[doc(alias = "double_exponential")]
+pub struct Laplace {
+    location: f64,
+    scale: f64,
+}
+
+impl Laplace {
+    /// Laplace distribution with location *μ* and scale *b > 0*
+    pub fn new(location: f64, scale: f64) -> Self {
+        Self { location, scale }
+    }
+
+    /// Location parameter *μ*
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Scale parameter *b*
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+/// Standard Laplace distribution with location 0 and scale 1
+impl Default for Laplace {
+    fn default() -> Self {
+        Self::new(0.0, 1.0)
+    }
+}
+
+impl Sealed for Laplace {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe { ffi::math_laplace_distribution(self.location, self.scale, op as c_int, x) }
+    }
+}
+
+impl Distribution for Laplace {}
+
+impl Find for Laplace {
+    fn find(op: FindOp, z: f64, p: f64, param: f64) -> f64 {
+        unsafe { ffi::math_laplace_find(op as c_int, z, p, param) }
+    }
+}
+
+impl LocationScale for Laplace {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{Distribution, Laplace};
+
+    const RTOL: f64 = 4.0 * f64::EPSILON;
+
+    #[test]
+    fn test_laplace() {
+        let d = Laplace::default();
+        assert_eq!(d.pdf(0.0), 0.5);
+        assert_eq!(d.cdf(0.0), 0.5);
+        assert_relative_eq!(d.cdf(1.0), 1.0 - 0.5 * (-1.0f64).exp(), max_relative = RTOL);
+        assert_relative_eq!(
+            d.cdf_complement(1.0),
+            0.5 * (-1.0f64).exp(),
+            max_relative = RTOL
+        );
+        assert_relative_eq!(d.quantile(0.75), 2.0f64.ln(), max_relative = RTOL);
+        assert_eq!(d.mean(), 0.0);
+        assert_eq!(d.variance(), 2.0);
+        assert_eq!(d.kurtosis_excess(), 3.0);
+    }
+}
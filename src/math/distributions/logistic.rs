@@ -0,0 +1,84 @@
+//! boost/math/distributions/logistic.hpp
+
+use super::{
+    Distribution, LocationScale,
+    sealed::{Find, FindOp, Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::c_int;
+
+/// Logistic distribution
+///
+/// Corresponds to `boost::math::logistic_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/logistic_dist.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "logistic_distribution")]
+pub struct Logistic {
+    location: f64,
+    scale: f64,
+}
+
+impl Logistic {
+    /// Logistic distribution with location *μ* and scale *s > 0*
+    pub fn new(location: f64, scale: f64) -> Self {
+        Self { location, scale }
+    }
+
+    /// Location parameter *μ*
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Scale parameter *s*
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+/// Standard logistic distribution with location 0 and scale 1
+impl Default for Logistic {
+    fn default() -> Self {
+        Self::new(0.0, 1.0)
+    }
+}
+
+impl Sealed for Logistic {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe { ffi::math_logistic_distribution(self.location, self.scale, op as c_int, x) }
+    }
+}
+
+impl Distribution for Logistic {}
+
+impl Find for Logistic {
+    fn find(op: FindOp, z: f64, p: f64, param: f64) -> f64 {
+        unsafe { ffi::math_logistic_find(op as c_int, z, p, param) }
+    }
+}
+
+impl LocationScale for Logistic {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{Distribution, Logistic};
+    use core::f64::consts::PI;
+
+    const RTOL: f64 = 4.0 * f64::EPSILON;
+
+    #[test]
+    fn test_logistic() {
+        // F(x) = 1 / (1 + exp(-(x - μ) / s))
+        let d = Logistic::default();
+        assert_eq!(d.pdf(0.0), 0.25);
+        assert_eq!(d.cdf(0.0), 0.5);
+        assert_relative_eq!(
+            d.cdf(1.0),
+            1.0 / (1.0 + (-1.0f64).exp()),
+            max_relative = RTOL
+        );
+        assert_relative_eq!(d.quantile(0.75), 3.0f64.ln(), max_relative = RTOL);
+        assert_eq!(d.mean(), 0.0);
+        assert_relative_eq!(d.variance(), PI * PI / 3.0, max_relative = RTOL);
+        assert_relative_eq!(d.kurtosis_excess(), 1.2, max_relative = RTOL);
+    }
+}
@@ -0,0 +1,73 @@
+//! boost/math/distributions/lognormal.hpp
+
+use super::{
+    Distribution,
+    sealed::{Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::c_int;
+
+/// Log-normal distribution
+///
+/// That is, the distribution of *e<sup>X</sup>* where *X ~ N(μ, σ<sup>2</sup>)*.
+///
+/// Corresponds to `boost::math::lognormal_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/lognormal_dist.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "lognormal_distribution")]
+pub struct LogNormal {
+    location: f64,
+    scale: f64,
+}
+
+impl LogNormal {
+    /// Log-normal distribution with location *μ* and scale *σ > 0* of the underlying normal
+    /// distribution
+    pub fn new(location: f64, scale: f64) -> Self {
+        Self { location, scale }
+    }
+
+    /// Location parameter *μ*
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Scale parameter *σ*
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+/// Standard log-normal distribution with location 0 and scale 1
+impl Default for LogNormal {
+    fn default() -> Self {
+        Self::new(0.0, 1.0)
+    }
+}
+
+impl Sealed for LogNormal {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe { ffi::math_lognormal_distribution(self.location, self.scale, op as c_int, x) }
+    }
+}
+
+impl Distribution for LogNormal {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{Distribution, LogNormal};
+    use core::f64::consts::E;
+
+    const RTOL: f64 = 4.0 * f64::EPSILON;
+
+    #[test]
+    fn test_lognormal() {
+        let d = LogNormal::new(1.0, 0.5);
+        assert_relative_eq!(d.cdf(E), 0.5, max_relative = RTOL);
+        assert_relative_eq!(d.median(), E, max_relative = RTOL);
+        assert_relative_eq!(d.mean(), E.powf(1.125), max_relative = RTOL);
+        assert_relative_eq!(d.mode(), E.powf(0.75), max_relative = RTOL);
+        assert_eq!(d.pdf(0.0), 0.0);
+        assert_eq!(d.cdf(0.0), 0.0);
+    }
+}
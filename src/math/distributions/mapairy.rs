@@ -0,0 +1,70 @@
+//! boost/math/distributions/mapairy.hpp
+
+use super::{
+    Distribution,
+    sealed::{Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::c_int;
+
+/// Map-Airy distribution
+///
+/// This is the stable distribution with *α = 3/2* and *β = 1*. Its variance and higher moments
+/// are undefined.
+///
+/// Corresponds to `boost::math::mapairy_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/mapairy_dist.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "mapairy_distribution")]
+pub struct MapAiry {
+    location: f64,
+    scale: f64,
+}
+
+impl MapAiry {
+    /// Map-Airy distribution with location *μ* and scale *c > 0*
+    pub fn new(location: f64, scale: f64) -> Self {
+        Self { location, scale }
+    }
+
+    /// Location parameter *μ*
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Scale parameter *c*
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+/// Map-Airy distribution with location 0 and scale 1
+impl Default for MapAiry {
+    fn default() -> Self {
+        Self::new(0.0, 1.0)
+    }
+}
+
+impl Sealed for MapAiry {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe { ffi::math_mapairy_distribution(self.location, self.scale, op as c_int, x) }
+    }
+}
+
+impl Distribution for MapAiry {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{Distribution, MapAiry};
+
+    const RTOL: f64 = 1e-12;
+
+    #[test]
+    fn test_mapairy() {
+        let d = MapAiry::default();
+        assert!(d.pdf(0.0) > 0.0);
+        assert_relative_eq!(d.quantile(d.cdf(1.5)), 1.5, max_relative = RTOL);
+        assert_relative_eq!(d.cdf(1.5) + d.cdf_complement(1.5), 1.0, max_relative = RTOL);
+        assert_eq!(d.mean(), 0.0);
+    }
+}
@@ -0,0 +1,398 @@
+//! Statistical Distributions
+//!
+//! Corresponds to the Boost Math `boost/math/distributions` C++ headers.
+//! <https://boost.org/doc/libs/latest/libs/math/doc/html/dist.html>
+//!
+//! All distributions implement the [`Distribution`] trait, which provides the (non-member)
+//! accessor functions that Boost defines for each of its distribution classes.
+//!
+//! Parameters are validated by Boost in the same way as in C++, but instead of raising an
+//! exception, invalid parameters or arguments result in a NaN.
+//!
+//! ## Continuous Distributions
+//!
+//! - [`Arcsine`]
+//! - [`Beta`]
+//! - [`Cauchy`]
+//! - [`ChiSquared`]
+//! - [`Exponential`]
+//! - [`ExtremeValue`]
+//! - [`FisherF`]
+//! - [`Gamma`]
+//! - [`Holtsmark`]
+//! - [`Hyperexponential`]
+//! - [`InverseChiSquared`]
+//! - [`InverseGamma`]
+//! - [`InverseGaussian`]
+//! - [`KolmogorovSmirnov`]
+//! - [`Landau`]
+//! - [`Laplace`]
+//! - [`LogNormal`]
+//! - [`Logistic`]
+//! - [`MapAiry`]
+//! - [`NonCentralBeta`]
+//! - [`NonCentralChiSquared`]
+//! - [`NonCentralF`]
+//! - [`NonCentralT`]
+//! - [`Normal`]
+//! - [`Pareto`]
+//! - [`Rayleigh`]
+//! - [`SaSPoint5`]
+//! - [`SkewNormal`]
+//! - [`StudentsT`]
+//! - [`Triangular`]
+//! - [`Uniform`]
+//! - [`Weibull`]
+//!
+//! ## Discrete Distributions
+//!
+//! - [`Bernoulli`]
+//! - [`Binomial`]
+//! - [`Geometric`]
+//! - [`Hypergeometric`]
+//! - [`NegativeBinomial`]
+//! - [`Poisson`]
+//!
+//! Discrete distributions also implement the [`DiscreteDistribution`] trait. The rounding of their
+//! quantiles can be chosen with a [`DiscreteQuantile`] policy.
+//!
+//! ## Empirical Distributions
+//!
+//! - [`EmpiricalCdf`]
+//!
+//! ## Parameter Finders
+//!
+//! - [`find_location`] and [`find_scale`] for [`LocationScale`] distributions
+//! - [`StudentsT::find_degrees_of_freedom`] and [`ChiSquared::find_degrees_of_freedom`]
+//! - [`Binomial::find_lower_bound_on_p`], [`Binomial::find_upper_bound_on_p`], and
+//!   [`Binomial::find_minimum_number_of_trials`]
+//! - [`NegativeBinomial::find_minimum_number_of_trials`]
+//! - [`Poisson::find_lower_bound_on_mean`] and [`Poisson::find_upper_bound_on_mean`]
+//! - [`NonCentralChiSquared::find_degrees_of_freedom`] and
+//!   [`NonCentralChiSquared::find_non_centrality`]
+
+mod arcsine;
+mod bernoulli;
+mod beta;
+mod binomial;
+mod cauchy;
+mod chi_squared;
+mod empirical_cumulative_distribution_function;
+mod exponential;
+mod extreme_value;
+mod find_location;
+mod find_scale;
+mod fisher_f;
+mod gamma;
+mod geometric;
+mod holtsmark;
+mod hyperexponential;
+mod hypergeometric;
+mod inverse_chi_squared;
+mod inverse_gamma;
+mod inverse_gaussian;
+mod kolmogorov_smirnov;
+mod landau;
+mod laplace;
+mod logistic;
+mod lognormal;
+mod mapairy;
+mod negative_binomial;
+mod non_central_beta;
+mod non_central_chi_squared;
+mod non_central_f;
+mod non_central_t;
+mod normal;
+mod pareto;
+mod poisson;
+mod rayleigh;
+mod saspoint5;
+mod skew_normal;
+mod students_t;
+mod triangular;
+mod uniform;
+mod weibull;
+
+pub use arcsine::*;
+pub use bernoulli::*;
+pub use beta::*;
+pub use binomial::*;
+pub use cauchy::*;
+pub use chi_squared::*;
+pub use empirical_cumulative_distribution_function::*;
+pub use exponential::*;
+pub use extreme_value::*;
+pub use find_location::*;
+pub use find_scale::*;
+pub use fisher_f::*;
+pub use gamma::*;
+pub use geometric::*;
+pub use holtsmark::*;
+pub use hyperexponential::*;
+pub use hypergeometric::*;
+pub use inverse_chi_squared::*;
+pub use inverse_gamma::*;
+pub use inverse_gaussian::*;
+pub use kolmogorov_smirnov::*;
+pub use landau::*;
+pub use laplace::*;
+pub use logistic::*;
+pub use lognormal::*;
+pub use mapairy::*;
+pub use negative_binomial::*;
+pub use non_central_beta::*;
+pub use non_central_chi_squared::*;
+pub use non_central_f::*;
+pub use non_central_t::*;
+pub use normal::*;
+pub use pareto::*;
+pub use poisson::*;
+pub use rayleigh::*;
+pub use saspoint5::*;
+pub use skew_normal::*;
+pub use students_t::*;
+pub use triangular::*;
+pub use uniform::*;
+pub use weibull::*;
+
+mod sealed {
+    /// Accessor of a distribution, passed to the C++ wrapper.
+    ///
+    /// Must be kept in sync with `detail::distribution_op` in `wrapper.cpp`.
+    #[derive(Clone, Copy, Debug)]
+    #[repr(i32)]
+    pub enum Op {
+        Pdf = 0,
+        Cdf,
+        CdfComplement,
+        Quantile,
+        QuantileComplement,
+        Hazard,
+        Chf,
+        Mean,
+        Variance,
+        StandardDeviation,
+        Skewness,
+        Kurtosis,
+        KurtosisExcess,
+        Mode,
+        Median,
+        SupportLower,
+        SupportUpper,
+    }
+
+    /// Dispatches an [`Op`] to the C++ distribution.
+    pub trait Sealed {
+        fn call(&self, op: Op, x: f64) -> f64;
+    }
+
+    /// Parameter of a location-scale distribution to solve for, passed to the C++ wrapper.
+    ///
+    /// Must be kept in sync with `detail::find_op` in `wrapper.cpp`.
+    #[derive(Clone, Copy, Debug)]
+    #[repr(i32)]
+    pub enum FindOp {
+        Location = 0,
+        LocationComplement,
+        Scale,
+        ScaleComplement,
+    }
+
+    /// Dispatches a [`FindOp`] to `boost::math::find_location` or `boost::math::find_scale`.
+    pub trait Find {
+        fn find(op: FindOp, z: f64, p: f64, param: f64) -> f64;
+    }
+}
+
+use sealed::Op;
+
+/// Non-member accessor functions of a Boost Math distribution
+///
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/nmp.html>
+pub trait Distribution: sealed::Sealed {
+    /// Probability density function *f(x)*
+    ///
+    /// For discrete distributions this is the probability mass function.
+    ///
+    /// Corresponds to `boost::math::pdf(dist, x)` in C++.
+    fn pdf(&self, x: f64) -> f64 {
+        self.call(Op::Pdf, x)
+    }
+
+    /// Cumulative distribution function *F(x) = P(X ≤ x)*
+    ///
+    /// Corresponds to `boost::math::cdf(dist, x)` in C++.
+    fn cdf(&self, x: f64) -> f64 {
+        self.call(Op::Cdf, x)
+    }
+
+    /// Complement of the cumulative distribution function *1 - F(x) = P(X > x)*
+    ///
+    /// This is also known as the survival function.
+    ///
+    /// Corresponds to `boost::math::cdf(complement(dist, x))` in C++.
+    #[doc(alias = "sf")]
+    #[doc(alias = "survival_function")]
+    fn cdf_complement(&self, x: f64) -> f64 {
+        self.call(Op::CdfComplement, x)
+    }
+
+    /// Inverse of [`cdf`](Distribution::cdf), i.e. the value *x* for which *F(x) = p*
+    ///
+    /// Corresponds to `boost::math::quantile(dist, p)` in C++.
+    #[doc(alias = "ppf")]
+    fn quantile(&self, p: f64) -> f64 {
+        self.call(Op::Quantile, p)
+    }
+
+    /// Inverse of [`cdf_complement`](Distribution::cdf_complement), i.e. the value *x* for which
+    /// *1 - F(x) = q*
+    ///
+    /// Corresponds to `boost::math::quantile(complement(dist, q))` in C++.
+    #[doc(alias = "isf")]
+    fn quantile_complement(&self, q: f64) -> f64 {
+        self.call(Op::QuantileComplement, q)
+    }
+
+    /// Hazard function *h(x) = f(x) / (1 - F(x))*
+    ///
+    /// Corresponds to `boost::math::hazard(dist, x)` in C++.
+    fn hazard(&self, x: f64) -> f64 {
+        self.call(Op::Hazard, x)
+    }
+
+    /// Cumulative hazard function *H(x) = -ln(1 - F(x))*
+    ///
+    /// Corresponds to `boost::math::chf(dist, x)` in C++.
+    fn chf(&self, x: f64) -> f64 {
+        self.call(Op::Chf, x)
+    }
+
+    /// Mean *E\[X\]*
+    ///
+    /// Corresponds to `boost::math::mean(dist)` in C++.
+    fn mean(&self) -> f64 {
+        self.call(Op::Mean, f64::NAN)
+    }
+
+    /// Variance *Var\[X\]*
+    ///
+    /// Corresponds to `boost::math::variance(dist)` in C++.
+    fn variance(&self) -> f64 {
+        self.call(Op::Variance, f64::NAN)
+    }
+
+    /// Standard deviation *√Var\[X\]*
+    ///
+    /// Corresponds to `boost::math::standard_deviation(dist)` in C++.
+    fn standard_deviation(&self) -> f64 {
+        self.call(Op::StandardDeviation, f64::NAN)
+    }
+
+    /// Skewness
+    ///
+    /// Corresponds to `boost::math::skewness(dist)` in C++.
+    fn skewness(&self) -> f64 {
+        self.call(Op::Skewness, f64::NAN)
+    }
+
+    /// Kurtosis
+    ///
+    /// See [`kurtosis_excess`](Distribution::kurtosis_excess) for the excess kurtosis.
+    ///
+    /// Corresponds to `boost::math::kurtosis(dist)` in C++.
+    fn kurtosis(&self) -> f64 {
+        self.call(Op::Kurtosis, f64::NAN)
+    }
+
+    /// Excess kurtosis, i.e. the [`kurtosis`](Distribution::kurtosis) minus 3
+    ///
+    /// Corresponds to `boost::math::kurtosis_excess(dist)` in C++.
+    fn kurtosis_excess(&self) -> f64 {
+        self.call(Op::KurtosisExcess, f64::NAN)
+    }
+
+    /// Mode, i.e. the location of the maximum of the [`pdf`](Distribution::pdf)
+    ///
+    /// Corresponds to `boost::math::mode(dist)` in C++.
+    fn mode(&self) -> f64 {
+        self.call(Op::Mode, f64::NAN)
+    }
+
+    /// Median, i.e. the 50% [`quantile`](Distribution::quantile)
+    ///
+    /// Corresponds to `boost::math::median(dist)` in C++.
+    fn median(&self) -> f64 {
+        self.call(Op::Median, f64::NAN)
+    }
+
+    /// Lower and upper bounds of the support, i.e. the range where the pdf is non-zero
+    ///
+    /// Corresponds to `boost::math::support(dist)` in C++.
+    fn support(&self) -> (f64, f64) {
+        (
+            self.call(Op::SupportLower, f64::NAN),
+            self.call(Op::SupportUpper, f64::NAN),
+        )
+    }
+}
+
+/// Accessor functions specific to discrete distributions
+pub trait DiscreteDistribution: Distribution {
+    /// Probability mass function *P(X = k)*
+    ///
+    /// This is the same as [`pdf`](Distribution::pdf).
+    ///
+    /// Corresponds to `boost::math::pdf(dist, k)` in C++.
+    fn pmf(&self, k: f64) -> f64 {
+        self.pdf(k)
+    }
+}
+
+/// Location-scale family of distributions
+///
+/// These can be used with [`find_location`] and [`find_scale`].
+pub trait LocationScale: Distribution + sealed::Find {}
+
+/// Rounding policy for the quantiles of discrete distributions
+///
+/// For a discrete distribution, there is generally no integer *k* for which *F(k) = p* holds
+/// exactly. This policy determines how the real-valued result of the inverse is mapped to an
+/// integer.
+///
+/// Corresponds to the `boost::math::policies::discrete_quantile<...>` policy in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/pol_ref/discrete_quant_ref.html>
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum DiscreteQuantile {
+    /// Don't round, and return the real-valued result of the continuous inverse
+    ///
+    /// Corresponds to `boost::math::policies::real` in C++.
+    Real = 0,
+    /// Round lower quantiles (*p < 0.5*) down and upper quantiles (*p > 0.5*) up
+    ///
+    /// This way, the coverage of a two-sided interval is at least the requested coverage.
+    /// This is the default policy.
+    ///
+    /// Corresponds to `boost::math::policies::integer_round_outwards` in C++.
+    #[default]
+    IntegerRoundOutwards,
+    /// Round lower quantiles (*p < 0.5*) up and upper quantiles (*p > 0.5*) down
+    ///
+    /// This way, the coverage of a two-sided interval is at most the requested coverage.
+    ///
+    /// Corresponds to `boost::math::policies::integer_round_inwards` in C++.
+    IntegerRoundInwards,
+    /// Always round down
+    ///
+    /// Corresponds to `boost::math::policies::integer_round_down` in C++.
+    IntegerRoundDown,
+    /// Always round up
+    ///
+    /// Corresponds to `boost::math::policies::integer_round_up` in C++.
+    IntegerRoundUp,
+    /// Round to the nearest integer
+    ///
+    /// Corresponds to `boost::math::policies::integer_round_nearest` in C++.
+    IntegerRoundNearest,
+}
@@ -0,0 +1,117 @@
+//! boost/math/distributions/negative_binomial.hpp
+
+use super::{
+    DiscreteDistribution, DiscreteQuantile, Distribution,
+    sealed::{Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::c_int;
+
+/// Negative binomial distribution *NB(r, p)*
+///
+/// The number of failures before the *r*-th success in independent trials, each with success
+/// probability *p*.
+///
+/// Corresponds to `boost::math::negative_binomial_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/negative_binomial_dist.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "negative_binomial_distribution")]
+#[doc(alias = "Pascal")]
+pub struct NegativeBinomial {
+    r: f64,
+    p: f64,
+    rounding: DiscreteQuantile,
+}
+
+impl NegativeBinomial {
+    /// Negative binomial distribution with *r > 0* successes and success fraction *0 ≤ p ≤ 1*
+    pub fn new(r: f64, p: f64) -> Self {
+        Self {
+            r,
+            p,
+            rounding: DiscreteQuantile::default(),
+        }
+    }
+
+    /// Use the given rounding policy for the quantiles
+    pub fn with_rounding(self, rounding: DiscreteQuantile) -> Self {
+        Self { rounding, ..self }
+    }
+
+    /// Number of successes *r*
+    pub fn successes(&self) -> f64 {
+        self.r
+    }
+
+    /// Success fraction *p*
+    pub fn success_fraction(&self) -> f64 {
+        self.p
+    }
+
+    /// Rounding policy for the quantiles
+    pub fn rounding(&self) -> DiscreteQuantile {
+        self.rounding
+    }
+
+    /// Minimum number of trials required to observe more than `k` failures with probability at
+    /// least *1 - α*, given the success fraction `p`
+    ///
+    /// The result is not rounded, and generally has to be rounded up.
+    ///
+    /// Corresponds to
+    /// `boost::math::negative_binomial_distribution<double>::find_minimum_number_of_trials` in C++.
+    pub fn find_minimum_number_of_trials(k: f64, p: f64, alpha: f64) -> f64 {
+        unsafe { ffi::math_negative_binomial_find_minimum_number_of_trials(k, p, alpha) }
+    }
+}
+
+impl Sealed for NegativeBinomial {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        let rounding = self.rounding as c_int;
+        unsafe {
+            ffi::math_negative_binomial_distribution(self.r, self.p, rounding, op as c_int, x)
+        }
+    }
+}
+
+impl Distribution for NegativeBinomial {}
+impl DiscreteDistribution for NegativeBinomial {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{
+        DiscreteDistribution, DiscreteQuantile, Distribution, NegativeBinomial,
+    };
+
+    const RTOL: f64 = 8.0 * f64::EPSILON;
+
+    #[test]
+    fn test_negative_binomial() {
+        // P(X = k) = (k + 1) p² (1 - p)^k
+        let d = NegativeBinomial::new(2.0, 0.5);
+        assert_relative_eq!(d.pmf(0.0), 0.25, max_relative = RTOL);
+        assert_relative_eq!(d.pmf(1.0), 0.25, max_relative = RTOL);
+        assert_relative_eq!(d.pmf(2.0), 0.1875, max_relative = RTOL);
+        assert_relative_eq!(d.cdf(1.0), 0.5, max_relative = RTOL);
+        assert_relative_eq!(d.cdf_complement(1.0), 0.5, max_relative = RTOL);
+        assert_eq!(d.mean(), 2.0);
+        assert_eq!(d.variance(), 4.0);
+    }
+
+    #[test]
+    fn test_negative_binomial_quantile_rounding() {
+        // F(1) = 0.5 < 0.6 < F(2) = 0.6875
+        let d = NegativeBinomial::new(2.0, 0.5);
+        assert_eq!(d.quantile(0.6), 2.0);
+        let d = d.with_rounding(DiscreteQuantile::IntegerRoundDown);
+        assert_eq!(d.rounding(), DiscreteQuantile::IntegerRoundDown);
+        assert_eq!(d.quantile(0.6), 1.0);
+    }
+    #[test]
+    fn test_negative_binomial_find_minimum_number_of_trials() {
+        let n = NegativeBinomial::find_minimum_number_of_trials(5.0, 0.5, 0.05);
+        assert!(n > 5.0);
+        assert!(NegativeBinomial::find_minimum_number_of_trials(10.0, 0.5, 0.05) > n);
+        assert!(NegativeBinomial::find_minimum_number_of_trials(5.0, 0.5, 0.01) > n);
+    }
+}
@@ -0,0 +1,120 @@
+//! boost/math/distributions/non_central_chi_squared.hpp
+
+use super::{
+    Distribution,
+    sealed::{Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::c_int;
+
+/// Non-central chi-squared distribution *χ'<sup>2</sup>(k, λ)*
+///
+/// Corresponds to `boost::math::non_central_chi_squared_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/nc_chi_squared_dist.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "non_central_chi_squared_distribution")]
+pub struct NonCentralChiSquared {
+    df: f64,
+    lambda: f64,
+}
+
+impl NonCentralChiSquared {
+    /// Non-central chi-squared distribution with *k > 0* degrees of freedom and non-centrality
+    /// parameter *λ ≥ 0*
+    pub fn new(df: f64, lambda: f64) -> Self {
+        Self { df, lambda }
+    }
+
+    /// Degrees of freedom *k*
+    pub fn degrees_of_freedom(&self) -> f64 {
+        self.df
+    }
+
+    /// Non-centrality parameter *λ*
+    pub fn non_centrality(&self) -> f64 {
+        self.lambda
+    }
+
+    /// Degrees of freedom *k* for which *F(x) = p*, given the non-centrality *λ*
+    ///
+    /// Corresponds to
+    /// `boost::math::non_central_chi_squared_distribution<double>::find_degrees_of_freedom` in C++.
+    pub fn find_degrees_of_freedom(lambda: f64, x: f64, p: f64) -> f64 {
+        unsafe { ffi::math_non_central_chi_squared_find_degrees_of_freedom(lambda, x, p) }
+    }
+
+    /// Degrees of freedom *k* for which *1 - F(x) = q*, given the non-centrality *λ*
+    ///
+    /// Corresponds to
+    /// `boost::math::non_central_chi_squared_distribution<double>::find_degrees_of_freedom` with
+    /// `complement(lambda, x, q)` in C++.
+    pub fn find_degrees_of_freedom_complement(lambda: f64, x: f64, q: f64) -> f64 {
+        unsafe { ffi::math_non_central_chi_squared_find_degrees_of_freedom_c(lambda, x, q) }
+    }
+
+    /// Non-centrality *λ* for which *F(x) = p*, given the degrees of freedom *k*
+    ///
+    /// Corresponds to
+    /// `boost::math::non_central_chi_squared_distribution<double>::find_non_centrality` in C++.
+    pub fn find_non_centrality(df: f64, x: f64, p: f64) -> f64 {
+        unsafe { ffi::math_non_central_chi_squared_find_non_centrality(df, x, p) }
+    }
+
+    /// Non-centrality *λ* for which *1 - F(x) = q*, given the degrees of freedom *k*
+    ///
+    /// Corresponds to
+    /// `boost::math::non_central_chi_squared_distribution<double>::find_non_centrality` with
+    /// `complement(df, x, q)` in C++.
+    pub fn find_non_centrality_complement(df: f64, x: f64, q: f64) -> f64 {
+        unsafe { ffi::math_non_central_chi_squared_find_non_centrality_c(df, x, q) }
+    }
+}
+
+impl Sealed for NonCentralChiSquared {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe {
+            ffi::math_non_central_chi_squared_distribution(self.df, self.lambda, op as c_int, x)
+        }
+    }
+}
+
+impl Distribution for NonCentralChiSquared {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{ChiSquared, Distribution, NonCentralChiSquared};
+
+    const RTOL: f64 = 1e-12;
+
+    #[test]
+    fn test_non_central_chi_squared() {
+        let d = NonCentralChiSquared::new(4.0, 2.0);
+        assert_relative_eq!(d.mean(), 6.0, max_relative = RTOL);
+        assert_relative_eq!(d.variance(), 16.0, max_relative = RTOL);
+        assert_relative_eq!(d.quantile(d.cdf(3.0)), 3.0, max_relative = RTOL);
+        assert_relative_eq!(d.cdf(3.0) + d.cdf_complement(3.0), 1.0, max_relative = RTOL);
+
+        // reduces to the central chi-squared distribution for λ = 0
+        let d0 = NonCentralChiSquared::new(4.0, 0.0);
+        let c = ChiSquared::new(4.0);
+        assert_relative_eq!(d0.pdf(3.0), c.pdf(3.0), max_relative = RTOL);
+        assert_relative_eq!(d0.cdf(3.0), c.cdf(3.0), max_relative = RTOL);
+    }
+
+    #[test]
+    fn test_non_central_chi_squared_finders() {
+        let d = NonCentralChiSquared::new(4.0, 2.0);
+        let p = d.cdf(3.0);
+        let q = d.cdf_complement(3.0);
+
+        let df = NonCentralChiSquared::find_degrees_of_freedom(2.0, 3.0, p);
+        assert_relative_eq!(df, 4.0, max_relative = 1e-8);
+        let df = NonCentralChiSquared::find_degrees_of_freedom_complement(2.0, 3.0, q);
+        assert_relative_eq!(df, 4.0, max_relative = 1e-8);
+
+        let lambda = NonCentralChiSquared::find_non_centrality(4.0, 3.0, p);
+        assert_relative_eq!(lambda, 2.0, max_relative = 1e-8);
+        let lambda = NonCentralChiSquared::find_non_centrality_complement(4.0, 3.0, q);
+        assert_relative_eq!(lambda, 2.0, max_relative = 1e-8);
+    }
+}
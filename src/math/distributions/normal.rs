@@ -0,0 +1,99 @@
+//! boost/math/distributions/normal.hpp
+
+use super::{
+    Distribution, LocationScale,
+    sealed::{Find, FindOp, Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::c_int;
+
+/// Normal (Gaussian) distribution *N(μ, σ<sup>2</sup>)*
+///
+/// Corresponds to `boost::math::normal_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/normal_dist.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "normal_distribution")]
+#[doc(alias = "Gaussian")]
+pub struct Normal {
+    mean: f64,
+    sd: f64,
+}
+
+impl Normal {
+    /// Normal distribution with mean *μ* and standard deviation *σ*
+    pub fn new(mean: f64, sd: f64) -> Self {
+        Self { mean, sd }
+    }
+
+    /// Location parameter *μ*, i.e. the mean
+    pub fn location(&self) -> f64 {
+        self.mean
+    }
+
+    /// Scale parameter *σ*, i.e. the standard deviation
+    pub fn scale(&self) -> f64 {
+        self.sd
+    }
+}
+
+/// Standard normal distribution *N(0, 1)*
+impl Default for Normal {
+    fn default() -> Self {
+        Self::new(0.0, 1.0)
+    }
+}
+
+impl Sealed for Normal {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe { ffi::math_normal_distribution(self.mean, self.sd, op as c_int, x) }
+    }
+}
+
+impl Distribution for Normal {}
+
+impl Find for Normal {
+    fn find(op: FindOp, z: f64, p: f64, param: f64) -> f64 {
+        unsafe { ffi::math_normal_find(op as c_int, z, p, param) }
+    }
+}
+
+impl LocationScale for Normal {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{Distribution, Normal};
+
+    const RTOL: f64 = 4.0 * f64::EPSILON;
+    const FRAC_1_SQRT_2PI: f64 = 0.398_942_280_401_432_7; // 1/√(2π)
+
+    #[test]
+    fn test_normal() {
+        let d = Normal::default();
+        assert_relative_eq!(d.pdf(0.0), FRAC_1_SQRT_2PI, max_relative = RTOL);
+        assert_eq!(d.cdf(0.0), 0.5);
+        assert_eq!(d.cdf_complement(0.0), 0.5);
+        assert_relative_eq!(
+            d.quantile(0.975),
+            1.959_963_984_540_054,
+            max_relative = RTOL
+        );
+        assert_relative_eq!(
+            d.quantile_complement(0.025),
+            1.959_963_984_540_054,
+            max_relative = RTOL
+        );
+        assert_eq!(d.mean(), 0.0);
+        assert_eq!(d.variance(), 1.0);
+        assert_eq!(d.skewness(), 0.0);
+        assert_eq!(d.kurtosis(), 3.0);
+        assert_eq!(d.kurtosis_excess(), 0.0);
+        assert_eq!(d.support(), (f64::NEG_INFINITY, f64::INFINITY));
+    }
+
+    #[test]
+    fn test_normal_invalid() {
+        let d = Normal::new(0.0, -1.0);
+        assert!(d.pdf(0.0).is_nan());
+        assert!(d.cdf(0.0).is_nan());
+    }
+}
@@ -0,0 +1,73 @@
+//! boost/math/distributions/pareto.hpp
+
+use super::{
+    Distribution,
+    sealed::{Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::c_int;
+
+/// Pareto distribution
+///
+/// Corresponds to `boost::math::pareto_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/pareto.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "pareto_distribution")]
+pub struct Pareto {
+    scale: f64,
+    shape: f64,
+}
+
+impl Pareto {
+    /// Pareto distribution with scale *x<sub>m</sub> > 0* and shape *α > 0*
+    pub fn new(scale: f64, shape: f64) -> Self {
+        Self { scale, shape }
+    }
+
+    /// Scale parameter *x<sub>m</sub>*, i.e. the lower bound of the support
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Shape parameter *α*
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+}
+
+/// Pareto distribution with scale 1 and shape 1
+impl Default for Pareto {
+    fn default() -> Self {
+        Self::new(1.0, 1.0)
+    }
+}
+
+impl Sealed for Pareto {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe { ffi::math_pareto_distribution(self.scale, self.shape, op as c_int, x) }
+    }
+}
+
+impl Distribution for Pareto {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{Distribution, Pareto};
+
+    const RTOL: f64 = 4.0 * f64::EPSILON;
+
+    #[test]
+    fn test_pareto() {
+        // F(x) = 1 - (x_m / x)^α
+        let d = Pareto::new(1.0, 3.0);
+        assert_relative_eq!(d.cdf(2.0), 0.875, max_relative = RTOL);
+        assert_relative_eq!(d.cdf_complement(2.0), 0.125, max_relative = RTOL);
+        assert_relative_eq!(d.pdf(2.0), 0.1875, max_relative = RTOL);
+        assert_relative_eq!(d.quantile(0.875), 2.0, max_relative = RTOL);
+        assert_relative_eq!(d.mean(), 1.5, max_relative = RTOL);
+        assert_relative_eq!(d.variance(), 0.75, max_relative = RTOL);
+        assert_eq!(d.mode(), 1.0);
+        // the variance is undefined for α <= 2
+        assert!(Pareto::new(1.0, 2.0).variance().is_nan());
+    }
+}
@@ -0,0 +1,143 @@
+//! boost/math/distributions/poisson.hpp
+
+use super::{
+    DiscreteDistribution, DiscreteQuantile, Distribution,
+    sealed::{Op, Sealed},
+};
+use crate::ffi;
+use crate::math::{gamma_p_inv, gamma_q_inv};
+use core::ffi::c_int;
+
+/// Poisson distribution *Pois(λ)*
+///
+/// Corresponds to `boost::math::poisson_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/poisson_dist.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "poisson_distribution")]
+pub struct Poisson {
+    lambda: f64,
+    rounding: DiscreteQuantile,
+}
+
+impl Poisson {
+    /// Poisson distribution with mean *λ > 0*
+    pub fn new(lambda: f64) -> Self {
+        Self {
+            lambda,
+            rounding: DiscreteQuantile::default(),
+        }
+    }
+
+    /// Use the given rounding policy for the quantiles
+    pub fn with_rounding(self, rounding: DiscreteQuantile) -> Self {
+        Self { rounding, ..self }
+    }
+
+    /// Rate parameter *λ*, i.e. the mean
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+
+    /// Rounding policy for the quantiles
+    pub fn rounding(&self) -> DiscreteQuantile {
+        self.rounding
+    }
+
+    /// Lower bound on the mean *λ*, given `k` observed events
+    ///
+    /// The true mean is below the returned bound with probability `alpha`. For a two-sided
+    /// interval with coverage *1 - α*, use `alpha / 2`.
+    ///
+    /// This is the exact (Garwood) bound *P<sup>-1</sup>(k, α)*, where *P<sup>-1</sup>* is
+    /// [`gamma_p_inv`].
+    ///
+    /// Pure rust helper, without a counterpart in `boost::math::poisson_distribution`.
+    pub fn find_lower_bound_on_mean(k: f64, alpha: f64) -> f64 {
+        if k == 0.0 { 0.0 } else { gamma_p_inv(k, alpha) }
+    }
+
+    /// Upper bound on the mean *λ*, given `k` observed events
+    ///
+    /// The true mean is above the returned bound with probability `alpha`. For a two-sided
+    /// interval with coverage *1 - α*, use `alpha / 2`.
+    ///
+    /// This is the exact (Garwood) bound *Q<sup>-1</sup>(k + 1, α)*, where *Q<sup>-1</sup>* is
+    /// [`gamma_q_inv`].
+    ///
+    /// Pure rust helper, without a counterpart in `boost::math::poisson_distribution`.
+    pub fn find_upper_bound_on_mean(k: f64, alpha: f64) -> f64 {
+        gamma_q_inv(k + 1.0, alpha)
+    }
+}
+
+/// Poisson distribution with mean 1
+impl Default for Poisson {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl Sealed for Poisson {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe {
+            ffi::math_poisson_distribution(self.lambda, self.rounding as c_int, op as c_int, x)
+        }
+    }
+}
+
+impl Distribution for Poisson {}
+impl DiscreteDistribution for Poisson {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{
+        DiscreteDistribution, DiscreteQuantile, Distribution, Poisson,
+    };
+
+    const RTOL: f64 = 8.0 * f64::EPSILON;
+
+    #[test]
+    fn test_poisson() {
+        let d = Poisson::new(2.0);
+        let e2 = (-2.0f64).exp();
+        assert_relative_eq!(d.pmf(0.0), e2, max_relative = RTOL);
+        assert_relative_eq!(d.pmf(2.0), 2.0 * e2, max_relative = RTOL);
+        assert_relative_eq!(d.cdf(1.0), 3.0 * e2, max_relative = RTOL);
+        assert_relative_eq!(d.cdf_complement(1.0), 1.0 - 3.0 * e2, max_relative = RTOL);
+        assert_eq!(d.mean(), 2.0);
+        assert_eq!(d.variance(), 2.0);
+    }
+
+    #[test]
+    fn test_poisson_quantile_rounding() {
+        // F(1) ≈ 0.406 < 0.5 < F(2) ≈ 0.677
+        let d = Poisson::new(2.0).with_rounding(DiscreteQuantile::IntegerRoundUp);
+        assert_eq!(d.quantile(0.5), 2.0);
+        let d = d.with_rounding(DiscreteQuantile::IntegerRoundDown);
+        assert_eq!(d.quantile(0.5), 1.0);
+    }
+
+    #[test]
+    fn test_poisson_find_bounds_on_mean() {
+        const ATOL: f64 = 1e-12;
+
+        // P(X ≥ k | λ_lower) = α and P(X ≤ k | λ_upper) = α
+        let lower = Poisson::find_lower_bound_on_mean(10.0, 0.025);
+        let upper = Poisson::find_upper_bound_on_mean(10.0, 0.025);
+        assert!(lower < 10.0 && 10.0 < upper);
+        assert_abs_diff_eq!(
+            Poisson::new(lower).cdf_complement(9.0),
+            0.025,
+            epsilon = ATOL
+        );
+        assert_abs_diff_eq!(Poisson::new(upper).cdf(10.0), 0.025, epsilon = ATOL);
+
+        assert_eq!(Poisson::find_lower_bound_on_mean(0.0, 0.025), 0.0);
+        // for k = 0: exp(-λ) = α
+        assert_relative_eq!(
+            Poisson::find_upper_bound_on_mean(0.0, 0.025),
+            -(0.025f64).ln(),
+            max_relative = RTOL
+        );
+    }
+}
@@ -0,0 +1,65 @@
+//! boost/math/distributions/rayleigh.hpp
+
+use super::{
+    Distribution,
+    sealed::{Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::c_int;
+
+/// Rayleigh distribution
+///
+/// Corresponds to `boost::math::rayleigh_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/rayleigh.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "rayleigh_distribution")]
+pub struct Rayleigh {
+    sigma: f64,
+}
+
+impl Rayleigh {
+    /// Rayleigh distribution with scale *σ > 0*
+    pub fn new(sigma: f64) -> Self {
+        Self { sigma }
+    }
+
+    /// Scale parameter *σ*, i.e. the mode
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+}
+
+/// Rayleigh distribution with scale 1
+impl Default for Rayleigh {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl Sealed for Rayleigh {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe { ffi::math_rayleigh_distribution(self.sigma, op as c_int, x) }
+    }
+}
+
+impl Distribution for Rayleigh {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::constants::{RAYLEIGH_KURTOSIS, RAYLEIGH_SKEWNESS};
+    use crate::math::distributions::{Distribution, Rayleigh};
+
+    const RTOL: f64 = 4.0 * f64::EPSILON;
+
+    #[test]
+    fn test_rayleigh() {
+        // F(x) = 1 - exp(-x² / (2σ²))
+        let d = Rayleigh::new(2.0);
+        assert_relative_eq!(d.cdf(2.0), -(-0.5f64).exp_m1(), max_relative = RTOL);
+        assert_relative_eq!(d.cdf_complement(2.0), (-0.5f64).exp(), max_relative = RTOL);
+        assert_relative_eq!(d.quantile(-(-0.5f64).exp_m1()), 2.0, max_relative = RTOL);
+        assert_relative_eq!(d.mode(), 2.0, max_relative = RTOL);
+        assert_relative_eq!(d.skewness(), RAYLEIGH_SKEWNESS, max_relative = RTOL);
+        assert_relative_eq!(d.kurtosis(), RAYLEIGH_KURTOSIS, max_relative = RTOL);
+    }
+}
@@ -0,0 +1,70 @@
+//! boost/math/distributions/saspoint5.hpp
+
+use super::{
+    Distribution,
+    sealed::{Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::c_int;
+
+/// Symmetric alpha-stable distribution with *α = 1/2*
+///
+/// Its mean and higher moments are undefined, so those accessors return NaN.
+///
+/// Corresponds to `boost::math::saspoint5_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/saspoint5_dist.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "saspoint5_distribution")]
+pub struct SaSPoint5 {
+    location: f64,
+    scale: f64,
+}
+
+impl SaSPoint5 {
+    /// SαS point five distribution with location *μ* and scale *c > 0*
+    pub fn new(location: f64, scale: f64) -> Self {
+        Self { location, scale }
+    }
+
+    /// Location parameter *μ*
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Scale parameter *c*
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+/// SαS point five distribution with location 0 and scale 1
+impl Default for SaSPoint5 {
+    fn default() -> Self {
+        Self::new(0.0, 1.0)
+    }
+}
+
+impl Sealed for SaSPoint5 {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe { ffi::math_saspoint5_distribution(self.location, self.scale, op as c_int, x) }
+    }
+}
+
+impl Distribution for SaSPoint5 {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{Distribution, SaSPoint5};
+
+    const RTOL: f64 = 1e-12;
+
+    #[test]
+    fn test_saspoint5() {
+        let d = SaSPoint5::default();
+        assert_eq!(d.cdf(0.0), 0.5);
+        assert_relative_eq!(d.cdf(-1.5), d.cdf_complement(1.5), max_relative = RTOL);
+        assert_relative_eq!(d.quantile(d.cdf(1.5)), 1.5, max_relative = RTOL);
+        assert_eq!(d.median(), 0.0);
+        assert!(d.mean().is_nan());
+    }
+}
@@ -0,0 +1,100 @@
+//! boost/math/distributions/students_t.hpp
+
+use super::{
+    Distribution,
+    sealed::{Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::c_int;
+
+/// Student's *t* distribution
+///
+/// Corresponds to `boost::math::students_t_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/students_t_dist.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "students_t_distribution")]
+pub struct StudentsT {
+    df: f64,
+}
+
+impl StudentsT {
+    /// Student's *t* distribution with *ν > 0* degrees of freedom
+    pub fn new(df: f64) -> Self {
+        Self { df }
+    }
+
+    /// Degrees of freedom *ν*
+    pub fn degrees_of_freedom(&self) -> f64 {
+        self.df
+    }
+
+    /// Degrees of freedom *ν* required for a one-sample *t* test to detect a difference
+    /// `difference_from_mean` between the sample mean and the true mean
+    ///
+    /// `alpha` is the acceptable probability of a type I error (rejecting the null hypothesis when
+    /// it is true), and `beta` the acceptable probability of a type II error (failing to reject it
+    /// when it is false). `sd` is the sample standard deviation, which must be positive;
+    /// otherwise the result is NaN. The required sample size is *ν + 1*.
+    ///
+    /// Corresponds to `boost::math::students_t_distribution<double>::find_degrees_of_freedom` in
+    /// C++.
+    pub fn find_degrees_of_freedom(
+        difference_from_mean: f64,
+        alpha: f64,
+        beta: f64,
+        sd: f64,
+    ) -> f64 {
+        // Boost only uses the variance, which would hide the sign of `sd`
+        if sd.is_nan() || sd <= 0.0 {
+            return f64::NAN;
+        }
+        unsafe {
+            ffi::math_students_t_find_degrees_of_freedom(difference_from_mean, alpha, beta, sd)
+        }
+    }
+}
+
+impl Sealed for StudentsT {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe { ffi::math_students_t_distribution(self.df, op as c_int, x) }
+    }
+}
+
+impl Distribution for StudentsT {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{Distribution, StudentsT};
+
+    const RTOL: f64 = 8.0 * f64::EPSILON;
+
+    #[test]
+    fn test_students_t() {
+        // for ν = 1 this is the standard Cauchy distribution
+        let d = StudentsT::new(1.0);
+        assert_relative_eq!(d.cdf(1.0), 0.75, max_relative = RTOL);
+        assert_relative_eq!(d.quantile(0.75), 1.0, max_relative = RTOL);
+        assert!(d.mean().is_nan());
+
+        let d = StudentsT::new(10.0);
+        assert_eq!(d.cdf(0.0), 0.5);
+        assert_relative_eq!(
+            d.quantile(0.975),
+            2.228_138_851_986_274,
+            max_relative = RTOL
+        );
+        assert_eq!(d.mean(), 0.0);
+        assert_relative_eq!(d.variance(), 1.25, max_relative = RTOL);
+    }
+
+    #[test]
+    fn test_students_t_find_degrees_of_freedom() {
+        let df = StudentsT::find_degrees_of_freedom(1.0, 0.05, 0.1, 2.0);
+        assert!(df > 0.0);
+        // larger differences need fewer samples, larger variances more
+        assert!(StudentsT::find_degrees_of_freedom(2.0, 0.05, 0.1, 2.0) < df);
+        assert!(StudentsT::find_degrees_of_freedom(1.0, 0.05, 0.1, 4.0) > df);
+        assert!(StudentsT::find_degrees_of_freedom(1.0, 0.05, 0.1, -1.0).is_nan());
+        assert!(StudentsT::find_degrees_of_freedom(1.0, 0.05, 0.1, 0.0).is_nan());
+    }
+}
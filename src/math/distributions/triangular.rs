@@ -0,0 +1,77 @@
+//! boost/math/distributions/triangular.hpp
+
+use super::{
+    Distribution,
+    sealed::{Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::c_int;
+
+/// Triangular distribution
+///
+/// The mode *c* is available through [`Distribution::mode`].
+///
+/// Corresponds to `boost::math::triangular_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/triangular_dist.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "triangular_distribution")]
+pub struct Triangular {
+    lower: f64,
+    mode: f64,
+    upper: f64,
+}
+
+impl Triangular {
+    /// Triangular distribution on *[a, b]* with mode *c*, where *a ≤ c ≤ b*
+    pub fn new(lower: f64, mode: f64, upper: f64) -> Self {
+        Self { lower, mode, upper }
+    }
+
+    /// Lower bound *a*
+    pub fn lower(&self) -> f64 {
+        self.lower
+    }
+
+    /// Upper bound *b*
+    pub fn upper(&self) -> f64 {
+        self.upper
+    }
+}
+
+/// Triangular distribution on *[-1, 1]* with mode 0
+impl Default for Triangular {
+    fn default() -> Self {
+        Self::new(-1.0, 0.0, 1.0)
+    }
+}
+
+impl Sealed for Triangular {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe {
+            ffi::math_triangular_distribution(self.lower, self.mode, self.upper, op as c_int, x)
+        }
+    }
+}
+
+impl Distribution for Triangular {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{Distribution, Triangular};
+
+    const RTOL: f64 = 4.0 * f64::EPSILON;
+
+    #[test]
+    fn test_triangular() {
+        let d = Triangular::new(0.0, 1.0, 2.0);
+        assert_eq!(d.pdf(1.0), 1.0);
+        assert_eq!(d.pdf(0.5), 0.5);
+        assert_eq!(d.cdf(1.0), 0.5);
+        assert_eq!(d.cdf(0.5), 0.125);
+        assert_relative_eq!(d.quantile(0.125), 0.5, max_relative = RTOL);
+        assert_eq!(d.mode(), 1.0);
+        assert_eq!(d.mean(), 1.0);
+        assert_relative_eq!(d.variance(), 1.0 / 6.0, max_relative = RTOL);
+        assert_eq!(d.support(), (0.0, 2.0));
+    }
+}
@@ -0,0 +1,75 @@
+//! boost/math/distributions/uniform.hpp
+
+use super::{
+    Distribution,
+    sealed::{Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::c_int;
+
+/// Continuous uniform distribution *U(a, b)*
+///
+/// Corresponds to `boost::math::uniform_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/uniform_dist.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "uniform_distribution")]
+pub struct Uniform {
+    lower: f64,
+    upper: f64,
+}
+
+impl Uniform {
+    /// Uniform distribution on *[a, b]*, with *a < b*
+    pub fn new(lower: f64, upper: f64) -> Self {
+        Self { lower, upper }
+    }
+
+    /// Lower bound *a*
+    pub fn lower(&self) -> f64 {
+        self.lower
+    }
+
+    /// Upper bound *b*
+    pub fn upper(&self) -> f64 {
+        self.upper
+    }
+}
+
+/// Standard uniform distribution *U(0, 1)*
+impl Default for Uniform {
+    fn default() -> Self {
+        Self::new(0.0, 1.0)
+    }
+}
+
+impl Sealed for Uniform {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe { ffi::math_uniform_distribution(self.lower, self.upper, op as c_int, x) }
+    }
+}
+
+impl Distribution for Uniform {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{Distribution, Uniform};
+
+    #[test]
+    fn test_uniform() {
+        let d = Uniform::new(-1.0, 3.0);
+        assert_eq!(d.pdf(0.0), 0.25);
+        assert_eq!(d.pdf(4.0), 0.0);
+        assert_eq!(d.cdf(0.0), 0.25);
+        assert_eq!(d.cdf_complement(0.0), 0.75);
+        assert_eq!(d.quantile(0.5), 1.0);
+        assert_eq!(d.mean(), 1.0);
+        assert_eq!(d.median(), 1.0);
+        assert_eq!(d.skewness(), 0.0);
+        assert_eq!(d.support(), (-1.0, 3.0));
+    }
+
+    #[test]
+    fn test_uniform_invalid() {
+        assert!(Uniform::new(1.0, 0.0).cdf(0.5).is_nan());
+    }
+}
@@ -0,0 +1,63 @@
+//! boost/math/distributions/weibull.hpp
+
+use super::{
+    Distribution,
+    sealed::{Op, Sealed},
+};
+use crate::ffi;
+use core::ffi::c_int;
+
+/// Weibull distribution
+///
+/// Corresponds to `boost::math::weibull_distribution<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/dist_ref/dists/weibull_dist.html>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "weibull_distribution")]
+pub struct Weibull {
+    shape: f64,
+    scale: f64,
+}
+
+impl Weibull {
+    /// Weibull distribution with shape *k > 0* and scale *λ > 0*
+    pub fn new(shape: f64, scale: f64) -> Self {
+        Self { shape, scale }
+    }
+
+    /// Shape parameter *k*
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    /// Scale parameter *λ*
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+impl Sealed for Weibull {
+    fn call(&self, op: Op, x: f64) -> f64 {
+        unsafe { ffi::math_weibull_distribution(self.shape, self.scale, op as c_int, x) }
+    }
+}
+
+impl Distribution for Weibull {}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::distributions::{Distribution, Weibull};
+
+    const RTOL: f64 = 4.0 * f64::EPSILON;
+
+    #[test]
+    fn test_weibull() {
+        // F(x) = 1 - exp(-(x/λ)^k)
+        let d = Weibull::new(2.0, 3.0);
+        assert_relative_eq!(d.cdf(3.0), -(-1.0f64).exp_m1(), max_relative = RTOL);
+        assert_relative_eq!(d.cdf_complement(6.0), (-4.0f64).exp(), max_relative = RTOL);
+        assert_relative_eq!(d.quantile(-(-1.0f64).exp_m1()), 3.0, max_relative = RTOL);
+        assert_relative_eq!(d.chf(6.0), 4.0, max_relative = RTOL);
+        assert_relative_eq!(d.mode(), 1.5 * 2.0f64.sqrt(), max_relative = RTOL);
+        assert_eq!(d.support().0, 0.0);
+    }
+}
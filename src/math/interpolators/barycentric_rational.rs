@@ -0,0 +1,171 @@
+//! boost/math/interpolators/barycentric_rational.hpp
+
+use crate::ffi;
+use alloc::vec::Vec;
+use core::{ffi::c_void, mem, ptr};
+
+/// Barycentric rational interpolation of unequally spaced data
+///
+/// Floater-Hormann interpolation blends the local polynomial interpolants of `order + 1`
+/// consecutive points into a rational function without poles on the real line. Unlike a single
+/// global polynomial, it does not oscillate wildly between unequally spaced abscissas, and it
+/// reproduces polynomials of degree at most `order` exactly. The interpolant is smooth, and
+/// passes through each data point. The default order in C++ is 3.
+///
+/// The abscissas `x` must be finite and strictly increasing, with as many ordinates `y`, and the
+/// `order` must be less than their number; otherwise the interpolant is NaN.
+///
+/// Corresponds to `boost::math::interpolators::barycentric_rational<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/barycentric.html>
+///
+/// # Examples
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use boost::math::interpolators::BarycentricRational;
+///
+/// let x = vec![0.0, 0.3, 1.1, 1.5, 2.4, 3.0, 3.7, 4.2];
+/// let y = x.iter().map(|&xi: &f64| xi.sin()).collect();
+/// let interpolant = BarycentricRational::new(x, y, 3);
+/// assert_relative_eq!(interpolant.eval(1.1), 1.1f64.sin());
+/// assert_relative_eq!(interpolant.eval(2.0), 2.0f64.sin(), max_relative = 1e-2);
+/// assert_relative_eq!(interpolant.prime(2.0), 2.0f64.cos(), max_relative = 1e-1);
+/// ```
+#[derive(Debug)]
+#[doc(alias = "barycentric_rational")]
+pub struct BarycentricRational {
+    interpolator: *mut c_void,
+    x: Vec<f64>,
+    y: Vec<f64>,
+    order: usize,
+}
+
+// The C++ interpolator is owned, and immutable after its construction.
+unsafe impl Send for BarycentricRational {}
+unsafe impl Sync for BarycentricRational {}
+
+impl BarycentricRational {
+    /// Interpolant of the data points *(x<sub>i</sub>, y<sub>i</sub>)* with approximation `order`
+    pub fn new(x: Vec<f64>, y: Vec<f64>, order: usize) -> Self {
+        let valid = x.len() == y.len()
+            && order < x.len()
+            && x.iter().all(|xi| xi.is_finite())
+            && x.windows(2).all(|w| w[0] < w[1]);
+        let interpolator = if valid {
+            unsafe { ffi::math_barycentric_rational_new(x.as_ptr(), y.as_ptr(), x.len(), order) }
+        } else {
+            ptr::null_mut()
+        };
+        Self {
+            interpolator,
+            x,
+            y,
+            order,
+        }
+    }
+
+    /// Abscissas of the data points
+    pub fn x(&self) -> &[f64] {
+        &self.x
+    }
+
+    /// Ordinates of the data points
+    pub fn y(&self) -> &[f64] {
+        &self.y
+    }
+
+    /// Approximation order, i.e. the degree of the blended local polynomials
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    /// Consumes the interpolant, returning its abscissas and ordinates
+    pub fn into_data(mut self) -> (Vec<f64>, Vec<f64>) {
+        self.free();
+        (mem::take(&mut self.x), mem::take(&mut self.y))
+    }
+
+    /// Value of the interpolant at `x`
+    pub fn eval(&self, x: f64) -> f64 {
+        if self.interpolator.is_null() {
+            return f64::NAN;
+        }
+        unsafe { ffi::math_barycentric_rational(self.interpolator, x) }
+    }
+
+    /// First derivative of the interpolant at `x`
+    pub fn prime(&self, x: f64) -> f64 {
+        if self.interpolator.is_null() {
+            return f64::NAN;
+        }
+        unsafe { ffi::math_barycentric_rational_prime(self.interpolator, x) }
+    }
+
+    fn free(&mut self) {
+        if !self.interpolator.is_null() {
+            unsafe { ffi::math_barycentric_rational_delete(self.interpolator) };
+            self.interpolator = ptr::null_mut();
+        }
+    }
+}
+
+impl Clone for BarycentricRational {
+    fn clone(&self) -> Self {
+        Self::new(self.x.clone(), self.y.clone(), self.order)
+    }
+}
+
+impl Drop for BarycentricRational {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_barycentric_rational() {
+        // reproduces polynomials of degree at most the order
+        let p = |x: f64| ((2.0 * x - 3.0) * x + 0.5) * x - 1.0;
+        let dp = |x: f64| (6.0 * x - 6.0) * x + 0.5;
+        let x = vec![-1.0, -0.7, 0.1, 0.2, 0.9, 1.6, 2.0, 2.8, 3.5];
+        let y = x.iter().map(|&xi| p(xi)).collect();
+        let interpolant = BarycentricRational::new(x, y, 3);
+        assert_eq!(interpolant.order(), 3);
+        assert_eq!(interpolant.y()[4], p(0.9));
+        for xi in [-1.0, -0.5, 0.15, 1.0, 2.5, 3.5] {
+            assert_relative_eq!(interpolant.eval(xi), p(xi), max_relative = 1e-10);
+            assert_relative_eq!(interpolant.prime(xi), dp(xi), max_relative = 1e-8);
+        }
+
+        let interpolant = interpolant.clone();
+        assert_relative_eq!(interpolant.eval(1.2), p(1.2), max_relative = 1e-10);
+        let (x, y) = interpolant.into_data();
+        assert_eq!((x.len(), y.len()), (9, 9));
+    }
+
+    #[test]
+    fn test_barycentric_rational_nodes() {
+        let x = vec![0.0, 1.0, 3.0, 4.0];
+        let y = vec![2.0, -1.0, 5.0, 0.5];
+        let interpolant = BarycentricRational::new(x.clone(), y.clone(), 0);
+        for (xi, yi) in x.into_iter().zip(y) {
+            assert_eq!(interpolant.eval(xi), yi);
+        }
+    }
+
+    #[test]
+    fn test_barycentric_rational_invalid() {
+        let interpolant = BarycentricRational::new(vec![0.0, 1.0], vec![1.0], 0);
+        assert!(interpolant.eval(0.5).is_nan());
+        let interpolant = BarycentricRational::new(vec![0.0, 1.0], vec![1.0, 2.0], 2);
+        assert!(interpolant.prime(0.5).is_nan());
+        let interpolant = BarycentricRational::new(vec![1.0, 0.0], vec![1.0, 2.0], 1);
+        assert!(interpolant.clone().eval(0.5).is_nan());
+        let interpolant = BarycentricRational::new(vec![], vec![], 0);
+        assert!(interpolant.into_data().0.is_empty());
+    }
+}
@@ -0,0 +1,171 @@
+//! boost/math/interpolators/cardinal_quadratic_b_spline.hpp
+
+use crate::ffi;
+use core::{ffi::c_void, ptr};
+
+/// Quadratic B-spline interpolation of equispaced data
+///
+/// The interpolant is a linear combination of the quadratic [`cardinal_b_spline`] basis
+/// functions centered on the grid *t<sub>0</sub> + ih*, which passes through the samples
+/// *y<sub>i</sub>*. It is continuously differentiable, and its error is *O(h<sup>3</sup>)*. The
+/// derivatives at the endpoints are estimated from the data, unless given.
+///
+/// There must be at least 3 samples, and *t<sub>0</sub>* and the spacing *h > 0* must be finite;
+/// otherwise the interpolant is NaN. It is also NaN outside of
+/// *\[t<sub>0</sub>, t<sub>max</sub>\]*, since B-splines do not extrapolate.
+///
+/// Corresponds to `boost::math::interpolators::cardinal_quadratic_b_spline<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/cardinal_quadratic.html>
+///
+/// [`cardinal_b_spline`]: crate::math::cardinal_b_spline
+///
+/// # Examples
+///
+/// ```
+/// # use approx::assert_abs_diff_eq;
+/// use boost::math::interpolators::CardinalQuadraticBSpline;
+///
+/// let h = 0.01;
+/// let y: Vec<f64> = (0..=100).map(|i| (i as f64 * h).exp()).collect();
+/// let spline = CardinalQuadraticBSpline::new(&y, 0.0, h, Some(1.0), Some(1f64.exp()));
+/// assert_abs_diff_eq!(spline.eval(0.555), 0.555f64.exp(), epsilon = 1e-5);
+/// assert_abs_diff_eq!(spline.prime(0.555), 0.555f64.exp(), epsilon = 1e-3);
+/// ```
+#[derive(Debug)]
+#[doc(alias = "cardinal_quadratic_b_spline")]
+pub struct CardinalQuadraticBSpline {
+    interpolator: *mut c_void,
+    t0: f64,
+    h: f64,
+    t_max: f64,
+}
+
+// The C++ interpolator is owned, and immutable after its construction.
+unsafe impl Send for CardinalQuadraticBSpline {}
+unsafe impl Sync for CardinalQuadraticBSpline {}
+
+impl CardinalQuadraticBSpline {
+    /// Interpolant of the samples `y` at *t<sub>0</sub> + ih*, with the optional first
+    /// derivatives at the left and right endpoints
+    pub fn new(
+        y: &[f64],
+        t0: f64,
+        h: f64,
+        left_derivative: Option<f64>,
+        right_derivative: Option<f64>,
+    ) -> Self {
+        let valid = y.len() >= 3 && t0.is_finite() && h > 0.0 && h.is_finite();
+        let interpolator = if valid {
+            unsafe {
+                ffi::math_cardinal_quadratic_b_spline_new(
+                    y.as_ptr(),
+                    y.len(),
+                    t0,
+                    h,
+                    left_derivative.unwrap_or(f64::NAN),
+                    right_derivative.unwrap_or(f64::NAN),
+                )
+            }
+        } else {
+            ptr::null_mut()
+        };
+        Self {
+            interpolator,
+            t0,
+            h,
+            t_max: t0 + y.len().saturating_sub(1) as f64 * h,
+        }
+    }
+
+    /// Abscissa of the first sample
+    pub fn t0(&self) -> f64 {
+        self.t0
+    }
+
+    /// Spacing of the samples
+    pub fn h(&self) -> f64 {
+        self.h
+    }
+
+    /// Abscissa of the last sample, i.e. the end of the domain
+    pub fn t_max(&self) -> f64 {
+        self.t_max
+    }
+
+    /// Value of the interpolant at `t`
+    pub fn eval(&self, t: f64) -> f64 {
+        if !self.contains(t) {
+            return f64::NAN;
+        }
+        unsafe { ffi::math_cardinal_quadratic_b_spline(self.interpolator, t) }
+    }
+
+    /// First derivative of the interpolant at `t`
+    pub fn prime(&self, t: f64) -> f64 {
+        if !self.contains(t) {
+            return f64::NAN;
+        }
+        unsafe { ffi::math_cardinal_quadratic_b_spline_prime(self.interpolator, t) }
+    }
+
+    fn contains(&self, t: f64) -> bool {
+        !self.interpolator.is_null() && self.t0 <= t && t <= self.t_max
+    }
+}
+
+impl Clone for CardinalQuadraticBSpline {
+    fn clone(&self) -> Self {
+        let interpolator = if self.interpolator.is_null() {
+            ptr::null_mut()
+        } else {
+            unsafe { ffi::math_cardinal_quadratic_b_spline_clone(self.interpolator) }
+        };
+        Self {
+            interpolator,
+            ..*self
+        }
+    }
+}
+
+impl Drop for CardinalQuadraticBSpline {
+    fn drop(&mut self) {
+        if !self.interpolator.is_null() {
+            unsafe { ffi::math_cardinal_quadratic_b_spline_delete(self.interpolator) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_cardinal_quadratic_b_spline() {
+        let h = 0.01;
+        let y: Vec<f64> = (0..=200).map(|i| (i as f64 * h).sin()).collect();
+        let spline = CardinalQuadraticBSpline::new(&y, 0.0, h, None, None);
+        assert_eq!((spline.t0(), spline.h()), (0.0, h));
+        assert_relative_eq!(spline.t_max(), 2.0);
+        for t in [0.0, 0.123, 0.5, 1.0, 1.777, 2.0] {
+            assert_abs_diff_eq!(spline.eval(t), t.sin(), epsilon = 1e-4);
+            assert_abs_diff_eq!(spline.prime(t), t.cos(), epsilon = 1e-2);
+        }
+
+        let spline = CardinalQuadraticBSpline::new(&y, 0.0, h, Some(1.0), Some(2f64.cos()));
+        assert_abs_diff_eq!(spline.clone().prime(0.0), 1.0, epsilon = 1e-4);
+        assert_abs_diff_eq!(spline.eval(0.5), 0.5f64.sin(), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_cardinal_quadratic_b_spline_invalid() {
+        let spline = CardinalQuadraticBSpline::new(&[1.0, 2.0, 3.0], 0.0, 1.0, None, None);
+        assert!(spline.eval(-0.1).is_nan());
+        assert!(spline.prime(2.1).is_nan());
+        assert!(spline.eval(f64::NAN).is_nan());
+        let spline = CardinalQuadraticBSpline::new(&[1.0, 2.0], 0.0, 1.0, None, None);
+        assert!(spline.clone().eval(0.5).is_nan());
+        let spline = CardinalQuadraticBSpline::new(&[1.0, 2.0, 3.0], 0.0, 0.0, None, None);
+        assert!(spline.eval(0.0).is_nan());
+    }
+}
@@ -0,0 +1,190 @@
+//! boost/math/interpolators/cardinal_quintic_b_spline.hpp
+
+use crate::ffi;
+use core::{ffi::c_void, ptr};
+
+/// Quintic B-spline interpolation of equispaced data
+///
+/// The interpolant is a linear combination of the quintic [`cardinal_b_spline`] basis functions
+/// centered on the grid *t<sub>0</sub> + ih*, which passes through the samples *y<sub>i</sub>*.
+/// It is four times continuously differentiable, and its error is *O(h<sup>6</sup>)*. The first
+/// and second derivatives at the endpoints are estimated from the data, unless given.
+///
+/// There must be at least 8 samples, and *t<sub>0</sub>* and the spacing *h > 0* must be finite;
+/// otherwise the interpolant is NaN. It is also NaN outside of
+/// *\[t<sub>0</sub>, t<sub>max</sub>\]*.
+///
+/// Corresponds to `boost::math::interpolators::cardinal_quintic_b_spline<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/cardinal_quintic.html>
+///
+/// [`cardinal_b_spline`]: crate::math::cardinal_b_spline
+///
+/// # Examples
+///
+/// ```
+/// # use approx::assert_abs_diff_eq;
+/// use boost::math::interpolators::CardinalQuinticBSpline;
+///
+/// let h = 0.05;
+/// let y: Vec<f64> = (0..=40).map(|i| (i as f64 * h).sin()).collect();
+/// // the exact first and second derivatives at both endpoints
+/// let left = (1.0, 0.0);
+/// let right = (2f64.cos(), -2f64.sin());
+/// let spline = CardinalQuinticBSpline::new(&y, 0.0, h, Some(left), Some(right));
+/// assert_abs_diff_eq!(spline.eval(1.234), 1.234f64.sin(), epsilon = 1e-7);
+/// assert_abs_diff_eq!(spline.prime(1.234), 1.234f64.cos(), epsilon = 1e-6);
+/// assert_abs_diff_eq!(spline.double_prime(1.234), -1.234f64.sin(), epsilon = 1e-4);
+/// ```
+#[derive(Debug)]
+#[doc(alias = "cardinal_quintic_b_spline")]
+pub struct CardinalQuinticBSpline {
+    interpolator: *mut c_void,
+    t0: f64,
+    h: f64,
+    t_max: f64,
+}
+
+// The C++ interpolator is owned, and immutable after its construction.
+unsafe impl Send for CardinalQuinticBSpline {}
+unsafe impl Sync for CardinalQuinticBSpline {}
+
+impl CardinalQuinticBSpline {
+    /// Interpolant of the samples `y` at *t<sub>0</sub> + ih*, with the optional first and second
+    /// derivatives at the left and right endpoints
+    pub fn new(
+        y: &[f64],
+        t0: f64,
+        h: f64,
+        left_derivatives: Option<(f64, f64)>,
+        right_derivatives: Option<(f64, f64)>,
+    ) -> Self {
+        let valid = y.len() >= 8 && t0.is_finite() && h > 0.0 && h.is_finite();
+        let interpolator = if valid {
+            let (left_prime, left_double_prime) = left_derivatives.unwrap_or((f64::NAN, f64::NAN));
+            let (right_prime, right_double_prime) =
+                right_derivatives.unwrap_or((f64::NAN, f64::NAN));
+            unsafe {
+                ffi::math_cardinal_quintic_b_spline_new(
+                    y.as_ptr(),
+                    y.len(),
+                    t0,
+                    h,
+                    left_prime,
+                    left_double_prime,
+                    right_prime,
+                    right_double_prime,
+                )
+            }
+        } else {
+            ptr::null_mut()
+        };
+        Self {
+            interpolator,
+            t0,
+            h,
+            t_max: t0 + y.len().saturating_sub(1) as f64 * h,
+        }
+    }
+
+    /// Abscissa of the first sample
+    pub fn t0(&self) -> f64 {
+        self.t0
+    }
+
+    /// Spacing of the samples
+    pub fn h(&self) -> f64 {
+        self.h
+    }
+
+    /// Abscissa of the last sample, i.e. the end of the domain
+    pub fn t_max(&self) -> f64 {
+        self.t_max
+    }
+
+    /// Value of the interpolant at `t`
+    pub fn eval(&self, t: f64) -> f64 {
+        if !self.contains(t) {
+            return f64::NAN;
+        }
+        unsafe { ffi::math_cardinal_quintic_b_spline(self.interpolator, t) }
+    }
+
+    /// First derivative of the interpolant at `t`
+    pub fn prime(&self, t: f64) -> f64 {
+        if !self.contains(t) {
+            return f64::NAN;
+        }
+        unsafe { ffi::math_cardinal_quintic_b_spline_prime(self.interpolator, t) }
+    }
+
+    /// Second derivative of the interpolant at `t`
+    pub fn double_prime(&self, t: f64) -> f64 {
+        if !self.contains(t) {
+            return f64::NAN;
+        }
+        unsafe { ffi::math_cardinal_quintic_b_spline_double_prime(self.interpolator, t) }
+    }
+
+    fn contains(&self, t: f64) -> bool {
+        !self.interpolator.is_null() && self.t0 <= t && t <= self.t_max
+    }
+}
+
+impl Clone for CardinalQuinticBSpline {
+    fn clone(&self) -> Self {
+        let interpolator = if self.interpolator.is_null() {
+            ptr::null_mut()
+        } else {
+            unsafe { ffi::math_cardinal_quintic_b_spline_clone(self.interpolator) }
+        };
+        Self {
+            interpolator,
+            ..*self
+        }
+    }
+}
+
+impl Drop for CardinalQuinticBSpline {
+    fn drop(&mut self) {
+        if !self.interpolator.is_null() {
+            unsafe { ffi::math_cardinal_quintic_b_spline_delete(self.interpolator) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_cardinal_quintic_b_spline() {
+        let h = 0.01;
+        let y: Vec<f64> = (0..=200).map(|i| (i as f64 * h).exp()).collect();
+        let spline = CardinalQuinticBSpline::new(&y, 0.0, h, None, None);
+        assert_relative_eq!(spline.t_max(), 2.0);
+        for t in [0.0, 0.123, 0.5, 1.0, 1.777, 2.0] {
+            assert_relative_eq!(spline.eval(t), t.exp(), max_relative = 1e-7);
+            assert_relative_eq!(spline.prime(t), t.exp(), max_relative = 1e-4);
+            assert_relative_eq!(spline.double_prime(t), t.exp(), max_relative = 1e-2);
+        }
+
+        let e2 = 2f64.exp();
+        let spline = CardinalQuinticBSpline::new(&y, 0.0, h, Some((1.0, 1.0)), Some((e2, e2)));
+        let spline = spline.clone();
+        assert_relative_eq!(spline.prime(0.0), 1.0, max_relative = 1e-8);
+        assert_relative_eq!(spline.double_prime(2.0), e2, max_relative = 1e-6);
+    }
+
+    #[test]
+    fn test_cardinal_quintic_b_spline_invalid() {
+        let spline = CardinalQuinticBSpline::new(&[1.0; 7], 0.0, 1.0, None, None);
+        assert!(spline.eval(1.0).is_nan());
+        let spline = CardinalQuinticBSpline::new(&[1.0; 8], 0.0, -1.0, None, None);
+        assert!(spline.clone().prime(-1.0).is_nan());
+        let spline = CardinalQuinticBSpline::new(&[1.0; 8], 0.0, 1.0, None, None);
+        assert_relative_eq!(spline.eval(3.5), 1.0);
+        assert!(spline.eval(-1e-3).is_nan());
+        assert!(spline.double_prime(7.5).is_nan());
+    }
+}
@@ -0,0 +1,28 @@
+//! Interpolation of tabulated data
+//!
+//! Corresponds to the Boost Math `boost/math/interpolators` C++ headers.
+//! <https://boost.org/doc/libs/latest/libs/math/doc/html/interpolation.html>
+//!
+//! ## Scattered Data
+//!
+//! - [`BarycentricRational`] for Floater-Hormann rational interpolation of unequally spaced data
+//!
+//! ## Equispaced Data
+//!
+//! - [`CardinalQuadraticBSpline`], [`CardinalCubicBSpline`] and [`CardinalQuinticBSpline`] for
+//!   B-spline interpolation of uniformly sampled data, with optional endpoint derivatives
+//!
+//! These are linear combinations of the shifted [`cardinal_b_spline`] basis functions of
+//! degree 2, 3 and 5, whose coefficients are fitted to the samples.
+//!
+//! [`cardinal_b_spline`]: crate::math::cardinal_b_spline
+
+mod barycentric_rational;
+mod cardinal_cubic_b_spline;
+mod cardinal_quadratic_b_spline;
+mod cardinal_quintic_b_spline;
+
+pub use barycentric_rational::*;
+pub use cardinal_cubic_b_spline::*;
+pub use cardinal_quadratic_b_spline::*;
+pub use cardinal_quintic_b_spline::*;
@@ -6,6 +6,30 @@
 //!
 //! See [`constants`] for common mathematical constants.
 //!
+//! ## Statistical Distributions
+//!
+//! See [`distributions`] for the available probability distributions.
+//!
+//! ## Statistics
+//!
+//! See [`statistics`] for descriptive statistics and hypothesis tests.
+//!
+//! ## Interpolation
+//!
+//! See [`interpolators`] for interpolation of tabulated data.
+//!
+//! ## Optimization
+//!
+//! See [`optimization`] for derivative-free global minimization of Rust closures.
+//!
+//! ## Quadrature
+//!
+//! See [`quadrature`] for numerical integration of Rust closures.
+//!
+//! ## Root Finding
+//!
+//! See [`roots`] for solving equations *f(x) = 0* of Rust closures, and for minimizing them.
+//!
 //! ## Special Functions
 //!
 //! <https://boost.org/doc/libs/latest/libs/math/doc/html/special.html>
@@ -296,6 +320,12 @@
 
 pub mod ccmath;
 pub mod constants;
+pub mod distributions;
+pub mod interpolators;
+pub mod optimization;
+pub mod quadrature;
+pub mod roots;
+pub mod statistics;
 
 mod special_functions;
 pub use special_functions::acosh::*;
@@ -0,0 +1,193 @@
+//! boost/math/optimization/cma_es.hpp
+
+use super::{Optimum, Options, optimize};
+use crate::ffi;
+
+/// Covariance matrix adaptation evolution strategy (CMA-ES)
+///
+/// Each generation samples its population from a multivariate normal distribution, and adapts
+/// the mean and the covariance matrix of the distribution towards the best samples. This makes
+/// CMA-ES effective on ill-conditioned and non-separable objectives. The maximum number of
+/// generations must be positive, the population size at least 2, and the learning rate in
+/// *(0, 1\]*; otherwise the optimum is NaN.
+///
+/// The samples are evaluated sequentially, so [`Options::with_threads`] has no effect.
+///
+/// Corresponds to `boost::math::optimization::cma_es` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/cma_es.html>
+///
+/// # Examples
+///
+/// ```
+/// use boost::math::optimization::{CmaEs, Options};
+///
+/// // ill-conditioned ellipsoid, with its minimum 0 at the origin
+/// let f = |x: &[f64]| x.iter().enumerate().map(|(i, xi)| 1e3f64.powi(i as i32) * xi * xi).sum();
+/// let optimum = CmaEs::new(2000).minimize(f, &[(-3.0, 3.0); 3], Options::new().with_seed(3));
+/// assert!(optimum.value < 1e-8);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "cma_es_parameters")]
+pub struct CmaEs {
+    max_generations: usize,
+    population_size: Option<usize>,
+    learning_rate: f64,
+}
+
+impl CmaEs {
+    /// CMA-ES for at most `max_generations` generations, with learning rate 1
+    pub fn new(max_generations: usize) -> Self {
+        Self {
+            max_generations,
+            population_size: None,
+            learning_rate: 1.0,
+        }
+    }
+
+    /// Use the given population size, instead of *4 + ⌊3 ln D⌋* for dimension *D*
+    pub fn with_population_size(self, population_size: usize) -> Self {
+        Self {
+            population_size: Some(population_size),
+            ..self
+        }
+    }
+
+    /// Use the given learning rate of the rank-one update of the covariance matrix
+    pub fn with_learning_rate(self, learning_rate: f64) -> Self {
+        Self {
+            learning_rate,
+            ..self
+        }
+    }
+
+    /// Maximum number of generations
+    pub fn max_generations(&self) -> usize {
+        self.max_generations
+    }
+
+    /// Number of samples in each generation, if not the default
+    pub fn population_size(&self) -> Option<usize> {
+        self.population_size
+    }
+
+    /// Learning rate of the rank-one update of the covariance matrix
+    pub fn learning_rate(&self) -> f64 {
+        self.learning_rate
+    }
+
+    /// Minimizes `f` over the `bounds` *(lower, upper)* of each dimension
+    pub fn minimize<F: Fn(&[f64]) -> f64 + Sync>(
+        &self,
+        f: F,
+        bounds: &[(f64, f64)],
+        options: Options<'_>,
+    ) -> Optimum {
+        let valid = self.max_generations > 0
+            && self.population_size.is_none_or(|n| n >= 2)
+            && self.learning_rate > 0.0
+            && self.learning_rate <= 1.0;
+        if !valid {
+            return Optimum::nan(bounds.len());
+        }
+
+        let history_len = if options.history {
+            self.max_generations + 1
+        } else {
+            0
+        };
+        let (target_value, cancellation) = options.stopping();
+        optimize(f, bounds, history_len, |p| unsafe {
+            ffi::math_cma_es(
+                p.f,
+                p.context,
+                p.lower.as_ptr(),
+                p.upper.as_ptr(),
+                p.lower.len(),
+                self.population_size.unwrap_or(0),
+                self.max_generations,
+                self.learning_rate,
+                options.seed,
+                target_value,
+                cancellation,
+                p.x.as_mut_ptr(),
+                p.value,
+                p.history.as_mut_ptr(),
+                p.history.len(),
+            )
+        })
+    }
+}
+
+/// At most 1000 generations, with the default population size and learning rate 1
+impl Default for CmaEs {
+    fn default() -> Self {
+        Self::new(1000)
+    }
+}
+
+/// CMA-ES of `f` over the `bounds` *(lower, upper)* of each dimension, with the [`Default`]
+/// [`CmaEs`] parameters
+///
+/// Corresponds to `boost::math::optimization::cma_es` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/cma_es.html>
+pub fn cma_es<F: Fn(&[f64]) -> f64 + Sync>(
+    f: F,
+    bounds: &[(f64, f64)],
+    options: Options<'_>,
+) -> Optimum {
+    CmaEs::default().minimize(f, bounds, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::optimization::sphere;
+
+    #[test]
+    fn test_cma_es() {
+        let options = Options::new().with_seed(8).with_history(true);
+        let optimum = CmaEs::new(500).minimize(sphere, &[(-5.0, 5.0); 3], options);
+        assert_eq!(optimum.x.len(), 3);
+        assert!(optimum.value < 1e-10);
+
+        let history = optimum.history.unwrap();
+        assert!(!history.is_empty() && history.len() <= 500);
+        assert!(history.windows(2).all(|w| w[1] <= w[0]));
+    }
+
+    #[test]
+    fn test_cma_es_target_value() {
+        let options = Options::new().with_seed(9).with_target_value(1e-3);
+        let optimum =
+            CmaEs::new(500)
+                .with_population_size(12)
+                .minimize(sphere, &[(-5.0, 5.0); 2], options);
+        assert!(optimum.value <= 1e-3);
+    }
+
+    #[test]
+    fn test_cma_es_invalid() {
+        let options = Options::new();
+        assert!(cma_es(sphere, &[(-1.0, f64::NAN)], options).value.is_nan());
+        let invalid = CmaEs::new(10).with_learning_rate(0.0);
+        assert!(
+            invalid
+                .minimize(sphere, &[(-1.0, 1.0)], options)
+                .value
+                .is_nan()
+        );
+        let invalid = CmaEs::new(10).with_population_size(1);
+        assert!(
+            invalid
+                .minimize(sphere, &[(-1.0, 1.0)], options)
+                .value
+                .is_nan()
+        );
+        assert!(
+            CmaEs::new(0)
+                .minimize(sphere, &[(-1.0, 1.0)], options)
+                .value
+                .is_nan()
+        );
+    }
+}
@@ -0,0 +1,229 @@
+//! boost/math/optimization/differential_evolution.hpp
+
+use super::{Optimum, Options, optimize};
+use crate::ffi;
+
+/// Differential evolution of an objective over a box
+///
+/// Each generation mutates every candidate of the population into a trial point, by adding the
+/// scaled difference of two other candidates to a third, and crosses it over with the candidate.
+/// Trial points that improve on their candidate replace it. The population size must be at least
+/// 4, the maximum number of generations positive, the mutation factor in *(0, 1\]*, and the
+/// crossover probability in *\[0, 1\]*; otherwise the optimum is NaN.
+///
+/// Corresponds to `boost::math::optimization::differential_evolution` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/differential_evolution.html>
+///
+/// # Examples
+///
+/// ```
+/// use boost::math::optimization::{DifferentialEvolution, Options};
+///
+/// // Rosenbrock function, with its minimum 0 at (1, 1)
+/// let f = |x: &[f64]| 100.0 * (x[1] - x[0] * x[0]).powi(2) + (1.0 - x[0]).powi(2);
+/// let optimum = DifferentialEvolution::new(50, 2000)
+///     .with_crossover_probability(0.9)
+///     .minimize(f, &[(-2.0, 2.0); 2], Options::new().with_seed(7));
+/// assert!(optimum.value < 1e-10);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "differential_evolution_parameters")]
+pub struct DifferentialEvolution {
+    population_size: usize,
+    max_generations: usize,
+    mutation_factor: f64,
+    crossover_probability: f64,
+}
+
+impl DifferentialEvolution {
+    /// Differential evolution of `population_size` candidates for at most `max_generations`
+    /// generations, with mutation factor 0.65 and crossover probability 0.5
+    pub fn new(population_size: usize, max_generations: usize) -> Self {
+        Self {
+            population_size,
+            max_generations,
+            mutation_factor: 0.65,
+            crossover_probability: 0.5,
+        }
+    }
+
+    /// Use the given mutation factor, i.e. the scale *F* of the differences
+    pub fn with_mutation_factor(self, mutation_factor: f64) -> Self {
+        Self {
+            mutation_factor,
+            ..self
+        }
+    }
+
+    /// Use the given crossover probability *CR* of each coordinate of the trial points
+    pub fn with_crossover_probability(self, crossover_probability: f64) -> Self {
+        Self {
+            crossover_probability,
+            ..self
+        }
+    }
+
+    /// Number of candidates in each generation
+    pub fn population_size(&self) -> usize {
+        self.population_size
+    }
+
+    /// Maximum number of generations
+    pub fn max_generations(&self) -> usize {
+        self.max_generations
+    }
+
+    /// Mutation factor *F*
+    pub fn mutation_factor(&self) -> f64 {
+        self.mutation_factor
+    }
+
+    /// Crossover probability *CR*
+    pub fn crossover_probability(&self) -> f64 {
+        self.crossover_probability
+    }
+
+    /// Minimizes `f` over the `bounds` *(lower, upper)* of each dimension
+    ///
+    /// The recorded history starts with the best value of the initial population.
+    pub fn minimize<F: Fn(&[f64]) -> f64 + Sync>(
+        &self,
+        f: F,
+        bounds: &[(f64, f64)],
+        options: Options<'_>,
+    ) -> Optimum {
+        let valid = self.population_size >= 4
+            && self.max_generations > 0
+            && self.mutation_factor > 0.0
+            && self.mutation_factor <= 1.0
+            && (0.0..=1.0).contains(&self.crossover_probability);
+        if !valid {
+            return Optimum::nan(bounds.len());
+        }
+
+        let history_len = if options.history {
+            self.max_generations + 1
+        } else {
+            0
+        };
+        let (target_value, cancellation) = options.stopping();
+        optimize(f, bounds, history_len, |p| unsafe {
+            ffi::math_differential_evolution(
+                p.f,
+                p.context,
+                p.lower.as_ptr(),
+                p.upper.as_ptr(),
+                p.lower.len(),
+                self.mutation_factor,
+                self.crossover_probability,
+                self.population_size,
+                self.max_generations,
+                options.threads.unwrap_or(0),
+                options.seed,
+                target_value,
+                cancellation,
+                p.x.as_mut_ptr(),
+                p.value,
+                p.history.as_mut_ptr(),
+                p.history.len(),
+            )
+        })
+    }
+}
+
+/// Population size 500, at most 1000 generations, mutation factor 0.65, and crossover
+/// probability 0.5
+impl Default for DifferentialEvolution {
+    fn default() -> Self {
+        Self::new(500, 1000)
+    }
+}
+
+/// Differential evolution of `f` over the `bounds` *(lower, upper)* of each dimension, with the
+/// [`Default`] [`DifferentialEvolution`] parameters
+///
+/// Corresponds to `boost::math::optimization::differential_evolution` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/differential_evolution.html>
+pub fn differential_evolution<F: Fn(&[f64]) -> f64 + Sync>(
+    f: F,
+    bounds: &[(f64, f64)],
+    options: Options<'_>,
+) -> Optimum {
+    DifferentialEvolution::default().minimize(f, bounds, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::optimization::sphere;
+    use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    #[test]
+    fn test_differential_evolution() {
+        let bounds = [(-5.0, 5.0); 3];
+        let options = Options::new()
+            .with_threads(2)
+            .with_seed(1)
+            .with_history(true);
+        let optimum = DifferentialEvolution::new(40, 300).minimize(sphere, &bounds, options);
+        assert_eq!(optimum.x.len(), 3);
+        assert!(optimum.value < 1e-8);
+        assert_eq!(optimum.value, sphere(&optimum.x));
+
+        let history = optimum.history.unwrap();
+        assert!(!history.is_empty() && history.len() <= 301);
+        assert!(history.windows(2).all(|w| w[1] <= w[0]));
+        assert_eq!(*history.last().unwrap(), optimum.value);
+    }
+
+    #[test]
+    fn test_differential_evolution_target_value() {
+        let calls = AtomicUsize::new(0);
+        let f = |x: &[f64]| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            sphere(x)
+        };
+        let options = Options::new().with_seed(2).with_target_value(1e-2);
+        let optimum = DifferentialEvolution::new(20, 1000).minimize(f, &[(-5.0, 5.0); 2], options);
+        assert!(optimum.value <= 1e-2);
+        assert!(optimum.history.is_none());
+        assert!(calls.load(Ordering::Relaxed) < 20 * 1000);
+    }
+
+    #[test]
+    fn test_differential_evolution_cancellation() {
+        let cancel = AtomicBool::new(true);
+        let calls = AtomicUsize::new(0);
+        let f = |x: &[f64]| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            sphere(x)
+        };
+        let options = Options::new().with_seed(3).with_cancellation(&cancel);
+        let optimum = DifferentialEvolution::new(20, 1000).minimize(f, &[(-5.0, 5.0); 2], options);
+        assert!(optimum.value.is_finite());
+        assert!(calls.load(Ordering::Relaxed) < 20 * 1000);
+    }
+
+    #[test]
+    fn test_differential_evolution_invalid() {
+        let options = Options::new();
+        assert!(differential_evolution(sphere, &[], options).value.is_nan());
+        assert!(
+            differential_evolution(sphere, &[(1.0, -1.0)], options)
+                .value
+                .is_nan()
+        );
+        let optimum = differential_evolution(sphere, &[(0.0, f64::INFINITY)], options);
+        assert!(optimum.value.is_nan());
+        assert!(optimum.x[0].is_nan());
+
+        let de = DifferentialEvolution::new(3, 10);
+        assert!(de.minimize(sphere, &[(-1.0, 1.0)], options).value.is_nan());
+        let de = DifferentialEvolution::new(10, 0);
+        assert!(de.minimize(sphere, &[(-1.0, 1.0)], options).value.is_nan());
+        let de = DifferentialEvolution::new(10, 10).with_mutation_factor(0.0);
+        assert!(de.minimize(sphere, &[(-1.0, 1.0)], options).value.is_nan());
+        let de = DifferentialEvolution::new(10, 10).with_crossover_probability(1.5);
+        assert!(de.minimize(sphere, &[(-1.0, 1.0)], options).value.is_nan());
+    }
+}
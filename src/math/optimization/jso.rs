@@ -0,0 +1,155 @@
+//! boost/math/optimization/jso.hpp
+
+use super::{Optimum, Options, optimize};
+use crate::ffi;
+
+/// jSO, a self-adaptive variant of differential evolution
+///
+/// jSO adapts the mutation factor and the crossover probability from the successful trial points,
+/// and linearly reduces the population size from its initial size, until the maximum number of
+/// objective evaluations is spent. The initial population size must be at least 4, and the
+/// maximum number of evaluations positive; otherwise the optimum is NaN.
+///
+/// The per-generation [history](Options::with_history) is not recorded, since the generations
+/// shrink.
+///
+/// Corresponds to `boost::math::optimization::jso` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/jso.html>
+///
+/// # Examples
+///
+/// ```
+/// use boost::math::optimization::{Jso, Options};
+///
+/// // Rastrigin function, with many local minima, and its global minimum 0 at the origin
+/// let f = |x: &[f64]| {
+///     let tau = core::f64::consts::TAU;
+///     x.iter().map(|xi| xi * xi - 10.0 * (tau * xi).cos() + 10.0).sum::<f64>()
+/// };
+/// let optimum = Jso::new(50_000).minimize(f, &[(-5.12, 5.12); 3], Options::new().with_seed(11));
+/// assert!(optimum.value < 1e-6);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[doc(alias = "jso_parameters")]
+pub struct Jso {
+    initial_population_size: Option<usize>,
+    max_function_evaluations: Option<usize>,
+}
+
+impl Jso {
+    /// jSO with at most `max_function_evaluations` evaluations of the objective
+    pub fn new(max_function_evaluations: usize) -> Self {
+        Self {
+            max_function_evaluations: Some(max_function_evaluations),
+            ..Self::default()
+        }
+    }
+
+    /// Use the given population size in the first generation, instead of
+    /// *⌈25 ln(D + 1) √D⌉* for dimension *D*
+    pub fn with_initial_population_size(self, initial_population_size: usize) -> Self {
+        Self {
+            initial_population_size: Some(initial_population_size),
+            ..self
+        }
+    }
+
+    /// Population size in the first generation, if not the default
+    pub fn initial_population_size(&self) -> Option<usize> {
+        self.initial_population_size
+    }
+
+    /// Maximum number of objective evaluations, if not the default *10000 D* for dimension *D*
+    pub fn max_function_evaluations(&self) -> Option<usize> {
+        self.max_function_evaluations
+    }
+
+    /// Minimizes `f` over the `bounds` *(lower, upper)* of each dimension
+    pub fn minimize<F: Fn(&[f64]) -> f64 + Sync>(
+        &self,
+        f: F,
+        bounds: &[(f64, f64)],
+        options: Options<'_>,
+    ) -> Optimum {
+        let valid = self.initial_population_size.is_none_or(|n| n >= 4)
+            && self.max_function_evaluations != Some(0);
+        if !valid {
+            return Optimum::nan(bounds.len());
+        }
+
+        let (target_value, cancellation) = options.stopping();
+        optimize(f, bounds, 0, |p| unsafe {
+            ffi::math_jso(
+                p.f,
+                p.context,
+                p.lower.as_ptr(),
+                p.upper.as_ptr(),
+                p.lower.len(),
+                self.initial_population_size.unwrap_or(0),
+                self.max_function_evaluations.unwrap_or(0),
+                options.threads.unwrap_or(0),
+                options.seed,
+                target_value,
+                cancellation,
+                p.x.as_mut_ptr(),
+                p.value,
+            );
+            0
+        })
+    }
+}
+
+/// jSO of `f` over the `bounds` *(lower, upper)* of each dimension, with the [`Default`] [`Jso`]
+/// parameters of the reference
+///
+/// Corresponds to `boost::math::optimization::jso` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/jso.html>
+pub fn jso<F: Fn(&[f64]) -> f64 + Sync>(
+    f: F,
+    bounds: &[(f64, f64)],
+    options: Options<'_>,
+) -> Optimum {
+    Jso::default().minimize(f, bounds, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::optimization::sphere;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_jso() {
+        let options = Options::new()
+            .with_threads(2)
+            .with_seed(5)
+            .with_history(true);
+        let optimum = jso(sphere, &[(-5.0, 5.0); 4], options);
+        assert_eq!(optimum.x.len(), 4);
+        assert!(optimum.value < 1e-8);
+        assert!(optimum.history.is_none());
+    }
+
+    #[test]
+    fn test_jso_max_function_evaluations() {
+        let calls = AtomicUsize::new(0);
+        let f = |x: &[f64]| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            sphere(x)
+        };
+        let jso = Jso::new(2000).with_initial_population_size(20);
+        let optimum = jso.minimize(f, &[(-5.0, 5.0); 2], Options::new().with_seed(6));
+        assert!(optimum.value < 1e-2);
+        assert!(calls.load(Ordering::Relaxed) <= 2000 + 20);
+    }
+
+    #[test]
+    fn test_jso_invalid() {
+        let options = Options::new();
+        assert!(jso(sphere, &[(1.0, 1.0)], options).value.is_nan());
+        let jso = Jso::new(100).with_initial_population_size(2);
+        assert!(jso.minimize(sphere, &[(-1.0, 1.0)], options).value.is_nan());
+        let jso = Jso::new(0);
+        assert!(jso.minimize(sphere, &[(-1.0, 1.0)], options).value.is_nan());
+    }
+}
@@ -0,0 +1,223 @@
+//! Derivative-free global optimization
+//!
+//! Corresponds to the Boost Math `boost/math/optimization` C++ headers.
+//! <https://boost.org/doc/libs/latest/libs/math/doc/html/optimization.html>
+//!
+//! - [`DifferentialEvolution`] and [`differential_evolution`]
+//! - [`Jso`] and [`jso`], a self-adaptive variant of differential evolution
+//! - [`CmaEs`] and [`cma_es`], the covariance matrix adaptation evolution strategy
+//! - [`RandomSearch`] and [`random_search`]
+//!
+//! These minimize an objective function `Fn(&[f64]) -> f64 + Sync` over a box, given by the
+//! bounds *(lower, upper)* of each dimension. The bounds must be finite and non-empty, with each
+//! lower bound below its upper bound; otherwise the [`Optimum`] is NaN. The [`Options`] control
+//! the threads, the random seed, a target value, cancellation, and the recording of the best value
+//! of each generation.
+//!
+//! Because the objective is called from the worker threads of the C++ optimizers, a panic in the
+//! objective cannot unwind to the caller, and aborts the process instead.
+
+mod cma_es;
+mod differential_evolution;
+mod jso;
+mod random_search;
+
+pub use cma_es::*;
+pub use differential_evolution::*;
+pub use jso::*;
+pub use random_search::*;
+
+use crate::ffi;
+use alloc::{vec, vec::Vec};
+use core::{ffi::c_void, ptr, slice, sync::atomic::AtomicBool};
+
+/// Options shared by the optimizers
+///
+/// # Examples
+///
+/// ```
+/// use boost::math::optimization::{Options, differential_evolution};
+/// use core::sync::atomic::AtomicBool;
+///
+/// let cancel = AtomicBool::new(false);
+/// let options = Options::new()
+///     .with_threads(2)
+///     .with_seed(42)
+///     .with_target_value(1e-8)
+///     .with_cancellation(&cancel)
+///     .with_history(true);
+/// let optimum = differential_evolution(|x| x[0] * x[0] + x[1] * x[1], &[(-5.0, 5.0); 2], options);
+/// assert!(optimum.value <= 1e-8);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Options<'a> {
+    threads: Option<usize>,
+    seed: u64,
+    target_value: Option<f64>,
+    cancellation: Option<&'a AtomicBool>,
+    history: bool,
+}
+
+impl<'a> Options<'a> {
+    /// Runs on all available threads with a random seed, until the optimizer's own stopping
+    /// criterion is met
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Evaluate the objective on `threads` worker threads, instead of the available hardware
+    /// concurrency
+    pub fn with_threads(self, threads: usize) -> Self {
+        Self {
+            threads: Some(threads),
+            ..self
+        }
+    }
+
+    /// Seed the random number generator with `seed`, or randomly if it is 0
+    pub fn with_seed(self, seed: u64) -> Self {
+        Self { seed, ..self }
+    }
+
+    /// Stop once the objective reaches `target_value` or below
+    pub fn with_target_value(self, target_value: f64) -> Self {
+        Self {
+            target_value: Some(target_value),
+            ..self
+        }
+    }
+
+    /// Stop once `cancellation` is set, e.g. from another thread
+    pub fn with_cancellation(self, cancellation: &'a AtomicBool) -> Self {
+        Self {
+            cancellation: Some(cancellation),
+            ..self
+        }
+    }
+
+    /// Record the best value after each generation in [`Optimum::history`]
+    pub fn with_history(self, history: bool) -> Self {
+        Self { history, ..self }
+    }
+
+    /// Number of worker threads, if not the available hardware concurrency
+    pub fn threads(&self) -> Option<usize> {
+        self.threads
+    }
+
+    /// Seed of the random number generator, where 0 is a random seed
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Value of the objective at which the optimization stops, if any
+    pub fn target_value(&self) -> Option<f64> {
+        self.target_value
+    }
+
+    /// Flag that cancels the optimization once set, if any
+    pub fn cancellation(&self) -> Option<&'a AtomicBool> {
+        self.cancellation
+    }
+
+    /// Whether the best value after each generation is recorded
+    pub fn history(&self) -> bool {
+        self.history
+    }
+
+    /// Arguments of the C++ wrapper for the target value and the cancellation flag.
+    fn stopping(&self) -> (f64, *const AtomicBool) {
+        (
+            self.target_value.unwrap_or(f64::NAN),
+            self.cancellation.map_or(ptr::null(), ptr::from_ref),
+        )
+    }
+}
+
+/// Result of an optimization
+#[derive(Clone, Debug, PartialEq)]
+pub struct Optimum {
+    /// Best point that was found
+    pub x: Vec<f64>,
+    /// Value of the objective at `x`
+    pub value: f64,
+    /// Best value after each generation, if recorded and supported by the optimizer
+    pub history: Option<Vec<f64>>,
+}
+
+impl Optimum {
+    fn nan(dimension: usize) -> Self {
+        Self {
+            x: vec![f64::NAN; dimension],
+            value: f64::NAN,
+            history: None,
+        }
+    }
+}
+
+/// Type-erased objective, split bounds, and outputs of an optimization, passed to the C++
+/// wrapper.
+struct Problem<'a> {
+    f: ffi::Objective,
+    context: *const c_void,
+    lower: &'a [f64],
+    upper: &'a [f64],
+    x: &'a mut [f64],
+    value: &'a mut f64,
+    history: &'a mut [f64],
+}
+
+/// Runs an optimizer, which writes the best point and its value, and at most `history_len`
+/// generation minima of which it returns the number.
+///
+/// The bounds are validated first, since the C++ optimizers throw on invalid bounds.
+fn optimize<F: Fn(&[f64]) -> f64 + Sync>(
+    f: F,
+    bounds: &[(f64, f64)],
+    history_len: usize,
+    run: impl FnOnce(Problem<'_>) -> usize,
+) -> Optimum {
+    let valid = !bounds.is_empty()
+        && bounds
+            .iter()
+            .all(|&(lower, upper)| lower.is_finite() && upper.is_finite() && lower < upper);
+    if !valid {
+        return Optimum::nan(bounds.len());
+    }
+
+    let (lower, upper): (Vec<f64>, Vec<f64>) = bounds.iter().copied().unzip();
+    let mut x = vec![f64::NAN; bounds.len()];
+    let mut value = f64::NAN;
+    let mut history = vec![f64::NAN; history_len];
+    let generations = run(Problem {
+        f: call::<F>,
+        context: ptr::from_ref(&f).cast(),
+        lower: &lower,
+        upper: &upper,
+        x: &mut x,
+        value: &mut value,
+        history: &mut history,
+    });
+    history.truncate(generations);
+    Optimum {
+        x,
+        value,
+        history: (history_len > 0).then_some(history),
+    }
+}
+
+/// Calls the closure that `f` points to; passed to the C++ wrapper as [`ffi::Objective`].
+unsafe extern "C" fn call<F: Fn(&[f64]) -> f64 + Sync>(
+    f: *const c_void,
+    x: *const f64,
+    len: usize,
+) -> f64 {
+    let (f, x) = unsafe { (&*f.cast::<F>(), slice::from_raw_parts(x, len)) };
+    f(x)
+}
+
+/// Sphere function *Σ x<sub>i</sub><sup>2</sup>*, with its minimum 0 at the origin
+#[cfg(test)]
+fn sphere(x: &[f64]) -> f64 {
+    x.iter().map(|xi| xi * xi).sum()
+}
@@ -0,0 +1,132 @@
+//! boost/math/optimization/random_search.hpp
+
+use super::{Optimum, Options, optimize};
+use crate::ffi;
+
+/// Random search of an objective over a box
+///
+/// The objective is evaluated at points sampled uniformly at random from the box, and the best
+/// one is kept. Random search makes no assumptions about the objective, so it serves as a
+/// baseline, or as an initial guess for the other optimizers. The maximum number of objective
+/// evaluations must be positive; otherwise the optimum is NaN. Random search has no generations,
+/// so the [history](Options::with_history) is not recorded.
+///
+/// Corresponds to `boost::math::optimization::random_search` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/random_search.html>
+///
+/// # Examples
+///
+/// ```
+/// use boost::math::optimization::{Options, RandomSearch};
+///
+/// let f = |x: &[f64]| (x[0] - 0.5).abs() + (x[1] + 0.5).abs();
+/// let optimum = RandomSearch::new(100_000).minimize(f, &[(-1.0, 1.0); 2], Options::new());
+/// assert!(optimum.value < 0.05);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[doc(alias = "random_search_parameters")]
+pub struct RandomSearch {
+    max_function_calls: Option<usize>,
+}
+
+impl RandomSearch {
+    /// Random search with at most `max_function_calls` evaluations of the objective
+    pub fn new(max_function_calls: usize) -> Self {
+        Self {
+            max_function_calls: Some(max_function_calls),
+        }
+    }
+
+    /// Maximum number of objective evaluations, if not the default *10000 D* for dimension *D*
+    pub fn max_function_calls(&self) -> Option<usize> {
+        self.max_function_calls
+    }
+
+    /// Minimizes `f` over the `bounds` *(lower, upper)* of each dimension
+    pub fn minimize<F: Fn(&[f64]) -> f64 + Sync>(
+        &self,
+        f: F,
+        bounds: &[(f64, f64)],
+        options: Options<'_>,
+    ) -> Optimum {
+        if self.max_function_calls == Some(0) {
+            return Optimum::nan(bounds.len());
+        }
+
+        let (target_value, cancellation) = options.stopping();
+        optimize(f, bounds, 0, |p| unsafe {
+            ffi::math_random_search(
+                p.f,
+                p.context,
+                p.lower.as_ptr(),
+                p.upper.as_ptr(),
+                p.lower.len(),
+                self.max_function_calls.unwrap_or(0),
+                options.threads.unwrap_or(0),
+                options.seed,
+                target_value,
+                cancellation,
+                p.x.as_mut_ptr(),
+                p.value,
+            );
+            0
+        })
+    }
+}
+
+/// Random search of `f` over the `bounds` *(lower, upper)* of each dimension, with the
+/// [`Default`] [`RandomSearch`] parameters
+///
+/// Corresponds to `boost::math::optimization::random_search` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/random_search.html>
+pub fn random_search<F: Fn(&[f64]) -> f64 + Sync>(
+    f: F,
+    bounds: &[(f64, f64)],
+    options: Options<'_>,
+) -> Optimum {
+    RandomSearch::default().minimize(f, bounds, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_random_search() {
+        let calls = AtomicUsize::new(0);
+        let f = |x: &[f64]| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            x.iter().map(|xi| xi * xi).sum()
+        };
+        let options = Options::new().with_threads(2).with_seed(4);
+        let optimum = RandomSearch::new(10_000).minimize(f, &[(-1.0, 1.0); 2], options);
+        assert!(optimum.value < 1e-2);
+        assert!(optimum.history.is_none());
+        assert!(calls.load(Ordering::Relaxed) <= 10_000);
+    }
+
+    #[test]
+    fn test_random_search_target_value() {
+        let options = Options::new().with_seed(5).with_target_value(0.1);
+        let optimum = random_search(|x| x[0].abs(), &[(-1.0, 1.0)], options);
+        assert!(optimum.value <= 0.1);
+    }
+
+    #[test]
+    fn test_random_search_invalid() {
+        let options = Options::new();
+        assert!(random_search(|x| x[0], &[], options).x.is_empty());
+        assert!(
+            random_search(|x| x[0], &[(2.0, 1.0)], options)
+                .value
+                .is_nan()
+        );
+        assert!(
+            RandomSearch::new(0)
+                .minimize(|x| x[0], &[(-1.0, 1.0)], options)
+                .value
+                .is_nan()
+        );
+    }
+}
@@ -0,0 +1,247 @@
+//! boost/math/quadrature/exp_sinh.hpp
+
+#[cfg(feature = "num-complex")]
+use super::erase_complex;
+use super::{Integral, ROOT_EPSILON, erase};
+use crate::ffi;
+use core::ffi::c_void;
+#[cfg(feature = "num-complex")]
+use num_complex::Complex;
+
+/// Exp-sinh quadrature *∫<sub>a</sub><sup>∞</sup> f(x) dx* or *∫<sub>-∞</sub><sup>b</sup> f(x) dx*
+///
+/// This double exponential quadrature integrates over half-infinite intervals, so exactly one of
+/// the endpoints must be infinite; otherwise the result is NaN. Like [`TanhSinh`](super::TanhSinh),
+/// it handles integrable singularities at the finite endpoint, and it caches the abscissas and
+/// weights of the levels it has used, so it should be reused for many integrals.
+///
+/// Corresponds to `boost::math::quadrature::exp_sinh<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/double_exponential/de_exp_sinh.html>
+///
+/// # Examples
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use boost::math::quadrature::ExpSinh;
+///
+/// // Γ(1/2) = √π
+/// let result = ExpSinh::default().integrate(|x| (-x).exp() / x.sqrt(), 0.0, f64::INFINITY);
+/// assert_relative_eq!(result.value, core::f64::consts::PI.sqrt(), max_relative = 1e-9);
+/// ```
+#[derive(Debug)]
+#[doc(alias = "exp_sinh")]
+pub struct ExpSinh {
+    integrator: *mut c_void,
+    tolerance: f64,
+    max_refinements: usize,
+}
+
+// The C++ integrator is owned, and only accessed through `&mut self`.
+unsafe impl Send for ExpSinh {}
+
+impl ExpSinh {
+    /// Exp-sinh integrator with relative `tolerance` and at most `max_refinements` levels
+    pub fn new(tolerance: f64, max_refinements: usize) -> Self {
+        Self {
+            integrator: unsafe { ffi::math_exp_sinh_new(max_refinements) },
+            tolerance,
+            max_refinements,
+        }
+    }
+
+    /// Relative tolerance at which the refinement stops
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    /// Maximum number of refinement levels
+    pub fn max_refinements(&self) -> usize {
+        self.max_refinements
+    }
+
+    /// Integrates `f` over *\[a, ∞)* or *(-∞, b\]*
+    pub fn integrate<F: FnMut(f64) -> f64>(&mut self, mut f: F, a: f64, b: f64) -> Integral {
+        if a.is_finite() == b.is_finite() {
+            return Integral::NAN;
+        }
+        let (callback, context) = erase(&mut f);
+        let (mut error, mut l1_norm, mut levels) = (f64::NAN, f64::NAN, 0);
+        let value = unsafe {
+            ffi::math_exp_sinh(
+                self.integrator,
+                callback,
+                context,
+                a,
+                b,
+                self.tolerance,
+                &mut error,
+                &mut l1_norm,
+                &mut levels,
+            )
+        };
+        Integral {
+            value,
+            error,
+            l1_norm,
+            levels: Some(levels),
+        }
+    }
+
+    /// Integrates the complex-valued `f` over *\[a, ∞)* or *(-∞, b\]*
+    ///
+    /// The error estimate and the L1 norm are computed from the moduli of the values.
+    #[cfg(feature = "num-complex")]
+    pub fn integrate_complex<F: FnMut(f64) -> Complex<f64>>(
+        &mut self,
+        mut f: F,
+        a: f64,
+        b: f64,
+    ) -> Integral<Complex<f64>> {
+        if a.is_finite() == b.is_finite() {
+            return Integral {
+                value: Complex::new(f64::NAN, f64::NAN),
+                error: f64::NAN,
+                l1_norm: f64::NAN,
+                levels: None,
+            };
+        }
+        let (callback, context) = erase_complex(&mut f);
+        let (mut re, mut im) = (f64::NAN, f64::NAN);
+        let (mut error, mut l1_norm, mut levels) = (f64::NAN, f64::NAN, 0);
+        unsafe {
+            ffi::math_exp_sinh_complex(
+                self.integrator,
+                callback,
+                context,
+                a,
+                b,
+                self.tolerance,
+                &mut re,
+                &mut im,
+                &mut error,
+                &mut l1_norm,
+                &mut levels,
+            )
+        };
+        Integral {
+            value: Complex::new(re, im),
+            error,
+            l1_norm,
+            levels: Some(levels),
+        }
+    }
+}
+
+/// Relative tolerance *√ε* and at most 9 refinement levels
+impl Default for ExpSinh {
+    fn default() -> Self {
+        Self::new(ROOT_EPSILON, 9)
+    }
+}
+
+impl Clone for ExpSinh {
+    fn clone(&self) -> Self {
+        Self::new(self.tolerance, self.max_refinements)
+    }
+}
+
+impl Drop for ExpSinh {
+    fn drop(&mut self) {
+        unsafe { ffi::math_exp_sinh_delete(self.integrator) };
+    }
+}
+
+/// Exp-sinh quadrature of `f` over *\[a, ∞)* or *(-∞, b\]* with the [`Default`] [`ExpSinh`]
+/// integrator
+///
+/// Corresponds to `boost::math::quadrature::exp_sinh<double>().integrate(f, a, b)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/double_exponential/de_exp_sinh.html>
+pub fn exp_sinh<F: FnMut(f64) -> f64>(f: F, a: f64, b: f64) -> Integral {
+    ExpSinh::default().integrate(f, a, b)
+}
+
+/// Exp-sinh quadrature of the complex-valued `f` over *\[a, ∞)* or *(-∞, b\]* with the
+/// [`Default`] [`ExpSinh`] integrator
+///
+/// Corresponds to `boost::math::quadrature::exp_sinh<double>().integrate(f, a, b)` in C++, with
+/// `f` returning `std::complex<double>`.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/double_exponential/de_exp_sinh.html>
+#[cfg(feature = "num-complex")]
+pub fn exp_sinh_complex<F: FnMut(f64) -> Complex<f64>>(
+    f: F,
+    a: f64,
+    b: f64,
+) -> Integral<Complex<f64>> {
+    ExpSinh::default().integrate_complex(f, a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RTOL: f64 = 1e-9;
+
+    #[test]
+    fn test_exp_sinh() {
+        let result = exp_sinh(|x| (-x).exp(), 0.0, f64::INFINITY);
+        assert_relative_eq!(result.value, 1.0, max_relative = RTOL);
+        assert_relative_eq!(result.l1_norm, 1.0, max_relative = RTOL);
+        assert!(result.error < RTOL);
+        assert!(result.levels.is_some());
+
+        let result = exp_sinh(|x| x.exp(), f64::NEG_INFINITY, 0.0);
+        assert_relative_eq!(result.value, 1.0, max_relative = RTOL);
+
+        let result = exp_sinh(|x| 1.0 / (x * x), 1.0, f64::INFINITY);
+        assert_relative_eq!(result.value, 1.0, max_relative = RTOL);
+    }
+
+    #[test]
+    fn test_exp_sinh_reuse() {
+        let mut integrator = ExpSinh::default();
+        let first = integrator.integrate(|x| (-x * x).exp(), 0.0, f64::INFINITY);
+        assert_relative_eq!(
+            first.value,
+            core::f64::consts::PI.sqrt() / 2.0,
+            max_relative = RTOL
+        );
+        let second = integrator.integrate(|x| (-x * x).exp(), 0.0, f64::INFINITY);
+        assert_eq!(second, first);
+        let mut clone = integrator.clone();
+        assert_eq!(
+            clone.integrate(|x| (-x * x).exp(), 0.0, f64::INFINITY),
+            first
+        );
+    }
+
+    #[test]
+    fn test_exp_sinh_invalid() {
+        assert!(exp_sinh(|x| x, 0.0, 1.0).value.is_nan());
+        assert!(
+            exp_sinh(|x| x, f64::NEG_INFINITY, f64::INFINITY)
+                .value
+                .is_nan()
+        );
+    }
+
+    #[cfg(feature = "num-complex")]
+    #[test]
+    fn test_exp_sinh_complex() {
+        use num_complex::Complex;
+
+        // ∫₀^∞ e^((i-1)x) dx = 1 / (1 - i) = (1 + i) / 2
+        let result = exp_sinh_complex(|x| Complex::new(-x, x).exp(), 0.0, f64::INFINITY);
+        assert_relative_eq!(result.value.re, 0.5, max_relative = RTOL);
+        assert_relative_eq!(result.value.im, 0.5, max_relative = RTOL);
+        assert!(result.error < RTOL);
+
+        let result = exp_sinh_complex(|x| Complex::new(x, x), 0.0, 1.0);
+        assert!(result.value.re.is_nan() && result.value.im.is_nan());
+    }
+
+    #[test]
+    #[should_panic(expected = "integrand panicked")]
+    fn test_exp_sinh_panic() {
+        exp_sinh(|_| panic!("integrand panicked"), 0.0, f64::INFINITY);
+    }
+}
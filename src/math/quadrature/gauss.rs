@@ -0,0 +1,142 @@
+//! boost/math/quadrature/gauss.hpp
+
+use super::{Integral, erase};
+use crate::ffi;
+use core::{ffi::c_uint, slice};
+
+/// Gauss-Legendre quadrature with `POINTS` nodes
+///
+/// An *n*-point rule integrates polynomials of degree up to *2n - 1* exactly, which makes it
+/// well suited for smooth integrands. It provides no error estimate; see
+/// [`GaussKronrod`](super::GaussKronrod) for that.
+///
+/// The supported numbers of points are 7, 10, 15, 20, 25, and 30. Other values result in a
+/// compile-time error.
+///
+/// Corresponds to `boost::math::quadrature::gauss<double, POINTS>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/gauss.html>
+///
+/// # Examples
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use boost::math::quadrature::Gauss;
+///
+/// let result = Gauss::<7>::integrate(|x| x.powi(13), 0.0, 1.0);
+/// assert_relative_eq!(result.value, 1.0 / 14.0, max_relative = 1e-14);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[doc(alias = "gauss_legendre")]
+pub struct Gauss<const POINTS: usize>;
+
+impl<const POINTS: usize> Gauss<POINTS> {
+    const SUPPORTED: () = assert!(
+        matches!(POINTS, 7 | 10 | 15 | 20 | 25 | 30),
+        "Gauss quadrature supports 7, 10, 15, 20, 25, or 30 points"
+    );
+
+    /// Integrates `f` over *\[a, b\]*
+    ///
+    /// Infinite endpoints are mapped onto a finite interval. The `error` of the result is NaN, as
+    /// the rule provides no error estimate.
+    pub fn integrate<F: FnMut(f64) -> f64>(mut f: F, a: f64, b: f64) -> Integral {
+        let () = Self::SUPPORTED;
+        let (callback, context) = erase(&mut f);
+        let mut l1_norm = f64::NAN;
+        let value =
+            unsafe { ffi::math_gauss(POINTS as c_uint, callback, context, a, b, &mut l1_norm) };
+        Integral {
+            value,
+            error: f64::NAN,
+            l1_norm,
+            levels: None,
+        }
+    }
+
+    /// Non-negative abscissas (nodes) *x<sub>i</sub>* on *\[-1, 1\]* in ascending order
+    ///
+    /// The negative abscissas follow by symmetry.
+    pub fn abscissa() -> &'static [f64] {
+        let () = Self::SUPPORTED;
+        unsafe {
+            slice::from_raw_parts(
+                ffi::math_gauss_abscissa(POINTS as c_uint),
+                POINTS.div_ceil(2),
+            )
+        }
+    }
+
+    /// Weights *w<sub>i</sub>* of the [`abscissa`](Self::abscissa)
+    pub fn weights() -> &'static [f64] {
+        let () = Self::SUPPORTED;
+        unsafe {
+            slice::from_raw_parts(
+                ffi::math_gauss_weights(POINTS as c_uint),
+                POINTS.div_ceil(2),
+            )
+        }
+    }
+}
+
+/// Gauss-Legendre quadrature of `f` over *\[a, b\]* with `POINTS` nodes
+///
+/// See [`Gauss`] for details.
+///
+/// Corresponds to `boost::math::quadrature::gauss<double, POINTS>::integrate(f, a, b)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/gauss.html>
+pub fn gauss<const POINTS: usize, F: FnMut(f64) -> f64>(f: F, a: f64, b: f64) -> Integral {
+    Gauss::<POINTS>::integrate(f, a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::legendre_p_zeros;
+    use core::f64::consts::E;
+
+    const RTOL: f64 = 1e-14;
+
+    /// Sum of the weights over the symmetric abscissas on [-1, 1]
+    fn total_weight(abscissa: &[f64], weights: &[f64]) -> f64 {
+        let sum: f64 = weights.iter().sum();
+        if abscissa[0] == 0.0 {
+            2.0 * sum - weights[0]
+        } else {
+            2.0 * sum
+        }
+    }
+
+    #[test]
+    fn test_gauss() {
+        let result = gauss::<7, _>(|x| x.powi(13), 0.0, 1.0);
+        assert_relative_eq!(result.value, 1.0 / 14.0, max_relative = RTOL);
+        assert!(result.error.is_nan());
+
+        let result = gauss::<10, _>(|x| x.exp(), 0.0, 1.0);
+        assert_relative_eq!(result.value, E - 1.0, max_relative = RTOL);
+        assert_relative_eq!(result.l1_norm, E - 1.0, max_relative = RTOL);
+
+        let result = Gauss::<30>::integrate(|x| x.sin(), -1.0, 1.0);
+        assert_abs_diff_eq!(result.value, 0.0, epsilon = RTOL);
+    }
+
+    #[test]
+    fn test_gauss_tables() {
+        fn check<const N: usize>() {
+            let (x, w) = (Gauss::<N>::abscissa(), Gauss::<N>::weights());
+            assert_eq!(x.len(), N.div_ceil(2));
+            assert_eq!(w.len(), x.len());
+            assert!(x.is_sorted());
+            for (xi, zi) in x.iter().zip(legendre_p_zeros(N)) {
+                assert_abs_diff_eq!(*xi, zi, epsilon = RTOL);
+            }
+            assert_relative_eq!(total_weight(x, w), 2.0, max_relative = RTOL);
+        }
+        check::<7>();
+        check::<10>();
+        check::<15>();
+        check::<20>();
+        check::<25>();
+        check::<30>();
+    }
+}
@@ -0,0 +1,194 @@
+//! boost/math/quadrature/gauss_kronrod.hpp
+
+use super::{Integral, ROOT_EPSILON, erase};
+use crate::ffi;
+use core::{ffi::c_uint, slice};
+
+/// (Adaptive) Gauss-Kronrod quadrature with `POINTS` nodes
+///
+/// A Gauss-Kronrod rule extends an *n*-point Gauss rule with *n + 1* nodes, so that the
+/// difference between the two gives an error estimate. The supported (Gauss/Kronrod) numbers of
+/// points are 7/15, 10/21, 15/31, 20/41, 25/51, and 30/61, where `POINTS` is the Kronrod one.
+/// Other values result in a compile-time error.
+///
+/// If the estimated relative error exceeds the tolerance, the interval is bisected recursively,
+/// up to the maximum depth. A maximum depth of 0 evaluates the rule only once.
+///
+/// Corresponds to `boost::math::quadrature::gauss_kronrod<double, POINTS>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/gauss_kronrod.html>
+///
+/// # Examples
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use boost::math::quadrature::GaussKronrod;
+///
+/// let integrator = GaussKronrod::<15>::new(1e-12, 20);
+/// let result = integrator.integrate(|x| x.sqrt(), 0.0, 1.0);
+/// assert_relative_eq!(result.value, 2.0 / 3.0, max_relative = 1e-12);
+/// assert!(result.error < 1e-12);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GaussKronrod<const POINTS: usize> {
+    tolerance: f64,
+    max_depth: u32,
+}
+
+impl<const POINTS: usize> GaussKronrod<POINTS> {
+    const SUPPORTED: () = assert!(
+        matches!(POINTS, 15 | 21 | 31 | 41 | 51 | 61),
+        "Gauss-Kronrod quadrature supports 15, 21, 31, 41, 51, or 61 points"
+    );
+
+    /// Gauss-Kronrod integrator with relative `tolerance` and recursion depth of at most
+    /// `max_depth`
+    pub fn new(tolerance: f64, max_depth: u32) -> Self {
+        let () = Self::SUPPORTED;
+        Self {
+            tolerance,
+            max_depth,
+        }
+    }
+
+    /// Relative tolerance at which the bisection stops
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    /// Maximum recursion depth of the bisection
+    pub fn max_depth(&self) -> u32 {
+        self.max_depth
+    }
+
+    /// Integrates `f` over *\[a, b\]*
+    ///
+    /// Infinite endpoints are mapped onto a finite interval.
+    pub fn integrate<F: FnMut(f64) -> f64>(&self, mut f: F, a: f64, b: f64) -> Integral {
+        let (callback, context) = erase(&mut f);
+        let (mut error, mut l1_norm) = (f64::NAN, f64::NAN);
+        let value = unsafe {
+            ffi::math_gauss_kronrod(
+                POINTS as c_uint,
+                callback,
+                context,
+                a,
+                b,
+                self.max_depth,
+                self.tolerance,
+                &mut error,
+                &mut l1_norm,
+            )
+        };
+        Integral {
+            value,
+            error,
+            l1_norm,
+            levels: None,
+        }
+    }
+
+    /// Non-negative abscissas (nodes) *x<sub>i</sub>* on *\[-1, 1\]* in ascending order
+    ///
+    /// The negative abscissas follow by symmetry. Every other abscissa is also an abscissa of the
+    /// embedded Gauss rule.
+    pub fn abscissa() -> &'static [f64] {
+        let () = Self::SUPPORTED;
+        let x = unsafe { ffi::math_gauss_kronrod_abscissa(POINTS as c_uint) };
+        unsafe { slice::from_raw_parts(x, POINTS.div_ceil(2)) }
+    }
+
+    /// Kronrod weights of the [`abscissa`](Self::abscissa)
+    pub fn weights() -> &'static [f64] {
+        let () = Self::SUPPORTED;
+        let w = unsafe { ffi::math_gauss_kronrod_weights(POINTS as c_uint) };
+        unsafe { slice::from_raw_parts(w, POINTS.div_ceil(2)) }
+    }
+
+    /// Weights of the embedded Gauss rule at the [`abscissa`](Self::abscissa), which are 0 at
+    /// the abscissas that only belong to the Kronrod rule
+    pub fn gauss_weights() -> &'static [f64] {
+        let () = Self::SUPPORTED;
+        let w = unsafe { ffi::math_gauss_kronrod_gauss_weights(POINTS as c_uint) };
+        unsafe { slice::from_raw_parts(w, POINTS.div_ceil(2)) }
+    }
+}
+
+/// Relative tolerance *√ε* and a maximum recursion depth of 15
+impl<const POINTS: usize> Default for GaussKronrod<POINTS> {
+    fn default() -> Self {
+        Self::new(ROOT_EPSILON, 15)
+    }
+}
+
+/// Adaptive Gauss-Kronrod quadrature of `f` over *\[a, b\]* with `POINTS` nodes, and the
+/// [`Default`] tolerance and maximum depth
+///
+/// See [`GaussKronrod`] for details.
+///
+/// Corresponds to `boost::math::quadrature::gauss_kronrod<double, POINTS>::integrate(f, a, b)` in
+/// C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/gauss_kronrod.html>
+pub fn gauss_kronrod<const POINTS: usize, F: FnMut(f64) -> f64>(f: F, a: f64, b: f64) -> Integral {
+    GaussKronrod::<POINTS>::default().integrate(f, a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::consts::PI;
+
+    const RTOL: f64 = 1e-14;
+
+    /// Sum of the weights over the symmetric abscissas on [-1, 1]
+    fn total_weight(weights: &[f64]) -> f64 {
+        2.0 * weights.iter().sum::<f64>() - weights[0]
+    }
+
+    #[test]
+    fn test_gauss_kronrod() {
+        let result = GaussKronrod::<15>::new(RTOL, 0).integrate(|x| x.powi(22), 0.0, 1.0);
+        assert_relative_eq!(result.value, 1.0 / 23.0, max_relative = RTOL);
+        // without refinement, the error estimate is |K15 - G7|, where only K15 is exact here
+        let gauss = crate::math::quadrature::gauss::<7, _>(|x| x.powi(22), 0.0, 1.0);
+        assert_relative_eq!(
+            result.error,
+            (result.value - gauss.value).abs(),
+            max_relative = 1e-6
+        );
+        assert!(result.error > 1e-6);
+
+        let result = gauss_kronrod::<15, _>(|x| x.sqrt(), 0.0, 1.0);
+        assert_relative_eq!(result.value, 2.0 / 3.0, max_relative = 1e-8);
+        assert!(result.levels.is_none());
+
+        let result = gauss_kronrod::<61, _>(|x| 1.0 / (1.0 + x * x), 0.0, f64::INFINITY);
+        assert_relative_eq!(result.value, PI / 2.0, max_relative = 1e-8);
+    }
+
+    #[test]
+    fn test_gauss_kronrod_tables() {
+        fn check<const N: usize>() {
+            let x = GaussKronrod::<N>::abscissa();
+            let (wk, wg) = (
+                GaussKronrod::<N>::weights(),
+                GaussKronrod::<N>::gauss_weights(),
+            );
+            assert_eq!(x.len(), N.div_ceil(2));
+            assert_eq!(wk.len(), x.len());
+            assert_eq!(wg.len(), x.len());
+            assert_eq!(x[0], 0.0);
+            assert!(x.is_sorted());
+            let gauss_points = (N - 1) / 2;
+            let nonzero = wg.iter().filter(|&&w| w != 0.0).count();
+            assert_eq!(nonzero, gauss_points.div_ceil(2));
+            assert_relative_eq!(total_weight(wk), 2.0, max_relative = RTOL);
+            assert_relative_eq!(total_weight(wg), 2.0, max_relative = RTOL);
+        }
+        check::<15>();
+        check::<21>();
+        check::<31>();
+        check::<41>();
+        check::<51>();
+        check::<61>();
+    }
+}
@@ -0,0 +1,111 @@
+//! Numerical integration
+//!
+//! Corresponds to the Boost Math `boost/math/quadrature` C++ headers.
+//! <https://boost.org/doc/libs/latest/libs/math/doc/html/quadrature.html>
+//!
+//! ## Double Exponential Quadrature
+//!
+//! - [`TanhSinh`] and [`tanh_sinh`] for finite intervals, including endpoint singularities
+//! - [`ExpSinh`] and [`exp_sinh`] for half-infinite intervals *\[a, ∞)* and *(-∞, b\]*
+//! - [`SinhSinh`] and [`sinh_sinh`] for the real line *(-∞, ∞)*
+//!
+//! Their integrands may also be complex-valued if the `num-complex` feature is enabled, through
+//! `integrate_complex` and the `*_complex` functions, e.g. `tanh_sinh_complex`.
+//!
+//! ## Gaussian Quadrature
+//!
+//! - [`Gauss`] and [`gauss`] for Gauss-Legendre quadrature
+//! - [`GaussKronrod`] and [`gauss_kronrod`] for (adaptive) Gauss-Kronrod quadrature, with an
+//!   error estimate
+//!
+//! Their node and weight tables are available through `abscissa()` and `weights()`.
+//!
+//! ## Fourier Integrals
+//!
+//! - [`OouraFourierSin`] and [`OouraFourierCos`] for oscillatory integrals over *\[0, ∞)*
+//!
+//! ## Monte Carlo Integration
+//!
+//! - [`NaiveMonteCarlo`] for multidimensional integrals over hyper-rectangles
+//!
+//! ## Trapezoidal Quadrature
+//!
+//! - [`Trapezoidal`] and [`trapezoidal`], especially for periodic integrands
+//!
+//! The integrands are Rust closures, which are called back from the C++ integrators. If the
+//! integrand panics, the panic unwinds through the integrator and propagates to the caller, except
+//! for the multithreaded [`NaiveMonteCarlo`].
+
+mod exp_sinh;
+mod gauss;
+mod gauss_kronrod;
+mod naive_monte_carlo;
+mod ooura_fourier_integrals;
+mod sinh_sinh;
+mod tanh_sinh;
+mod trapezoidal;
+
+pub use exp_sinh::*;
+pub use gauss::*;
+pub use gauss_kronrod::*;
+pub use naive_monte_carlo::*;
+pub use ooura_fourier_integrals::*;
+pub use sinh_sinh::*;
+pub use tanh_sinh::*;
+pub use trapezoidal::*;
+
+use crate::callback::erase;
+#[cfg(feature = "num-complex")]
+use crate::ffi;
+#[cfg(feature = "num-complex")]
+use core::ffi::c_void;
+#[cfg(feature = "num-complex")]
+use num_complex::Complex;
+
+/// Default relative tolerance *√ε* of the integrators
+const ROOT_EPSILON: f64 = 1.490_116_119_384_765_6e-8;
+
+/// Result of a numerical integration
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Integral<T = f64> {
+    /// Estimate of the integral
+    pub value: T,
+    /// Estimate of the absolute error of `value`
+    pub error: f64,
+    /// L1 norm *∫|f(x)| dx* of the integrand, so that *L1 / |value|* is the condition number
+    pub l1_norm: f64,
+    /// Number of refinement levels that were used, if reported by the integrator
+    pub levels: Option<usize>,
+}
+
+impl Integral {
+    const NAN: Self = Self {
+        value: f64::NAN,
+        error: f64::NAN,
+        l1_norm: f64::NAN,
+        levels: None,
+    };
+}
+
+/// Calls the complex-valued closure that `f` points to; passed to the C++ wrapper as
+/// [`ffi::ComplexIntegrand`].
+#[cfg(feature = "num-complex")]
+unsafe extern "C-unwind" fn call_complex<F: FnMut(f64) -> Complex<f64>>(
+    f: *mut c_void,
+    x: f64,
+    out_re: *mut f64,
+    out_im: *mut f64,
+) {
+    let f = unsafe { &mut *f.cast::<F>() };
+    let Complex { re, im } = f(x);
+    unsafe {
+        *out_re = re;
+        *out_im = im;
+    }
+}
+
+/// Type-erases a complex-valued closure into a callback and a context pointer for the C++ wrapper.
+#[cfg(feature = "num-complex")]
+fn erase_complex<F: FnMut(f64) -> Complex<f64>>(f: &mut F) -> (ffi::ComplexIntegrand, *mut c_void) {
+    (call_complex::<F>, (f as *mut F).cast())
+}
@@ -0,0 +1,274 @@
+//! boost/math/quadrature/naive_monte_carlo.hpp
+
+use crate::ffi;
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    ffi::c_void,
+    ptr, slice,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// Multithreaded Monte Carlo integration *∫<sub>Ω</sub> f(x) dx* over a hyper-rectangle *Ω*
+///
+/// The integrand is sampled uniformly at random on the bounds, until the estimated error drops
+/// below the error goal, or the integration is cancelled. Infinite bounds are mapped onto finite
+/// ones. The integration blocks the calling thread, but its progress can be queried, and it can
+/// be cancelled, from other threads. Only one integration can run at a time.
+///
+/// The bounds must be non-empty, with each lower bound below its upper bound, and the error goal
+/// must be positive; otherwise the integral is NaN.
+///
+/// Because the integrand is called from the worker threads of the C++ integrator, a panic in the
+/// integrand cannot unwind to the caller, and aborts the process instead.
+///
+/// Corresponds to `boost::math::quadrature::naive_monte_carlo<double, F>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/naive_monte_carlo.html>
+///
+/// # Examples
+///
+/// ```
+/// use boost::math::quadrature::NaiveMonteCarlo;
+///
+/// // volume of the unit ball, i.e. 4π/3
+/// let bounds = [(-1.0, 1.0); 3];
+/// let f = |x: &[f64]| if x.iter().map(|xi| xi * xi).sum::<f64>() <= 1.0 { 1.0 } else { 0.0 };
+/// let integrator = NaiveMonteCarlo::new(f, &bounds, 1e-2, Some(2), 42);
+/// let volume = integrator.integrate();
+/// assert!((volume - 4.0 * core::f64::consts::PI / 3.0).abs() < 0.1);
+/// ```
+///
+/// Querying and cancelling the integration from another thread:
+///
+/// ```
+/// use boost::math::quadrature::NaiveMonteCarlo;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::thread;
+///
+/// let calls = AtomicUsize::new(0);
+/// let f = |x: &[f64]| {
+///     calls.fetch_add(1, Ordering::Relaxed);
+///     x[0] * x[1]
+/// };
+/// // the error goal is too small to ever be met
+/// let integrator = NaiveMonteCarlo::new(f, &[(0.0, 1.0); 2], 1e-12, None, 0);
+/// let estimate = thread::scope(|s| {
+///     let run = s.spawn(|| integrator.integrate());
+///     // wait until the integration is running, so that it is not restarted after the cancel
+///     while calls.load(Ordering::Relaxed) < 10_000 {
+///         thread::yield_now();
+///     }
+///     assert!(integrator.progress() < 1.0);
+///     integrator.cancel();
+///     run.join().unwrap()
+/// });
+/// assert!((estimate - 0.25).abs() < 0.05);
+/// ```
+pub struct NaiveMonteCarlo<F> {
+    integrator: *mut c_void,
+    // owned, and freed after the C++ integrator that holds on to it
+    integrand: *mut F,
+    // rejects concurrent integrations, which would race on the state of the C++ integrator
+    running: AtomicBool,
+}
+
+// The C++ integrator synchronizes the state that is queried and cancelled during an integration,
+// `running` serializes the integrations, and the integrand is called concurrently.
+unsafe impl<F: Send + Sync> Send for NaiveMonteCarlo<F> {}
+unsafe impl<F: Sync> Sync for NaiveMonteCarlo<F> {}
+
+impl<F: Fn(&[f64]) -> f64 + Sync> NaiveMonteCarlo<F> {
+    /// Monte Carlo integrator of `f` over the `bounds` *(lower, upper)* of each dimension
+    ///
+    /// The integration stops once the estimated absolute error is below `error_goal`. It runs on
+    /// `threads` worker threads, defaulting to the available hardware concurrency. The random
+    /// number generator is seeded with `seed`, or randomly if it is 0.
+    pub fn new(
+        f: F,
+        bounds: &[(f64, f64)],
+        error_goal: f64,
+        threads: Option<usize>,
+        seed: u64,
+    ) -> Self {
+        let integrand = Box::into_raw(Box::new(f));
+        let valid = !bounds.is_empty()
+            && bounds.iter().all(|&(lower, upper)| lower < upper)
+            && error_goal > 0.0;
+        if !valid {
+            return Self {
+                integrator: ptr::null_mut(),
+                integrand,
+                running: AtomicBool::new(false),
+            };
+        }
+
+        let (lower, upper): (Vec<f64>, Vec<f64>) = bounds.iter().copied().unzip();
+        let integrator = unsafe {
+            ffi::math_naive_monte_carlo_new(
+                call::<F>,
+                integrand.cast_const().cast(),
+                lower.as_ptr(),
+                upper.as_ptr(),
+                bounds.len(),
+                error_goal,
+                threads.unwrap_or(0),
+                seed,
+            )
+        };
+        Self {
+            integrator,
+            integrand,
+            running: AtomicBool::new(false),
+        }
+    }
+
+    /// Runs the integration until the error goal is met or it is [cancelled](Self::cancel), and
+    /// returns the estimate of the integral
+    ///
+    /// Returns NaN if the integration is already running on another thread.
+    pub fn integrate(&self) -> f64 {
+        if self.integrator.is_null() || self.running.swap(true, Ordering::Acquire) {
+            return f64::NAN;
+        }
+        let value = unsafe { ffi::math_naive_monte_carlo_integrate(self.integrator) };
+        self.running.store(false, Ordering::Release);
+        value
+    }
+
+    /// Stops a running integration, which then returns its current estimate
+    ///
+    /// A cancel before the integration starts is lost, since starting the integration resets it.
+    pub fn cancel(&self) {
+        if !self.integrator.is_null() {
+            unsafe { ffi::math_naive_monte_carlo_cancel(self.integrator) };
+        }
+    }
+
+    /// Changes the error goal, e.g. to accept a less accurate result of a running integration
+    pub fn update_target_error(&self, error_goal: f64) {
+        if !self.integrator.is_null() && error_goal > 0.0 {
+            unsafe { ffi::math_naive_monte_carlo_update_target_error(self.integrator, error_goal) };
+        }
+    }
+
+    /// Current estimate of the integral
+    pub fn current_estimate(&self) -> f64 {
+        if self.integrator.is_null() {
+            return f64::NAN;
+        }
+        unsafe { ffi::math_naive_monte_carlo_current_estimate(self.integrator) }
+    }
+
+    /// Current estimate of the absolute error of the integral
+    pub fn current_error_estimate(&self) -> f64 {
+        if self.integrator.is_null() {
+            return f64::NAN;
+        }
+        unsafe { ffi::math_naive_monte_carlo_current_error_estimate(self.integrator) }
+    }
+
+    /// Progress of the integration towards the error goal, in *\[0, 1\]*
+    pub fn progress(&self) -> f64 {
+        if self.integrator.is_null() {
+            return f64::NAN;
+        }
+        unsafe { ffi::math_naive_monte_carlo_progress(self.integrator) }
+    }
+
+    /// Number of integrand evaluations so far
+    pub fn calls(&self) -> u64 {
+        if self.integrator.is_null() {
+            return 0;
+        }
+        unsafe { ffi::math_naive_monte_carlo_calls(self.integrator) }
+    }
+}
+
+impl<F> core::fmt::Debug for NaiveMonteCarlo<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("NaiveMonteCarlo")
+            .field("integrator", &self.integrator)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F> Drop for NaiveMonteCarlo<F> {
+    fn drop(&mut self) {
+        if !self.integrator.is_null() {
+            unsafe { ffi::math_naive_monte_carlo_delete(self.integrator) };
+        }
+        drop(unsafe { Box::from_raw(self.integrand) });
+    }
+}
+
+/// Calls the closure that `f` points to; passed to the C++ wrapper as
+/// [`ffi::MultivariateIntegrand`]. A panic in the closure aborts, instead of unwinding into the
+/// worker threads of the C++ integrator.
+unsafe extern "C" fn call<F: Fn(&[f64]) -> f64>(
+    f: *const c_void,
+    x: *const f64,
+    len: usize,
+) -> f64 {
+    let (f, x) = unsafe { (&*f.cast::<F>(), slice::from_raw_parts(x, len)) };
+    f(x)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+
+    #[test]
+    fn test_naive_monte_carlo() {
+        let f = |x: &[f64]| x.iter().product::<f64>();
+        let integrator = NaiveMonteCarlo::new(f, &[(0.0, 1.0); 3], 1e-3, Some(2), 42);
+        let value = integrator.integrate();
+        assert_abs_diff_eq!(value, 0.125, epsilon = 1e-2);
+        assert_eq!(integrator.current_estimate(), value);
+        assert!(integrator.current_error_estimate() <= 1e-3);
+        assert!(integrator.calls() > 0);
+    }
+
+    #[test]
+    fn test_naive_monte_carlo_cancel() {
+        let calls = AtomicUsize::new(0);
+        let f = |x: &[f64]| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            x[0].sin() * x[1].cos()
+        };
+        let integrator = NaiveMonteCarlo::new(f, &[(0.0, 1.0); 2], 1e-12, Some(2), 1);
+        let value = thread::scope(|s| {
+            let run = s.spawn(|| integrator.integrate());
+            // a cancel before the integration starts would be overwritten by it
+            while calls.load(Ordering::Relaxed) < 1000 {
+                thread::yield_now();
+            }
+            assert!(integrator.progress() < 1.0);
+            assert!(integrator.integrate().is_nan());
+            integrator.cancel();
+            run.join().unwrap()
+        });
+        assert!(value.is_finite());
+        assert!(integrator.current_error_estimate() > 1e-12);
+    }
+
+    #[test]
+    fn test_naive_monte_carlo_invalid() {
+        let f = |x: &[f64]| x[0];
+        assert!(
+            NaiveMonteCarlo::new(f, &[], 1e-3, None, 0)
+                .integrate()
+                .is_nan()
+        );
+        assert!(
+            NaiveMonteCarlo::new(f, &[(1.0, 0.0)], 1e-3, None, 0)
+                .integrate()
+                .is_nan()
+        );
+        let integrator = NaiveMonteCarlo::new(f, &[(0.0, 1.0)], 0.0, None, 0);
+        assert!(integrator.integrate().is_nan());
+        assert_eq!(integrator.calls(), 0);
+    }
+}
@@ -0,0 +1,204 @@
+//! boost/math/quadrature/sinh_sinh.hpp
+
+#[cfg(feature = "num-complex")]
+use super::erase_complex;
+use super::{Integral, ROOT_EPSILON, erase};
+use crate::ffi;
+use core::ffi::c_void;
+#[cfg(feature = "num-complex")]
+use num_complex::Complex;
+
+/// Sinh-sinh quadrature *∫<sub>-∞</sub><sup>∞</sup> f(x) dx*
+///
+/// This double exponential quadrature integrates over the entire real line. The integrator
+/// caches the abscissas and weights of the levels it has used, so it should be reused for many
+/// integrals.
+///
+/// Corresponds to `boost::math::quadrature::sinh_sinh<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/double_exponential/de_sinh_sinh.html>
+///
+/// # Examples
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use boost::math::quadrature::SinhSinh;
+///
+/// let result = SinhSinh::default().integrate(|x| (-x * x).exp());
+/// assert_relative_eq!(result.value, core::f64::consts::PI.sqrt(), max_relative = 1e-9);
+/// ```
+#[derive(Debug)]
+#[doc(alias = "sinh_sinh")]
+pub struct SinhSinh {
+    integrator: *mut c_void,
+    tolerance: f64,
+    max_refinements: usize,
+}
+
+// The C++ integrator is owned, and only accessed through `&mut self`.
+unsafe impl Send for SinhSinh {}
+
+impl SinhSinh {
+    /// Sinh-sinh integrator with relative `tolerance` and at most `max_refinements` levels
+    pub fn new(tolerance: f64, max_refinements: usize) -> Self {
+        Self {
+            integrator: unsafe { ffi::math_sinh_sinh_new(max_refinements) },
+            tolerance,
+            max_refinements,
+        }
+    }
+
+    /// Relative tolerance at which the refinement stops
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    /// Maximum number of refinement levels
+    pub fn max_refinements(&self) -> usize {
+        self.max_refinements
+    }
+
+    /// Integrates `f` over *(-∞, ∞)*
+    pub fn integrate<F: FnMut(f64) -> f64>(&mut self, mut f: F) -> Integral {
+        let (callback, context) = erase(&mut f);
+        let (mut error, mut l1_norm, mut levels) = (f64::NAN, f64::NAN, 0);
+        let value = unsafe {
+            ffi::math_sinh_sinh(
+                self.integrator,
+                callback,
+                context,
+                self.tolerance,
+                &mut error,
+                &mut l1_norm,
+                &mut levels,
+            )
+        };
+        Integral {
+            value,
+            error,
+            l1_norm,
+            levels: Some(levels),
+        }
+    }
+
+    /// Integrates the complex-valued `f` over *(-∞, ∞)*
+    ///
+    /// The error estimate and the L1 norm are computed from the moduli of the values.
+    #[cfg(feature = "num-complex")]
+    pub fn integrate_complex<F: FnMut(f64) -> Complex<f64>>(
+        &mut self,
+        mut f: F,
+    ) -> Integral<Complex<f64>> {
+        let (callback, context) = erase_complex(&mut f);
+        let (mut re, mut im) = (f64::NAN, f64::NAN);
+        let (mut error, mut l1_norm, mut levels) = (f64::NAN, f64::NAN, 0);
+        unsafe {
+            ffi::math_sinh_sinh_complex(
+                self.integrator,
+                callback,
+                context,
+                self.tolerance,
+                &mut re,
+                &mut im,
+                &mut error,
+                &mut l1_norm,
+                &mut levels,
+            )
+        };
+        Integral {
+            value: Complex::new(re, im),
+            error,
+            l1_norm,
+            levels: Some(levels),
+        }
+    }
+}
+
+/// Relative tolerance *√ε* and at most 9 refinement levels
+impl Default for SinhSinh {
+    fn default() -> Self {
+        Self::new(ROOT_EPSILON, 9)
+    }
+}
+
+impl Clone for SinhSinh {
+    fn clone(&self) -> Self {
+        Self::new(self.tolerance, self.max_refinements)
+    }
+}
+
+impl Drop for SinhSinh {
+    fn drop(&mut self) {
+        unsafe { ffi::math_sinh_sinh_delete(self.integrator) };
+    }
+}
+
+/// Sinh-sinh quadrature of `f` over *(-∞, ∞)* with the [`Default`] [`SinhSinh`] integrator
+///
+/// Corresponds to `boost::math::quadrature::sinh_sinh<double>().integrate(f)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/double_exponential/de_sinh_sinh.html>
+pub fn sinh_sinh<F: FnMut(f64) -> f64>(f: F) -> Integral {
+    SinhSinh::default().integrate(f)
+}
+
+/// Sinh-sinh quadrature of the complex-valued `f` over *(-∞, ∞)* with the [`Default`]
+/// [`SinhSinh`] integrator
+///
+/// Corresponds to `boost::math::quadrature::sinh_sinh<double>().integrate(f)` in C++, with `f`
+/// returning `std::complex<double>`.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/double_exponential/de_sinh_sinh.html>
+#[cfg(feature = "num-complex")]
+pub fn sinh_sinh_complex<F: FnMut(f64) -> Complex<f64>>(f: F) -> Integral<Complex<f64>> {
+    SinhSinh::default().integrate_complex(f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::consts::PI;
+
+    const RTOL: f64 = 1e-9;
+
+    #[test]
+    fn test_sinh_sinh() {
+        let result = sinh_sinh(|x| 1.0 / (1.0 + x * x));
+        assert_relative_eq!(result.value, PI, max_relative = RTOL);
+        assert_relative_eq!(result.l1_norm, PI, max_relative = RTOL);
+        assert!(result.error < RTOL);
+        assert!(result.levels.is_some());
+
+        let result = sinh_sinh(|x| x * (-x * x).exp());
+        assert_abs_diff_eq!(result.value, 0.0, epsilon = RTOL);
+        assert_relative_eq!(result.l1_norm, 1.0, max_relative = RTOL);
+    }
+
+    #[test]
+    fn test_sinh_sinh_reuse() {
+        let mut integrator = SinhSinh::default();
+        let first = integrator.integrate(|x| 1.0 / (1.0 + x * x));
+        assert_relative_eq!(first.value, PI, max_relative = RTOL);
+        assert_eq!(integrator.integrate(|x| 1.0 / (1.0 + x * x)), first);
+        assert_eq!(integrator.clone().integrate(|x| 1.0 / (1.0 + x * x)), first);
+    }
+
+    #[cfg(feature = "num-complex")]
+    #[test]
+    fn test_sinh_sinh_complex() {
+        use num_complex::Complex;
+
+        // ∫ e^(-x²) e^(ix) dx = √π e^(-1/4)
+        let result = sinh_sinh_complex(|x| Complex::new(-x * x, x).exp());
+        assert_relative_eq!(
+            result.value.re,
+            PI.sqrt() * (-0.25f64).exp(),
+            max_relative = RTOL
+        );
+        assert_abs_diff_eq!(result.value.im, 0.0, epsilon = RTOL);
+        assert_relative_eq!(result.l1_norm, PI.sqrt(), max_relative = RTOL);
+    }
+
+    #[test]
+    #[should_panic(expected = "integrand panicked")]
+    fn test_sinh_sinh_panic() {
+        sinh_sinh(|_| panic!("integrand panicked"));
+    }
+}
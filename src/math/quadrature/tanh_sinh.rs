@@ -0,0 +1,267 @@
+//! boost/math/quadrature/tanh_sinh.hpp
+
+#[cfg(feature = "num-complex")]
+use super::erase_complex;
+use super::{Integral, ROOT_EPSILON, erase};
+use crate::ffi;
+use core::ffi::c_void;
+#[cfg(feature = "num-complex")]
+use num_complex::Complex;
+
+/// Tanh-sinh quadrature *∫<sub>a</sub><sup>b</sup> f(x) dx*
+///
+/// This double exponential quadrature clusters the abscissas near the endpoints without ever
+/// evaluating them, so it handles integrable endpoint singularities, such as *ln x* or
+/// *1 / √x* at *x = 0*. Infinite endpoints are mapped onto a finite interval.
+///
+/// Refinement stops once the relative difference between two successive levels drops below the
+/// tolerance, or after the maximum number of refinements. The integrator caches the abscissas and
+/// weights of the levels it has used, so it should be reused for many integrals.
+///
+/// Corresponds to `boost::math::quadrature::tanh_sinh<double>` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/double_exponential/de_tanh_sinh.html>
+///
+/// # Examples
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use boost::math::quadrature::TanhSinh;
+///
+/// let mut integrator = TanhSinh::new(1e-12, 20);
+/// let result = integrator.integrate(|x| x.ln(), 0.0, 1.0);
+/// assert_relative_eq!(result.value, -1.0, max_relative = 1e-12);
+/// let result = integrator.integrate(|x| x.ln() * x.ln(), 0.0, 1.0);
+/// assert_relative_eq!(result.value, 2.0, max_relative = 1e-12);
+/// ```
+///
+/// Complex-valued integrands, with the `num-complex` feature:
+///
+/// ```
+/// # #[cfg(feature = "num-complex")]
+/// # {
+/// # use approx::assert_relative_eq;
+/// use boost::math::{quadrature::tanh_sinh_complex, sph_hankel_1};
+///
+/// let result = tanh_sinh_complex(|x| sph_hankel_1(0.0, x), 1.0, 2.0);
+/// assert_relative_eq!(result.value.re, 0.6593299064355118, max_relative = 1e-9);
+/// # }
+/// ```
+#[derive(Debug)]
+#[doc(alias = "tanh_sinh")]
+#[doc(alias = "double_exponential")]
+pub struct TanhSinh {
+    integrator: *mut c_void,
+    tolerance: f64,
+    max_refinements: usize,
+}
+
+// The C++ integrator is owned, and only accessed through `&mut self`.
+unsafe impl Send for TanhSinh {}
+
+impl TanhSinh {
+    /// Tanh-sinh integrator with relative `tolerance` and at most `max_refinements` levels
+    pub fn new(tolerance: f64, max_refinements: usize) -> Self {
+        Self {
+            integrator: unsafe { ffi::math_tanh_sinh_new(max_refinements) },
+            tolerance,
+            max_refinements,
+        }
+    }
+
+    /// Relative tolerance at which the refinement stops
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    /// Maximum number of refinement levels
+    pub fn max_refinements(&self) -> usize {
+        self.max_refinements
+    }
+
+    /// Integrates `f` over *\[a, b\]*
+    pub fn integrate<F: FnMut(f64) -> f64>(&mut self, mut f: F, a: f64, b: f64) -> Integral {
+        let (callback, context) = erase(&mut f);
+        let (mut error, mut l1_norm, mut levels) = (f64::NAN, f64::NAN, 0);
+        let value = unsafe {
+            ffi::math_tanh_sinh(
+                self.integrator,
+                callback,
+                context,
+                a,
+                b,
+                self.tolerance,
+                &mut error,
+                &mut l1_norm,
+                &mut levels,
+            )
+        };
+        Integral {
+            value,
+            error,
+            l1_norm,
+            levels: Some(levels),
+        }
+    }
+
+    /// Integrates the complex-valued `f` over *\[a, b\]*
+    ///
+    /// The error estimate and the L1 norm are computed from the moduli of the values.
+    #[cfg(feature = "num-complex")]
+    pub fn integrate_complex<F: FnMut(f64) -> Complex<f64>>(
+        &mut self,
+        mut f: F,
+        a: f64,
+        b: f64,
+    ) -> Integral<Complex<f64>> {
+        let (callback, context) = erase_complex(&mut f);
+        let (mut re, mut im) = (f64::NAN, f64::NAN);
+        let (mut error, mut l1_norm, mut levels) = (f64::NAN, f64::NAN, 0);
+        unsafe {
+            ffi::math_tanh_sinh_complex(
+                self.integrator,
+                callback,
+                context,
+                a,
+                b,
+                self.tolerance,
+                &mut re,
+                &mut im,
+                &mut error,
+                &mut l1_norm,
+                &mut levels,
+            )
+        };
+        Integral {
+            value: Complex::new(re, im),
+            error,
+            l1_norm,
+            levels: Some(levels),
+        }
+    }
+}
+
+/// Relative tolerance *√ε* and at most 15 refinement levels
+impl Default for TanhSinh {
+    fn default() -> Self {
+        Self::new(ROOT_EPSILON, 15)
+    }
+}
+
+impl Clone for TanhSinh {
+    fn clone(&self) -> Self {
+        Self::new(self.tolerance, self.max_refinements)
+    }
+}
+
+impl Drop for TanhSinh {
+    fn drop(&mut self) {
+        unsafe { ffi::math_tanh_sinh_delete(self.integrator) };
+    }
+}
+
+/// Tanh-sinh quadrature of `f` over *\[a, b\]* with the [`Default`] [`TanhSinh`] integrator
+///
+/// Corresponds to `boost::math::quadrature::tanh_sinh<double>().integrate(f, a, b)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/double_exponential/de_tanh_sinh.html>
+pub fn tanh_sinh<F: FnMut(f64) -> f64>(f: F, a: f64, b: f64) -> Integral {
+    TanhSinh::default().integrate(f, a, b)
+}
+
+/// Tanh-sinh quadrature of the complex-valued `f` over *\[a, b\]* with the [`Default`]
+/// [`TanhSinh`] integrator
+///
+/// Corresponds to `boost::math::quadrature::tanh_sinh<double>().integrate(f, a, b)` in C++, with
+/// `f` returning `std::complex<double>`.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/double_exponential/de_tanh_sinh.html>
+#[cfg(feature = "num-complex")]
+pub fn tanh_sinh_complex<F: FnMut(f64) -> Complex<f64>>(
+    f: F,
+    a: f64,
+    b: f64,
+) -> Integral<Complex<f64>> {
+    TanhSinh::default().integrate_complex(f, a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::consts::PI;
+
+    const RTOL: f64 = 1e-9;
+
+    #[test]
+    fn test_tanh_sinh() {
+        let result = tanh_sinh(|x| 1.0 / x.sqrt(), 0.0, 1.0);
+        assert_relative_eq!(result.value, 2.0, max_relative = RTOL);
+        assert!(result.error < RTOL);
+        assert_relative_eq!(result.l1_norm, 2.0, max_relative = RTOL);
+        assert!(result.levels.is_some());
+
+        let result = tanh_sinh(|x| -x.ln(), 0.0, 1.0);
+        assert_relative_eq!(result.value, 1.0, max_relative = RTOL);
+
+        let result = tanh_sinh(|x| 1.0 / (1.0 - x * x).sqrt(), -1.0, 1.0);
+        assert_relative_eq!(result.value, PI, max_relative = RTOL);
+
+        let result = tanh_sinh(|x| (-x).exp(), 0.0, f64::INFINITY);
+        assert_relative_eq!(result.value, 1.0, max_relative = RTOL);
+    }
+
+    #[test]
+    fn test_tanh_sinh_refinements() {
+        let integrate = |max_refinements| {
+            let mut calls = 0;
+            let result = TanhSinh::new(1e-14, max_refinements).integrate(
+                |x| {
+                    calls += 1;
+                    x.cos()
+                },
+                0.0,
+                1.0,
+            );
+            (result, calls)
+        };
+        let (coarse, coarse_calls) = integrate(2);
+        let (fine, fine_calls) = integrate(15);
+        assert!(coarse.levels.unwrap() <= 2);
+        assert_relative_eq!(coarse.value, 1.0f64.sin(), max_relative = 1e-3);
+        assert!(fine.levels.unwrap() > 2);
+        assert!(coarse_calls < fine_calls);
+    }
+
+    #[test]
+    fn test_tanh_sinh_reuse() {
+        let mut integrator = TanhSinh::default();
+        let first = integrator.integrate(|x| x.exp(), 0.0, 1.0);
+        assert_relative_eq!(first.value, 1.0f64.exp() - 1.0, max_relative = RTOL);
+        assert_eq!(integrator.integrate(|x| x.exp(), 0.0, 1.0), first);
+        assert_eq!(integrator.clone().integrate(|x| x.exp(), 0.0, 1.0), first);
+    }
+
+    #[cfg(feature = "num-complex")]
+    #[test]
+    fn test_tanh_sinh_complex() {
+        use num_complex::Complex;
+
+        // ∫₀¹ e^(ix) dx = sin(1) + i (1 - cos(1))
+        let result = tanh_sinh_complex(|x| Complex::new(x.cos(), x.sin()), 0.0, 1.0);
+        assert_relative_eq!(result.value.re, 1.0f64.sin(), max_relative = RTOL);
+        assert_relative_eq!(result.value.im, 1.0 - 1.0f64.cos(), max_relative = RTOL);
+        assert_relative_eq!(result.l1_norm, 1.0, max_relative = RTOL);
+        assert!(result.error < RTOL);
+
+        // the same integrator serves complex- and real-valued integrands
+        let mut integrator = TanhSinh::default();
+        let result = integrator.integrate_complex(|x| Complex::new(x, -x), 0.0, 1.0);
+        assert_relative_eq!(result.value.re, 0.5, max_relative = RTOL);
+        assert_relative_eq!(result.value.im, -0.5, max_relative = RTOL);
+        let result = integrator.integrate(|x| x, 0.0, 1.0);
+        assert_relative_eq!(result.value, 0.5, max_relative = RTOL);
+    }
+
+    #[test]
+    #[should_panic(expected = "integrand panicked")]
+    fn test_tanh_sinh_panic() {
+        tanh_sinh(|_| panic!("integrand panicked"), 0.0, 1.0);
+    }
+}
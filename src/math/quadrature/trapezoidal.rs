@@ -0,0 +1,133 @@
+//! boost/math/quadrature/trapezoidal.hpp
+
+use super::{Integral, ROOT_EPSILON, erase};
+use crate::ffi;
+
+/// Adaptive trapezoidal quadrature *∫<sub>a</sub><sup>b</sup> f(x) dx* on a finite interval
+///
+/// The trapezoidal rule converges exponentially for smooth periodic integrands over a full
+/// period, but only quadratically otherwise. Each refinement halves the step size, until the
+/// estimated relative error drops below the tolerance, or the maximum number of refinements has
+/// been reached.
+///
+/// Corresponds to `boost::math::quadrature::trapezoidal(f, a, b, tolerance, max_refinements)` in
+/// C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/trapezoidal.html>
+///
+/// # Examples
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use boost::math::quadrature::trapezoidal;
+/// use core::f64::consts::PI;
+///
+/// let result = trapezoidal(|x| 1.0 / (2.0 + x.cos()), 0.0, 2.0 * PI);
+/// assert_relative_eq!(result.value, 2.0 * PI / 3.0f64.sqrt(), max_relative = 1e-12);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Trapezoidal {
+    tolerance: f64,
+    max_refinements: usize,
+}
+
+impl Trapezoidal {
+    /// Trapezoidal integrator with relative `tolerance` and at most `max_refinements` halvings of
+    /// the step size
+    pub fn new(tolerance: f64, max_refinements: usize) -> Self {
+        Self {
+            tolerance,
+            max_refinements,
+        }
+    }
+
+    /// Relative tolerance at which the refinement stops
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    /// Maximum number of refinements
+    pub fn max_refinements(&self) -> usize {
+        self.max_refinements
+    }
+
+    /// Integrates `f` over the finite interval *\[a, b\]*
+    pub fn integrate<F: FnMut(f64) -> f64>(&self, mut f: F, a: f64, b: f64) -> Integral {
+        let (callback, context) = erase(&mut f);
+        let (mut error, mut l1_norm) = (f64::NAN, f64::NAN);
+        let value = unsafe {
+            ffi::math_trapezoidal(
+                callback,
+                context,
+                a,
+                b,
+                self.tolerance,
+                self.max_refinements,
+                &mut error,
+                &mut l1_norm,
+            )
+        };
+        Integral {
+            value,
+            error,
+            l1_norm,
+            levels: None,
+        }
+    }
+}
+
+/// Relative tolerance *√ε* and at most 12 refinements
+impl Default for Trapezoidal {
+    fn default() -> Self {
+        Self::new(ROOT_EPSILON, 12)
+    }
+}
+
+/// Adaptive trapezoidal quadrature of `f` over *\[a, b\]* with the [`Default`] [`Trapezoidal`]
+/// integrator
+///
+/// Corresponds to `boost::math::quadrature::trapezoidal(f, a, b)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/trapezoidal.html>
+pub fn trapezoidal<F: FnMut(f64) -> f64>(f: F, a: f64, b: f64) -> Integral {
+    Trapezoidal::default().integrate(f, a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::consts::PI;
+
+    #[test]
+    fn test_trapezoidal() {
+        let result = trapezoidal(|x| (x.sin()).exp(), 0.0, 2.0 * PI);
+        // 2π I₀(1)
+        assert_relative_eq!(result.value, 7.954_926_521_012_844, max_relative = 1e-12);
+        assert!(result.error < 1e-8);
+
+        let result = trapezoidal(|x| x * x, 0.0, 1.0);
+        assert_relative_eq!(result.value, 1.0 / 3.0, max_relative = 1e-6);
+        assert_relative_eq!(result.l1_norm, 1.0 / 3.0, max_relative = 1e-6);
+    }
+
+    #[test]
+    fn test_trapezoidal_max_refinements() {
+        let mut calls = 0;
+        let coarse = Trapezoidal::new(1e-15, 4).integrate(
+            |x| {
+                calls += 1;
+                x.sqrt()
+            },
+            0.0,
+            1.0,
+        );
+        let fine = Trapezoidal::new(1e-15, 12).integrate(|x| x.sqrt(), 0.0, 1.0);
+        // at most the 2⁴ + 1 points of the finest grid, with step (b - a) / 2⁴
+        assert!(calls <= (1 << 4) + 1);
+        assert!(coarse.error > fine.error);
+    }
+
+    #[test]
+    fn test_trapezoidal_invalid() {
+        assert!(trapezoidal(|x| x, 0.0, f64::INFINITY).value.is_nan());
+        assert!(trapezoidal(|x| x, f64::NAN, 1.0).value.is_nan());
+    }
+}
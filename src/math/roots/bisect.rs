@@ -0,0 +1,99 @@
+//! boost/math/tools/roots.hpp
+
+use super::Bracket;
+use crate::callback::erase;
+use crate::ffi;
+
+/// Bisection of a root of `f` in the bracket *\[min, max\]*
+///
+/// `f(min)` and `f(max)` must have opposite signs, or be zero; otherwise the bracket is NaN.
+/// Bisection halves the bracket in each iteration, until it is accurate to `bits` binary digits,
+/// or after `max_iter` iterations.
+///
+/// Corresponds to `boost::math::tools::bisect(f, min, max, eps_tolerance<double>(bits), max_iter)`
+/// in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/roots_noderiv/bisect.html>
+///
+/// # Examples
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use boost::math::roots::bisect;
+///
+/// let root = bisect(|x| x * x - 2.0, 0.0, 2.0, 40, 100);
+/// assert_relative_eq!(root.midpoint(), core::f64::consts::SQRT_2, max_relative = 1e-12);
+/// ```
+pub fn bisect<F: FnMut(f64) -> f64>(
+    mut f: F,
+    min: f64,
+    max: f64,
+    bits: u32,
+    max_iter: usize,
+) -> Bracket {
+    // Boost reports these as evaluation errors, which yield a bogus bracket when ignored
+    let (f_min, f_max) = (f(min), f(max));
+    if !(min < max && f_min * f_max <= 0.0) {
+        return Bracket::NAN;
+    }
+
+    // Boost evaluates the endpoints first, so reuse them
+    let mut f = |x| {
+        if x == min {
+            f_min
+        } else if x == max {
+            f_max
+        } else {
+            f(x)
+        }
+    };
+    let (callback, context) = erase(&mut f);
+    Bracket::from_ffi(max_iter, |iterations, upper| unsafe {
+        ffi::math_bisect(callback, context, min, max, bits, iterations, upper)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::consts::SQRT_2;
+
+    #[test]
+    fn test_bisect() {
+        let root = bisect(|x| x * x - 2.0, 0.0, 2.0, f64::MANTISSA_DIGITS, 100);
+        assert!(root.lower <= SQRT_2 && SQRT_2 <= root.upper);
+        assert_relative_eq!(root.lower, root.upper, max_relative = 4.0 * f64::EPSILON);
+        assert!(0 < root.iterations && root.iterations <= 100);
+
+        let root = bisect(|x| x.cos(), 0.0, 3.0, 20, 100);
+        assert_relative_eq!(
+            root.midpoint(),
+            core::f64::consts::FRAC_PI_2,
+            max_relative = 1e-5
+        );
+    }
+
+    #[test]
+    fn test_bisect_max_iter() {
+        let mut calls = 0;
+        let root = bisect(
+            |x| {
+                calls += 1;
+                x - 0.1
+            },
+            0.0,
+            1.0,
+            f64::MANTISSA_DIGITS,
+            5,
+        );
+        assert!(root.iterations <= 5);
+        assert!(root.upper - root.lower > 1e-3);
+        assert!(calls <= 2 + 5);
+    }
+
+    #[test]
+    fn test_bisect_invalid() {
+        assert!(bisect(|x| x * x + 1.0, -1.0, 1.0, 40, 100).lower.is_nan());
+        assert!(bisect(|x| x, 1.0, -1.0, 40, 100).lower.is_nan());
+        assert!(bisect(|_| f64::NAN, -1.0, 1.0, 40, 100).lower.is_nan());
+    }
+}
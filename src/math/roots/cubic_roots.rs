@@ -0,0 +1,107 @@
+//! boost/math/tools/cubic_roots.hpp
+
+use crate::ffi;
+
+/// Real roots of the cubic polynomial *ax<sup>3</sup> + bx<sup>2</sup> + cx + d*
+///
+/// The real roots are sorted in ascending order and polished with a Newton step, followed by NaN
+/// for each non-real root. Repeated roots are repeated.
+///
+/// Corresponds to `boost::math::tools::cubic_roots(a, b, c, d)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/cubic_roots.html>
+///
+/// # Examples
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use boost::math::roots::cubic_roots;
+///
+/// // (x - 1)(x - 2)(x - 3)
+/// let roots = cubic_roots(1.0, -6.0, 11.0, -6.0);
+/// assert_relative_eq!(roots.as_slice(), [1.0, 2.0, 3.0].as_slice(), max_relative = 1e-15);
+///
+/// // (x - 1)(x² + x + 1)
+/// let [x, y, z] = cubic_roots(1.0, 0.0, 0.0, -1.0);
+/// assert_relative_eq!(x, 1.0, max_relative = 1e-15);
+/// assert!(y.is_nan() && z.is_nan());
+/// ```
+pub fn cubic_roots(a: f64, b: f64, c: f64, d: f64) -> [f64; 3] {
+    let mut roots = [f64::NAN; 3];
+    unsafe { ffi::math_cubic_roots(a, b, c, d, roots.as_mut_ptr()) };
+    roots
+}
+
+/// Residual of a `root` of the cubic polynomial *ax<sup>3</sup> + bx<sup>2</sup> + cx + d*, and
+/// its expected magnitude due to rounding errors
+///
+/// A residual of larger magnitude than expected indicates an inaccurate root.
+///
+/// Corresponds to `boost::math::tools::cubic_root_residual(a, b, c, d, root)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/cubic_roots.html>
+pub fn cubic_root_residual(a: f64, b: f64, c: f64, d: f64, root: f64) -> (f64, f64) {
+    let mut bound = f64::NAN;
+    let residual = unsafe { ffi::math_cubic_root_residual(a, b, c, d, root, &mut bound) };
+    (residual, bound)
+}
+
+/// Condition number of a `root` of the cubic polynomial *ax<sup>3</sup> + bx<sup>2</sup> + cx + d*
+///
+/// This bounds the relative change of the root per relative change of the coefficients. It is
+/// infinite for repeated roots, which are ill-conditioned.
+///
+/// Corresponds to `boost::math::tools::cubic_root_condition_number(a, b, c, d, root)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/cubic_roots.html>
+pub fn cubic_root_condition_number(a: f64, b: f64, c: f64, d: f64, root: f64) -> f64 {
+    unsafe { ffi::math_cubic_root_condition_number(a, b, c, d, root) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RTOL: f64 = 4.0 * f64::EPSILON;
+
+    #[test]
+    fn test_cubic_roots() {
+        let [x, y, z] = cubic_roots(2.0, -12.0, 22.0, -12.0);
+        assert_relative_eq!(x, 1.0, max_relative = RTOL);
+        assert_relative_eq!(y, 2.0, max_relative = RTOL);
+        assert_relative_eq!(z, 3.0, max_relative = RTOL);
+
+        // (x + 1)(x² + 1)
+        let [x, y, z] = cubic_roots(1.0, 1.0, 1.0, 1.0);
+        assert_relative_eq!(x, -1.0, max_relative = RTOL);
+        assert!(y.is_nan() && z.is_nan());
+
+        // x² - 1, i.e. a quadratic
+        let [x, y, z] = cubic_roots(0.0, 1.0, 0.0, -1.0);
+        assert_eq!([x, y], [-1.0, 1.0]);
+        assert!(z.is_nan());
+    }
+
+    #[test]
+    fn test_cubic_root_residual() {
+        let (a, b, c, d) = (1.0, -6.0, 11.0, -6.0);
+        for root in cubic_roots(a, b, c, d) {
+            let (residual, bound) = cubic_root_residual(a, b, c, d, root);
+            assert!(residual.abs() <= bound);
+        }
+        let (residual, bound) = cubic_root_residual(a, b, c, d, 2.5);
+        assert!(residual.abs() > bound);
+    }
+
+    #[test]
+    fn test_cubic_root_condition_number() {
+        let (a, b, c, d) = (1.0, -6.0, 11.0, -6.0);
+        for root in cubic_roots(a, b, c, d) {
+            let kappa = cubic_root_condition_number(a, b, c, d, root);
+            assert!(kappa.is_finite() && kappa >= 1.0);
+        }
+
+        // (x - 1)²(x + 1)
+        assert_eq!(
+            cubic_root_condition_number(1.0, -1.0, -1.0, 1.0, 1.0),
+            f64::INFINITY
+        );
+    }
+}
@@ -0,0 +1,84 @@
+//! boost/math/tools/roots.hpp
+
+use super::{Root, digits, erase_2};
+use crate::ffi;
+
+/// Halley iteration for a root of `f` in *\[min, max\]*, starting from `guess`
+///
+/// The closure returns *(f(x), f'(x), f''(x))*. The iteration converges cubically near a simple
+/// root, until the estimate is accurate to about `digits` binary digits, or after `max_iter`
+/// iterations. The `guess` must lie within the bounds; otherwise the root is NaN.
+///
+/// Corresponds to `boost::math::tools::halley_iterate(f, guess, min, max, digits, max_iter)` in
+/// C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/roots_deriv.html>
+///
+/// # Examples
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use boost::math::roots::halley_iterate;
+///
+/// // cube root of 10
+/// let root = halley_iterate(|x| (x * x * x - 10.0, 3.0 * x * x, 6.0 * x), 2.0, 1.0, 10.0, 52, 20);
+/// assert_relative_eq!(root.value, 10.0f64.cbrt(), max_relative = 1e-15);
+/// ```
+pub fn halley_iterate<F: FnMut(f64) -> (f64, f64, f64)>(
+    mut f: F,
+    guess: f64,
+    min: f64,
+    max: f64,
+    digits: u32,
+    max_iter: usize,
+) -> Root {
+    if !(min <= guess && guess <= max) {
+        return Root::NAN;
+    }
+    let (callback, context) = erase_2(&mut f);
+    let digits = self::digits(digits);
+    Root::from_ffi(max_iter, |iterations| unsafe {
+        ffi::math_halley_iterate(callback, context, guess, min, max, digits, iterations)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::{cyl_bessel_j, cyl_bessel_j_prime};
+
+    #[test]
+    fn test_halley_iterate() {
+        let f = |x| (x * x * x - 2.0, 3.0 * x * x, 6.0 * x);
+        let root = halley_iterate(f, 1.0, 0.0, 2.0, 52, 20);
+        assert_relative_eq!(root.value, 2.0f64.cbrt(), max_relative = 1e-15);
+        assert!(0 < root.iterations && root.iterations < 20);
+
+        // J₀'' = -J₀' / x - J₀ from Bessel's equation
+        let f = |x| {
+            let (j, dj) = (cyl_bessel_j(0.0, x), cyl_bessel_j_prime(0.0, x));
+            (j, dj, -dj / x - j)
+        };
+        let root = halley_iterate(f, 5.0, 4.0, 6.0, 50, 20);
+        assert_relative_eq!(root.value, 5.520_078_110_286_311, max_relative = 1e-13);
+    }
+
+    #[test]
+    fn test_halley_iterate_invalid() {
+        let f = |x| (x, 1.0, 0.0);
+        assert!(halley_iterate(f, 2.0, -1.0, 1.0, 52, 20).value.is_nan());
+        assert!(halley_iterate(f, 0.0, 1.0, -1.0, 52, 20).value.is_nan());
+    }
+
+    #[test]
+    #[should_panic(expected = "function panicked")]
+    fn test_halley_iterate_panic() {
+        halley_iterate(
+            |_| -> (f64, f64, f64) { panic!("function panicked") },
+            0.5,
+            0.0,
+            1.0,
+            52,
+            20,
+        );
+    }
+}
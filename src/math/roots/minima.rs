@@ -0,0 +1,124 @@
+//! boost/math/tools/minima.hpp
+
+use super::digits;
+use crate::callback::erase;
+use crate::ffi;
+
+/// Minimum of a function, found by [`brent_find_minima`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Minimum {
+    /// Location of the minimum, i.e. the argmin
+    pub x: f64,
+    /// Value of the function at `x`
+    pub value: f64,
+    /// Number of iterations that were used, at most the maximum number of iterations
+    pub iterations: usize,
+}
+
+/// Brent's method for a minimum of `f` in the bracket *\[min, max\]*
+///
+/// Brent's method combines golden-section search with parabolic interpolation, and requires no
+/// derivatives. It stops once the location of the minimum is accurate to `bits` binary digits,
+/// or after `max_iter` iterations. Since a function is flat near its minimum, `bits` is limited
+/// to half of [`f64::MANTISSA_DIGITS`]. If `min` is not below `max`, the minimum is NaN.
+///
+/// To find a maximum, minimize the negated function instead.
+///
+/// Corresponds to `boost::math::tools::brent_find_minima(f, min, max, bits, max_iter)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/brent_minima.html>
+///
+/// # Examples
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use boost::math::{gamma_p_derivative, roots::brent_find_minima};
+///
+/// // mode of the gamma distribution with shape 3, i.e. 3 - 1
+/// let minimum = brent_find_minima(|x| -gamma_p_derivative(3.0, x), 0.0, 10.0, 26, 100);
+/// assert_relative_eq!(minimum.x, 2.0, max_relative = 1e-7);
+/// ```
+#[doc(alias = "brent")]
+#[doc(alias = "minimize")]
+pub fn brent_find_minima<F: FnMut(f64) -> f64>(
+    mut f: F,
+    min: f64,
+    max: f64,
+    bits: u32,
+    max_iter: usize,
+) -> Minimum {
+    if min.is_nan() || max.is_nan() || min >= max {
+        return Minimum {
+            x: f64::NAN,
+            value: f64::NAN,
+            iterations: 0,
+        };
+    }
+    let (callback, context) = erase(&mut f);
+    let (bits, mut iterations, mut value) = (digits(bits), max_iter, f64::NAN);
+    let x = unsafe {
+        ffi::math_brent_find_minima(
+            callback,
+            context,
+            min,
+            max,
+            bits,
+            &mut iterations,
+            &mut value,
+        )
+    };
+    Minimum {
+        x,
+        value,
+        iterations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::hermite_h;
+
+    #[test]
+    fn test_brent_find_minima() {
+        let minimum = brent_find_minima(|x| (x - 2.0) * (x - 2.0) + 1.0, 0.0, 5.0, 26, 100);
+        assert_relative_eq!(minimum.x, 2.0, max_relative = 1e-7);
+        assert_relative_eq!(minimum.value, 1.0, max_relative = 1e-14);
+        assert!(0 < minimum.iterations && minimum.iterations < 100);
+
+        // H₃(x) = 8x³ - 12x has a local minimum at x = 1/√2
+        let minimum = brent_find_minima(|x| hermite_h(3, x), 0.0, 2.0, 26, 100);
+        assert_relative_eq!(
+            minimum.x,
+            core::f64::consts::FRAC_1_SQRT_2,
+            max_relative = 1e-7
+        );
+        assert_relative_eq!(
+            minimum.value,
+            -8.0 * core::f64::consts::FRAC_1_SQRT_2,
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_brent_find_minima_max_iter() {
+        let mut calls = 0;
+        let f = |x: f64| {
+            calls += 1;
+            x.cos()
+        };
+        let minimum = brent_find_minima(f, 0.0, 6.0, 26, 3);
+        assert!(minimum.iterations <= 3);
+        // the initial evaluation, and one per iteration
+        assert!(calls <= 3 + 1);
+    }
+
+    #[test]
+    fn test_brent_find_minima_invalid() {
+        assert!(brent_find_minima(|x| x * x, 1.0, -1.0, 26, 100).x.is_nan());
+        assert!(
+            brent_find_minima(|x| x * x, f64::NAN, 1.0, 26, 100)
+                .x
+                .is_nan()
+        );
+    }
+}
@@ -0,0 +1,162 @@
+//! Root finding and minimization
+//!
+//! Corresponds to the Boost Math `boost/math/tools/roots.hpp` and related C++ headers.
+//! <https://boost.org/doc/libs/latest/libs/math/doc/html/root_finding.html>
+//!
+//! ## Bracketing Root Finders
+//!
+//! - [`bisect`] for robust, but slow, bisection of a bracket
+//! - [`toms748_solve`] for the faster TOMS 748 algorithm, given a bracket
+//! - [`bracket_and_solve_root`] for TOMS 748, after first bracketing the root from a guess
+//!
+//! These stop once the bracket is at most *2<sup>1-bits</sup>* times its endpoints wide, i.e.
+//! accurate to `bits` binary digits, like `boost::math::tools::eps_tolerance<double>(bits)`. Pass
+//! [`f64::MANTISSA_DIGITS`] for full precision, which is limited to *4ε* relative.
+//!
+//! ## Root Finders With Derivatives
+//!
+//! - [`newton_raphson_iterate`], which converges quadratically given the first derivative
+//! - [`halley_iterate`] and [`schroder_iterate`], which converge cubically given the first two
+//!   derivatives
+//!
+//! Starting from a guess, these iterate until the last step is smaller than *2<sup>1-digits</sup>*
+//! times the estimate, i.e. accurate to about `digits` binary digits. Steps that leave the bounds
+//! *\[min, max\]* are replaced by bisection steps.
+//!
+//! ## Polynomial Roots
+//!
+//! - [`cubic_roots`], with the diagnostics [`cubic_root_residual`] and
+//!   [`cubic_root_condition_number`]
+//! - [`quartic_roots`]
+//!
+//! These return the real roots in closed form, followed by NaN for the non-real ones.
+//!
+//! ## Minimization
+//!
+//! - [`brent_find_minima`] for a minimum of a function in a bracket, without derivatives
+//!
+//! The functions of the iterative root finders are Rust closures, which are called back from the
+//! C++ root finders. If the function panics, the panic unwinds through the root finder and
+//! propagates to the caller. The same holds for minimization.
+
+mod bisect;
+mod cubic_roots;
+mod halley;
+mod minima;
+mod newton_raphson;
+mod quartic_roots;
+mod schroder;
+mod toms748_solve;
+
+pub use bisect::*;
+pub use cubic_roots::*;
+pub use halley::*;
+pub use minima::*;
+pub use newton_raphson::*;
+pub use quartic_roots::*;
+pub use schroder::*;
+pub use toms748_solve::*;
+
+use crate::ffi;
+use core::ffi::{c_int, c_void};
+
+/// Bracketing interval *\[lower, upper\]* of a root
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bracket {
+    /// Lower endpoint of the bracket
+    pub lower: f64,
+    /// Upper endpoint of the bracket
+    pub upper: f64,
+    /// Number of iterations that were used, at most the maximum number of iterations
+    pub iterations: usize,
+}
+
+impl Bracket {
+    const NAN: Self = Self {
+        lower: f64::NAN,
+        upper: f64::NAN,
+        iterations: 0,
+    };
+
+    /// Calls a wrapper function that takes the maximum number of iterations, overwrites it with
+    /// the number of iterations used, returns the lower endpoint and writes the upper one.
+    fn from_ffi(max_iter: usize, f: impl FnOnce(*mut usize, *mut f64) -> f64) -> Self {
+        let (mut iterations, mut upper) = (max_iter, f64::NAN);
+        let lower = f(&mut iterations, &mut upper);
+        Self {
+            lower,
+            upper,
+            iterations,
+        }
+    }
+
+    /// Midpoint of the bracket, i.e. the best estimate of the root
+    pub fn midpoint(&self) -> f64 {
+        self.lower + (self.upper - self.lower) / 2.0
+    }
+}
+
+/// Root found by an iterative root finder
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Root {
+    /// Estimate of the root
+    pub value: f64,
+    /// Number of iterations that were used, at most the maximum number of iterations
+    pub iterations: usize,
+}
+
+impl Root {
+    const NAN: Self = Self {
+        value: f64::NAN,
+        iterations: 0,
+    };
+
+    /// Calls a wrapper function that takes the maximum number of iterations, overwrites it with
+    /// the number of iterations used, and returns the root.
+    fn from_ffi(max_iter: usize, f: impl FnOnce(*mut usize) -> f64) -> Self {
+        let mut iterations = max_iter;
+        let value = f(&mut iterations);
+        Self { value, iterations }
+    }
+}
+
+/// Clamps the number of binary digits to the `int` of the C++ root finders.
+fn digits(digits: u32) -> c_int {
+    c_int::try_from(digits).unwrap_or(c_int::MAX)
+}
+
+/// Calls the closure that `f` points to, and writes the function value and its derivative to
+/// `out`; passed to the C++ wrapper as [`ffi::Derivatives`].
+unsafe extern "C-unwind" fn call_1<F: FnMut(f64) -> (f64, f64)>(
+    f: *mut c_void,
+    x: f64,
+    out: *mut f64,
+) {
+    let f = unsafe { &mut *f.cast::<F>() };
+    let (f0, f1) = f(x);
+    unsafe { out.cast::<[f64; 2]>().write([f0, f1]) };
+}
+
+/// Calls the closure that `f` points to, and writes the function value and its first two
+/// derivatives to `out`; passed to the C++ wrapper as [`ffi::Derivatives`].
+unsafe extern "C-unwind" fn call_2<F: FnMut(f64) -> (f64, f64, f64)>(
+    f: *mut c_void,
+    x: f64,
+    out: *mut f64,
+) {
+    let f = unsafe { &mut *f.cast::<F>() };
+    let (f0, f1, f2) = f(x);
+    unsafe { out.cast::<[f64; 3]>().write([f0, f1, f2]) };
+}
+
+/// Type-erases a closure that returns the first derivative into a callback and a context pointer
+/// for the C++ wrapper.
+fn erase_1<F: FnMut(f64) -> (f64, f64)>(f: &mut F) -> (ffi::Derivatives, *mut c_void) {
+    (call_1::<F>, (f as *mut F).cast())
+}
+
+/// Type-erases a closure that returns the first two derivatives into a callback and a context
+/// pointer for the C++ wrapper.
+fn erase_2<F: FnMut(f64) -> (f64, f64, f64)>(f: &mut F) -> (ffi::Derivatives, *mut c_void) {
+    (call_2::<F>, (f as *mut F).cast())
+}
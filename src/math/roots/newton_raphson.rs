@@ -0,0 +1,96 @@
+//! boost/math/tools/roots.hpp
+
+use super::{Root, digits, erase_1};
+use crate::ffi;
+
+/// Newton-Raphson iteration for a root of `f` in *\[min, max\]*, starting from `guess`
+///
+/// The closure returns *(f(x), f'(x))*. The iteration converges quadratically near a simple root,
+/// until the estimate is accurate to about `digits` binary digits, or after `max_iter`
+/// iterations. The `guess` must lie within the bounds; otherwise the root is NaN.
+///
+/// Corresponds to `boost::math::tools::newton_raphson_iterate(f, guess, min, max, digits,
+/// max_iter)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/roots_deriv.html>
+///
+/// # Examples
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use boost::math::{cyl_bessel_j, cyl_bessel_j_prime, roots::newton_raphson_iterate};
+///
+/// // first zero of the Bessel function J₀
+/// let f = |x| (cyl_bessel_j(0.0, x), cyl_bessel_j_prime(0.0, x));
+/// let root = newton_raphson_iterate(f, 2.0, 1.0, 3.0, 50, 20);
+/// assert_relative_eq!(root.value, 2.404_825_557_695_773, max_relative = 1e-14);
+/// ```
+#[doc(alias = "newton")]
+pub fn newton_raphson_iterate<F: FnMut(f64) -> (f64, f64)>(
+    mut f: F,
+    guess: f64,
+    min: f64,
+    max: f64,
+    digits: u32,
+    max_iter: usize,
+) -> Root {
+    if !(min <= guess && guess <= max) {
+        return Root::NAN;
+    }
+    let (callback, context) = erase_1(&mut f);
+    let digits = self::digits(digits);
+    Root::from_ffi(max_iter, |iterations| unsafe {
+        ffi::math_newton_raphson_iterate(callback, context, guess, min, max, digits, iterations)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::{lambert_w0, lambert_w0_prime};
+
+    #[test]
+    fn test_newton_raphson_iterate() {
+        let root = newton_raphson_iterate(|x| (x * x - 2.0, 2.0 * x), 1.0, 0.0, 2.0, 52, 20);
+        assert_relative_eq!(root.value, core::f64::consts::SQRT_2, max_relative = 1e-15);
+        assert!(0 < root.iterations && root.iterations < 20);
+
+        // invert the Lambert W function, i.e. W(x) = 1 at x = e
+        let f = |x| (lambert_w0(x) - 1.0, lambert_w0_prime(x));
+        let root = newton_raphson_iterate(f, 1.0, 0.0, 10.0, 50, 50);
+        assert_relative_eq!(root.value, core::f64::consts::E, max_relative = 1e-13);
+    }
+
+    #[test]
+    fn test_newton_raphson_iterate_max_iter() {
+        let mut calls = 0;
+        let f = |x: f64| {
+            calls += 1;
+            (x.exp() - 1e6, x.exp())
+        };
+        let root = newton_raphson_iterate(f, 0.0, 0.0, 100.0, 52, 3);
+        assert!(root.iterations <= 3);
+        // one evaluation per iteration, allowing for one more when a step leaves the bounds
+        assert!(calls <= 3 + 1);
+        assert!((root.value - 1e6f64.ln()).abs() > 1e-6);
+    }
+
+    #[test]
+    fn test_newton_raphson_iterate_invalid() {
+        let f = |x| (x, 1.0);
+        assert!(
+            newton_raphson_iterate(f, 2.0, -1.0, 1.0, 52, 20)
+                .value
+                .is_nan()
+        );
+        assert!(
+            newton_raphson_iterate(f, 0.0, 1.0, -1.0, 52, 20)
+                .value
+                .is_nan()
+        );
+        assert!(
+            newton_raphson_iterate(f, f64::NAN, -1.0, 1.0, 52, 20)
+                .value
+                .is_nan()
+        );
+    }
+}
@@ -0,0 +1,54 @@
+//! boost/math/tools/quartic_roots.hpp
+
+use crate::ffi;
+
+/// Real roots of the quartic polynomial *ax<sup>4</sup> + bx<sup>3</sup> + cx<sup>2</sup> + dx +
+/// e*
+///
+/// The real roots are sorted in ascending order and polished with a Newton step, followed by NaN
+/// for each non-real root. Repeated roots are repeated.
+///
+/// Corresponds to `boost::math::tools::quartic_roots(a, b, c, d, e)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/quartic_roots.html>
+///
+/// # Examples
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use boost::math::roots::quartic_roots;
+///
+/// // (x² - 1)(x² - 4)
+/// let roots = quartic_roots(1.0, 0.0, -5.0, 0.0, 4.0);
+/// assert_relative_eq!(roots.as_slice(), [-2.0, -1.0, 1.0, 2.0].as_slice(), max_relative = 1e-12);
+/// ```
+pub fn quartic_roots(a: f64, b: f64, c: f64, d: f64, e: f64) -> [f64; 4] {
+    let mut roots = [f64::NAN; 4];
+    unsafe { ffi::math_quartic_roots(a, b, c, d, e, roots.as_mut_ptr()) };
+    roots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quartic_roots() {
+        // (x - 1)(x - 2)(x - 3)(x - 4)
+        let roots = quartic_roots(1.0, -10.0, 35.0, -50.0, 24.0);
+        for (root, expected) in roots.into_iter().zip([1.0, 2.0, 3.0, 4.0]) {
+            assert_relative_eq!(root, expected, max_relative = 1e-12);
+        }
+
+        // (x² - 2)(x² + 1)
+        let [x, y, z, w] = quartic_roots(1.0, 0.0, -1.0, 0.0, -2.0);
+        assert_relative_eq!(x, -core::f64::consts::SQRT_2, max_relative = 1e-15);
+        assert_relative_eq!(y, core::f64::consts::SQRT_2, max_relative = 1e-15);
+        assert!(z.is_nan() && w.is_nan());
+
+        assert!(
+            quartic_roots(1.0, 0.0, 0.0, 0.0, 1.0)
+                .iter()
+                .all(|x| x.is_nan())
+        );
+    }
+}
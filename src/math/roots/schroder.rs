@@ -0,0 +1,73 @@
+//! boost/math/tools/roots.hpp
+
+use super::{Root, digits, erase_2};
+use crate::ffi;
+
+/// Schröder iteration for a root of `f` in *\[min, max\]*, starting from `guess`
+///
+/// The closure returns *(f(x), f'(x), f''(x))*. Like [`halley_iterate`](super::halley_iterate),
+/// the iteration converges cubically near a simple root, until the estimate is accurate to about
+/// `digits` binary digits, or after `max_iter` iterations. The `guess` must lie within the
+/// bounds; otherwise the root is NaN.
+///
+/// Corresponds to `boost::math::tools::schroder_iterate(f, guess, min, max, digits, max_iter)` in
+/// C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/roots_deriv.html>
+///
+/// # Examples
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use boost::math::roots::schroder_iterate;
+///
+/// let f = |x: f64| (x.ln() - 1.0, 1.0 / x, -1.0 / (x * x));
+/// let root = schroder_iterate(f, 2.0, 1.0, 10.0, 52, 20);
+/// assert_relative_eq!(root.value, core::f64::consts::E, max_relative = 1e-15);
+/// ```
+#[doc(alias = "schroeder_iterate")]
+pub fn schroder_iterate<F: FnMut(f64) -> (f64, f64, f64)>(
+    mut f: F,
+    guess: f64,
+    min: f64,
+    max: f64,
+    digits: u32,
+    max_iter: usize,
+) -> Root {
+    if !(min <= guess && guess <= max) {
+        return Root::NAN;
+    }
+    let (callback, context) = erase_2(&mut f);
+    let digits = self::digits(digits);
+    Root::from_ffi(max_iter, |iterations| unsafe {
+        ffi::math_schroder_iterate(callback, context, guess, min, max, digits, iterations)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schroder_iterate() {
+        let f = |x| (x * x * x - 2.0, 3.0 * x * x, 6.0 * x);
+        let root = schroder_iterate(f, 1.0, 0.0, 2.0, 52, 20);
+        assert_relative_eq!(root.value, 2.0f64.cbrt(), max_relative = 1e-15);
+        assert!(0 < root.iterations && root.iterations < 20);
+
+        // double root at x = 1
+        let f = |x: f64| ((x - 1.0) * (x - 1.0), 2.0 * (x - 1.0), 2.0);
+        let root = schroder_iterate(f, 3.0, 0.0, 5.0, 40, 100);
+        assert_abs_diff_eq!(root.value, 1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_schroder_iterate_invalid() {
+        let f = |x| (x, 1.0, 0.0);
+        assert!(schroder_iterate(f, 2.0, -1.0, 1.0, 52, 20).value.is_nan());
+        assert!(
+            schroder_iterate(f, f64::NAN, -1.0, 1.0, 52, 20)
+                .value
+                .is_nan()
+        );
+    }
+}
@@ -0,0 +1,158 @@
+//! boost/math/tools/toms748_solve.hpp
+
+use super::Bracket;
+use crate::callback::erase;
+use crate::ffi;
+
+/// TOMS 748 root finding of `f` in the bracket *\[a, b\]*
+///
+/// `f(a)` and `f(b)` must have opposite signs, or be zero; otherwise the bracket is NaN. The
+/// algorithm of Alefeld, Potra and Shi combines cubic inverse interpolation with bisection, and
+/// usually needs far fewer iterations than [`bisect`](super::bisect). It stops once the bracket
+/// is accurate to `bits` binary digits, or after `max_iter` iterations.
+///
+/// Corresponds to `boost::math::tools::toms748_solve(f, a, b, eps_tolerance<double>(bits),
+/// max_iter)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/roots_noderiv/TOMS748.html>
+///
+/// # Examples
+///
+/// Solving *P(a, 1) = 0.5* for the shape parameter *a* of the regularized lower incomplete gamma
+/// function:
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use boost::math::{gamma_p, gamma_p_inva, roots::toms748_solve};
+///
+/// let root = toms748_solve(|a| gamma_p(a, 1.0) - 0.5, 0.1, 10.0, 50, 100);
+/// assert_relative_eq!(root.midpoint(), gamma_p_inva(1.0, 0.5), max_relative = 1e-12);
+/// ```
+#[doc(alias = "toms748")]
+pub fn toms748_solve<F: FnMut(f64) -> f64>(
+    mut f: F,
+    a: f64,
+    b: f64,
+    bits: u32,
+    max_iter: usize,
+) -> Bracket {
+    let (callback, context) = erase(&mut f);
+    Bracket::from_ffi(max_iter, |iterations, upper| unsafe {
+        ffi::math_toms748_solve(callback, context, a, b, bits, iterations, upper)
+    })
+}
+
+/// TOMS 748 root finding of `f`, after bracketing the root starting from `guess`
+///
+/// The bracket is found by repeatedly multiplying or dividing `guess` by `factor`, which must be
+/// greater than 1; otherwise the bracket is NaN. If `rising`, `f` is assumed to be increasing,
+/// and decreasing otherwise. The root must have the same sign as `guess`. Both the bracketing
+/// and the [`toms748_solve`] iterations count towards `max_iter`, which must be positive. If no
+/// bracket is found, e.g. because `f` has no root, the bracket is NaN.
+///
+/// Corresponds to `boost::math::tools::bracket_and_solve_root(f, guess, factor, rising,
+/// eps_tolerance<double>(bits), max_iter)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/roots_noderiv/bracket_solve.html>
+///
+/// # Examples
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use boost::math::roots::bracket_and_solve_root;
+///
+/// let root = bracket_and_solve_root(|x| x * x * x - 27.0, 1.0, 2.0, true, 50, 100);
+/// assert_relative_eq!(root.midpoint(), 3.0, max_relative = 1e-12);
+/// ```
+pub fn bracket_and_solve_root<F: FnMut(f64) -> f64>(
+    mut f: F,
+    guess: f64,
+    factor: f64,
+    rising: bool,
+    bits: u32,
+    max_iter: usize,
+) -> Bracket {
+    // Boost counts the iterations down from `max_iter - 1`, which wraps around for 0
+    if factor.is_nan() || factor <= 1.0 || max_iter == 0 {
+        return Bracket::NAN;
+    }
+    let (callback, context) = erase(&mut f);
+    let root = Bracket::from_ffi(max_iter, |iterations, upper| unsafe {
+        ffi::math_bracket_and_solve_root(
+            callback, context, guess, factor, rising, bits, iterations, upper,
+        )
+    });
+    // Boost reports a failure to bracket the root as a degenerate bracket that is not a root
+    if root.lower == root.upper && f(root.lower) != 0.0 {
+        return Bracket::NAN;
+    }
+    root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::{gamma_p, gamma_p_inva};
+
+    const RTOL: f64 = 1e-12;
+
+    #[test]
+    fn test_toms748_solve() {
+        let root = toms748_solve(|x| x * x - 2.0, 0.0, 2.0, f64::MANTISSA_DIGITS, 100);
+        assert_relative_eq!(root.lower, root.upper, max_relative = 4.0 * f64::EPSILON);
+        assert_relative_eq!(
+            root.midpoint(),
+            core::f64::consts::SQRT_2,
+            max_relative = RTOL
+        );
+        assert!(0 < root.iterations && root.iterations < 20);
+
+        let (x, p) = (2.5, 0.25);
+        let root = toms748_solve(|a| gamma_p(a, x) - p, 0.1, 20.0, 50, 100);
+        assert_relative_eq!(root.midpoint(), gamma_p_inva(x, p), max_relative = RTOL);
+    }
+
+    #[test]
+    fn test_toms748_solve_invalid() {
+        assert!(
+            toms748_solve(|x| x * x + 1.0, -1.0, 1.0, 40, 100)
+                .lower
+                .is_nan()
+        );
+        assert!(toms748_solve(|x| x, 1.0, -1.0, 40, 100).lower.is_nan());
+    }
+
+    #[test]
+    fn test_bracket_and_solve_root() {
+        let root = bracket_and_solve_root(|x| x * x * x - 27.0, 1.0, 2.0, true, 50, 100);
+        assert_relative_eq!(root.midpoint(), 3.0, max_relative = RTOL);
+        assert!(root.iterations > 0);
+
+        let root = bracket_and_solve_root(|x| 1.0 / x - 0.1, 1.0, 2.0, false, 50, 100);
+        assert_relative_eq!(root.midpoint(), 10.0, max_relative = RTOL);
+
+        assert!(
+            bracket_and_solve_root(|x| x - 2.0, 1.0, 1.0, true, 50, 100)
+                .lower
+                .is_nan()
+        );
+    }
+
+    #[test]
+    fn test_bracket_and_solve_root_no_root() {
+        let root = bracket_and_solve_root(|x| x * x + 1.0, 1.0, 2.0, true, 50, 100);
+        assert!(root.lower.is_nan() && root.upper.is_nan());
+        let root = bracket_and_solve_root(|x| x * x + 1.0, 1.0, 2.0, true, 50, 0);
+        assert!(root.lower.is_nan() && root.upper.is_nan());
+    }
+
+    #[test]
+    #[should_panic(expected = "function panicked")]
+    fn test_toms748_solve_panic() {
+        toms748_solve(
+            |_| -> f64 { panic!("function panicked") },
+            0.0,
+            1.0,
+            50,
+            100,
+        );
+    }
+}
@@ -0,0 +1,70 @@
+//! boost/math/statistics/anderson_darling.hpp
+
+use crate::ffi;
+use alloc::vec::Vec;
+
+/// Anderson-Darling test statistic for the null hypothesis that `v` is drawn from a normal
+/// distribution
+///
+/// If the mean `mu` or the standard deviation `sd` are `None`, they are estimated from the
+/// samples. Larger values indicate a larger deviation from normality. Boost does not provide a
+/// p-value for this test.
+///
+/// Boost requires sorted samples; unsorted samples are sorted in a copy first.
+///
+/// Corresponds to `boost::math::statistics::anderson_darling_normality_statistic(v, mu, sd)` in
+/// C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/stats/anderson_darling.html>
+pub fn anderson_darling_normality_statistic(v: &[f64], mu: Option<f64>, sd: Option<f64>) -> f64 {
+    if v.is_empty() {
+        return f64::NAN;
+    }
+    let (mu, sd) = (mu.unwrap_or(f64::NAN), sd.unwrap_or(f64::NAN));
+    let statistic = |v: &[f64]| unsafe {
+        ffi::math_anderson_darling_normality_statistic(v.as_ptr(), v.len(), mu, sd)
+    };
+    if v.is_sorted() {
+        statistic(v)
+    } else {
+        let mut sorted: Vec<f64> = v.to_vec();
+        sorted.sort_unstable_by(f64::total_cmp);
+        statistic(&sorted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::distributions::{Distribution, Exponential, Normal};
+    use alloc::vec::Vec;
+
+    fn quantiles(d: &impl Distribution, n: usize) -> Vec<f64> {
+        (0..n)
+            .map(|i| d.quantile((i as f64 + 0.5) / n as f64))
+            .collect()
+    }
+
+    #[test]
+    fn test_anderson_darling_normality_statistic() {
+        let normal = quantiles(&Normal::default(), 100);
+        let exponential = quantiles(&Exponential::default(), 100);
+
+        let a_normal = anderson_darling_normality_statistic(&normal, None, None);
+        let a_exponential = anderson_darling_normality_statistic(&exponential, None, None);
+        assert!(a_normal >= 0.0);
+        assert!(a_normal < a_exponential);
+
+        let a_known = anderson_darling_normality_statistic(&normal, Some(0.0), Some(1.0));
+        let a_shifted = anderson_darling_normality_statistic(&normal, Some(1.0), Some(1.0));
+        assert!(a_known < a_shifted);
+
+        // the order of the samples does not matter
+        let reversed: Vec<f64> = normal.iter().rev().copied().collect();
+        assert_eq!(
+            anderson_darling_normality_statistic(&reversed, None, None),
+            a_normal
+        );
+
+        assert!(anderson_darling_normality_statistic(&[], None, None).is_nan());
+    }
+}
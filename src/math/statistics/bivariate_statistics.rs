@@ -0,0 +1,92 @@
+//! boost/math/statistics/bivariate_statistics.hpp
+
+use super::LengthMismatch;
+use crate::ffi;
+
+/// Population covariance *Σ(u<sub>i</sub> - ū)(v<sub>i</sub> - v̄) / n* of two samples
+///
+/// Corresponds to `boost::math::statistics::covariance(u, v)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/bivariate_statistics.html>
+pub fn covariance(u: &[f64], v: &[f64]) -> Result<f64, LengthMismatch> {
+    let n = LengthMismatch::check(u, v)?;
+    if n == 0 {
+        return Ok(f64::NAN);
+    }
+    Ok(unsafe { ffi::math_covariance(u.as_ptr(), v.as_ptr(), n) })
+}
+
+/// Means *ū* and *v̄*, and the [`covariance`] of two samples, computed in a single pass
+///
+/// Corresponds to `boost::math::statistics::means_and_covariance(u, v)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/bivariate_statistics.html>
+pub fn means_and_covariance(u: &[f64], v: &[f64]) -> Result<(f64, f64, f64), LengthMismatch> {
+    let n = LengthMismatch::check(u, v)?;
+    if n == 0 {
+        return Ok((f64::NAN, f64::NAN, f64::NAN));
+    }
+    let (mut mu_u, mut mu_v) = (f64::NAN, f64::NAN);
+    let cov =
+        unsafe { ffi::math_means_and_covariance(u.as_ptr(), v.as_ptr(), n, &mut mu_u, &mut mu_v) };
+    Ok((mu_u, mu_v, cov))
+}
+
+/// Pearson correlation coefficient *ρ ∈ \[-1, 1\]* of two samples
+///
+/// Corresponds to `boost::math::statistics::correlation_coefficient(u, v)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/bivariate_statistics.html>
+#[doc(alias = "pearson")]
+pub fn correlation_coefficient(u: &[f64], v: &[f64]) -> Result<f64, LengthMismatch> {
+    let n = LengthMismatch::check(u, v)?;
+    if n == 0 {
+        return Ok(f64::NAN);
+    }
+    Ok(unsafe { ffi::math_correlation_coefficient(u.as_ptr(), v.as_ptr(), n) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RTOL: f64 = 4.0 * f64::EPSILON;
+
+    #[test]
+    fn test_covariance() {
+        let u = [1.0, 2.0, 3.0, 4.0];
+        let v = [2.0, 4.0, 6.0, 9.0];
+        assert_relative_eq!(covariance(&u, &v).unwrap(), 2.875, max_relative = RTOL);
+
+        let (mu_u, mu_v, cov) = means_and_covariance(&u, &v).unwrap();
+        assert_eq!(mu_u, 2.5);
+        assert_eq!(mu_v, 5.25);
+        assert_relative_eq!(cov, 2.875, max_relative = RTOL);
+    }
+
+    #[test]
+    fn test_correlation_coefficient() {
+        let u = [1.0, 2.0, 3.0, 4.0];
+        assert_relative_eq!(
+            correlation_coefficient(&u, &[3.0, 5.0, 7.0, 9.0]).unwrap(),
+            1.0,
+            max_relative = RTOL
+        );
+        assert_relative_eq!(
+            correlation_coefficient(&u, &[8.0, 6.0, 4.0, 2.0]).unwrap(),
+            -1.0,
+            max_relative = RTOL
+        );
+    }
+
+    #[test]
+    fn test_length_mismatch() {
+        let err = LengthMismatch { left: 3, right: 2 };
+        assert_eq!(covariance(&[1.0, 2.0, 3.0], &[1.0, 2.0]), Err(err));
+        assert_eq!(
+            means_and_covariance(&[1.0, 2.0, 3.0], &[1.0, 2.0]),
+            Err(err)
+        );
+        assert_eq!(
+            correlation_coefficient(&[1.0, 2.0, 3.0], &[1.0, 2.0]),
+            Err(err)
+        );
+    }
+}
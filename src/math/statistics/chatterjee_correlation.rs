@@ -0,0 +1,77 @@
+//! boost/math/statistics/chatterjee_correlation.hpp
+
+use super::LengthMismatch;
+use crate::ffi;
+use alloc::vec::Vec;
+
+/// Chatterjee's rank correlation coefficient *ξ<sub>n</sub>(u, v)*
+///
+/// Unlike the Pearson [`correlation_coefficient`](super::correlation_coefficient), this measures
+/// whether *v* is a (possibly non-monotonic) function of *u*, and is therefore not symmetric. It
+/// approaches 0 if *u* and *v* are independent, and 1 if *v* is a measurable function of *u*.
+///
+/// Boost requires `u` to be sorted; otherwise the pairs are sorted by `u` in a copy first.
+///
+/// Corresponds to `boost::math::statistics::chatterjee_correlation(u, v)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/bivariate_statistics.html>
+#[doc(alias = "xi")]
+pub fn chatterjee_correlation(u: &[f64], v: &[f64]) -> Result<f64, LengthMismatch> {
+    let n = LengthMismatch::check(u, v)?;
+    if n == 0 {
+        return Ok(f64::NAN);
+    }
+    let xi = |u: &[f64], v: &[f64]| unsafe {
+        ffi::math_chatterjee_correlation(u.as_ptr(), v.as_ptr(), n)
+    };
+    if u.is_sorted() {
+        return Ok(xi(u, v));
+    }
+    let mut pairs: Vec<(f64, f64)> = u.iter().copied().zip(v.iter().copied()).collect();
+    pairs.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let (u, v): (Vec<f64>, Vec<f64>) = pairs.into_iter().unzip();
+    Ok(xi(&u, &v))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::statistics::correlation_coefficient;
+    use alloc::vec::Vec;
+
+    const RTOL: f64 = 4.0 * f64::EPSILON;
+
+    #[test]
+    fn test_chatterjee_correlation() {
+        // ξ = 1 - 3 Σ|r[i+1] - r[i]| / (n² - 1) = 1 - 9/15
+        let u = [1.0, 2.0, 3.0, 4.0];
+        assert_relative_eq!(
+            chatterjee_correlation(&u, &u).unwrap(),
+            0.4,
+            max_relative = RTOL
+        );
+
+        // the pairs are sorted by u
+        let u = [3.0, 1.0, 4.0, 2.0];
+        assert_relative_eq!(
+            chatterjee_correlation(&u, &u).unwrap(),
+            0.4,
+            max_relative = RTOL
+        );
+
+        // v = u² is not monotonic, but still a function of u
+        let u: Vec<f64> = (-50..=50).map(f64::from).collect();
+        let v: Vec<f64> = u.iter().map(|x| x * x).collect();
+        assert!(chatterjee_correlation(&u, &v).unwrap() > 0.8);
+        assert_abs_diff_eq!(
+            correlation_coefficient(&u, &v).unwrap(),
+            0.0,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_chatterjee_correlation_length_mismatch() {
+        let err = LengthMismatch { left: 2, right: 1 };
+        assert_eq!(chatterjee_correlation(&[1.0, 2.0], &[1.0]), Err(err));
+    }
+}
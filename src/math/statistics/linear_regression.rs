@@ -0,0 +1,249 @@
+//! boost/math/statistics/linear_regression.hpp
+
+use super::{LengthMismatch, mean, variance};
+use crate::ffi;
+use crate::math::{ccmath::sqrt, ibeta_inv};
+
+/// Intercept *c<sub>0</sub>* and slope *c<sub>1</sub>* of the least-squares line
+/// *y = c<sub>0</sub> + c<sub>1</sub>x*
+///
+/// Requires `x` to have a positive variance; see [`simple_linear_regression`] for standard errors
+/// and confidence intervals.
+///
+/// Corresponds to `boost::math::statistics::simple_ordinary_least_squares(x, y)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/linear_regression.html>
+#[doc(alias = "ols")]
+pub fn simple_ordinary_least_squares(x: &[f64], y: &[f64]) -> Result<(f64, f64), LengthMismatch> {
+    let n = LengthMismatch::check(x, y)?;
+    if !has_variance(x) {
+        return Ok((f64::NAN, f64::NAN));
+    }
+    let mut c1 = f64::NAN;
+    let c0 = unsafe { ffi::math_simple_ordinary_least_squares(x.as_ptr(), y.as_ptr(), n, &mut c1) };
+    Ok((c0, c1))
+}
+
+/// Intercept *c<sub>0</sub>*, slope *c<sub>1</sub>*, and coefficient of determination
+/// *R<sup>2</sup>* of the least-squares line *y = c<sub>0</sub> + c<sub>1</sub>x*
+///
+/// Requires `x` to have a positive variance.
+///
+/// Corresponds to `boost::math::statistics::simple_ordinary_least_squares_with_R_squared(x, y)`
+/// in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/linear_regression.html>
+pub fn simple_ordinary_least_squares_with_r_squared(
+    x: &[f64],
+    y: &[f64],
+) -> Result<(f64, f64, f64), LengthMismatch> {
+    let n = LengthMismatch::check(x, y)?;
+    if !has_variance(x) {
+        return Ok((f64::NAN, f64::NAN, f64::NAN));
+    }
+    let (mut c1, mut r_squared) = (f64::NAN, f64::NAN);
+    let c0 = unsafe {
+        ffi::math_simple_ordinary_least_squares_with_R_squared(
+            x.as_ptr(),
+            y.as_ptr(),
+            n,
+            &mut c1,
+            &mut r_squared,
+        )
+    };
+    Ok((c0, c1, r_squared))
+}
+
+/// Whether the [`variance`] of `x` is positive, without which Boost throws
+///
+/// Distinct values are not enough, since their variance can underflow to zero.
+fn has_variance(x: &[f64]) -> bool {
+    variance(x) > 0.0
+}
+
+/// Least-squares line *y = c<sub>0</sub> + c<sub>1</sub>x* with its goodness-of-fit
+///
+/// See [`simple_linear_regression`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SimpleLinearRegression {
+    /// Intercept *c<sub>0</sub>*
+    pub intercept: f64,
+    /// Slope *c<sub>1</sub>*
+    pub slope: f64,
+    /// Coefficient of determination *R<sup>2</sup>*
+    pub r_squared: f64,
+    /// Standard error of the intercept
+    pub intercept_standard_error: f64,
+    /// Standard error of the slope
+    pub slope_standard_error: f64,
+    /// Degrees of freedom of the residuals *n - 2*
+    pub degrees_of_freedom: f64,
+}
+
+impl SimpleLinearRegression {
+    /// Two-sided confidence interval of the intercept, e.g. with `confidence = 0.95`
+    pub fn intercept_confidence_interval(&self, confidence: f64) -> (f64, f64) {
+        let half_width = self.critical_value(confidence) * self.intercept_standard_error;
+        (self.intercept - half_width, self.intercept + half_width)
+    }
+
+    /// Two-sided confidence interval of the slope, e.g. with `confidence = 0.95`
+    pub fn slope_confidence_interval(&self, confidence: f64) -> (f64, f64) {
+        let half_width = self.critical_value(confidence) * self.slope_standard_error;
+        (self.slope - half_width, self.slope + half_width)
+    }
+
+    /// Critical value *t* of the Student's *t* distribution with *ν* degrees of freedom, for
+    /// which *P(|T| > t) = 1 - confidence*
+    fn critical_value(&self, confidence: f64) -> f64 {
+        // P(|T| > t) = I_x(ν/2, 1/2), with x = ν / (ν + t²)
+        let nu = self.degrees_of_freedom;
+        let x = ibeta_inv(0.5 * nu, 0.5, 1.0 - confidence);
+        sqrt(nu * (1.0 - x) / x)
+    }
+}
+
+/// Simple linear regression of `y` on `x`, including the standard errors of the coefficients
+///
+/// The coefficients and *R<sup>2</sup>* are computed with
+/// [`simple_ordinary_least_squares_with_r_squared`]. The standard errors assume independent,
+/// normally distributed residuals, and require at least three samples.
+///
+/// # Examples
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use boost::math::statistics::simple_linear_regression;
+///
+/// let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+/// let y = [2.1, 3.9, 6.2, 7.8, 10.1];
+/// let fit = simple_linear_regression(&x, &y).unwrap();
+/// assert_relative_eq!(fit.slope, 1.99, max_relative = 1e-12);
+///
+/// let (lower, upper) = fit.slope_confidence_interval(0.95);
+/// assert!(lower < 1.99 && 1.99 < upper);
+/// ```
+#[doc(alias = "linear_regression")]
+pub fn simple_linear_regression(
+    x: &[f64],
+    y: &[f64],
+) -> Result<SimpleLinearRegression, LengthMismatch> {
+    let (intercept, slope, r_squared) = simple_ordinary_least_squares_with_r_squared(x, y)?;
+    let n = x.len() as f64;
+    let degrees_of_freedom = n - 2.0;
+
+    let (intercept_standard_error, slope_standard_error) = if x.len() < 3 || !has_variance(x) {
+        (f64::NAN, f64::NAN)
+    } else {
+        let sse: f64 = x
+            .iter()
+            .zip(y)
+            .map(|(&xi, &yi)| {
+                let residual = yi - (intercept + slope * xi);
+                residual * residual
+            })
+            .sum();
+        let s2 = sse / degrees_of_freedom;
+        let (x_mean, sxx) = (mean(x), n * variance(x));
+        (sqrt(s2 * (1.0 / n + x_mean * x_mean / sxx)), sqrt(s2 / sxx))
+    };
+
+    Ok(SimpleLinearRegression {
+        intercept,
+        slope,
+        r_squared,
+        intercept_standard_error,
+        slope_standard_error,
+        degrees_of_freedom,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::distributions::{Distribution, StudentsT};
+
+    const RTOL: f64 = 1e-12;
+
+    const X: [f64; 5] = [1.0, 2.0, 3.0, 4.0, 5.0];
+    const Y: [f64; 5] = [2.1, 3.9, 6.2, 7.8, 10.1];
+
+    #[test]
+    fn test_simple_ordinary_least_squares() {
+        // y = 2x + 1
+        let y = X.map(|x| 2.0 * x + 1.0);
+        let (c0, c1) = simple_ordinary_least_squares(&X, &y).unwrap();
+        assert_relative_eq!(c0, 1.0, max_relative = RTOL);
+        assert_relative_eq!(c1, 2.0, max_relative = RTOL);
+
+        let (c0, c1, r2) = simple_ordinary_least_squares_with_r_squared(&X, &Y).unwrap();
+        assert_relative_eq!(c0, 0.05, max_relative = 1e-11);
+        assert_relative_eq!(c1, 1.99, max_relative = RTOL);
+        // SSE = 0.107, SST = 39.708
+        assert_relative_eq!(r2, 1.0 - 0.107 / 39.708, max_relative = RTOL);
+
+        // no variance in x
+        let (c0, c1) = simple_ordinary_least_squares(&[2.0; 3], &Y[..3]).unwrap();
+        assert!(c0.is_nan() && c1.is_nan());
+        let (_, _, r2) = simple_ordinary_least_squares_with_r_squared(&[2.0; 3], &Y[..3]).unwrap();
+        assert!(r2.is_nan());
+        // distinct, but the variance underflows
+        let (c0, c1) = simple_ordinary_least_squares(&[1e-200, 2e-200], &[1.0, 2.0]).unwrap();
+        assert!(c0.is_nan() && c1.is_nan());
+        let fit = simple_linear_regression(&[1e-200, 2e-200, 3e-200], &Y[..3]).unwrap();
+        assert!(fit.slope.is_nan() && fit.slope_standard_error.is_nan());
+        assert!(
+            simple_ordinary_least_squares(&[1.0], &[1.0])
+                .unwrap()
+                .0
+                .is_nan()
+        );
+
+        let err = LengthMismatch { left: 5, right: 4 };
+        assert_eq!(simple_ordinary_least_squares(&X, &Y[1..]), Err(err));
+    }
+
+    #[test]
+    fn test_simple_linear_regression() {
+        let fit = simple_linear_regression(&X, &Y).unwrap();
+        assert_eq!(fit.degrees_of_freedom, 3.0);
+
+        // s² = SSE / (n - 2), Sxx = 10, x̄ = 3
+        let s2: f64 = 0.107 / 3.0;
+        assert_relative_eq!(
+            fit.slope_standard_error,
+            (s2 / 10.0).sqrt(),
+            max_relative = RTOL
+        );
+        assert_relative_eq!(
+            fit.intercept_standard_error,
+            (s2 * (0.2 + 0.9)).sqrt(),
+            max_relative = RTOL
+        );
+
+        let t = StudentsT::new(3.0).quantile(0.975);
+        let (lower, upper) = fit.slope_confidence_interval(0.95);
+        assert_relative_eq!(
+            lower,
+            fit.slope - t * fit.slope_standard_error,
+            max_relative = RTOL
+        );
+        assert_relative_eq!(
+            upper,
+            fit.slope + t * fit.slope_standard_error,
+            max_relative = RTOL
+        );
+        let (lower, upper) = fit.intercept_confidence_interval(0.95);
+        let half_width = t * fit.intercept_standard_error;
+        assert_relative_eq!(upper - lower, 2.0 * half_width, max_relative = RTOL);
+    }
+
+    #[test]
+    fn test_simple_linear_regression_exact() {
+        let y = X.map(|x| 1.0 - 0.5 * x);
+        let fit = simple_linear_regression(&X, &y).unwrap();
+        assert_relative_eq!(fit.r_squared, 1.0, max_relative = RTOL);
+        assert_abs_diff_eq!(fit.slope_standard_error, 0.0, epsilon = 1e-15);
+
+        let fit = simple_linear_regression(&X[..2], &y[..2]).unwrap();
+        assert!(fit.slope_standard_error.is_nan());
+    }
+}
@@ -0,0 +1,69 @@
+//! boost/math/statistics/ljung_box.hpp
+
+use super::TestResult;
+use crate::ffi;
+
+/// Ljung-Box test of the null hypothesis that the samples `v` are not autocorrelated
+///
+/// *Q = n(n+2) Σ<sub>k=1</sub><sup>h</sup> ρ<sub>k</sub><sup>2</sup> / (n-k)*, where
+/// *ρ<sub>k</sub>* is the sample autocorrelation at lag *k*, with a p-value from the chi-squared
+/// distribution with *h - `fit_dof`* degrees of freedom.
+///
+/// The number of lags *h* defaults to *⌊ln n⌋* if `lags` is `None`, and must be positive and
+/// smaller than the number of samples. Use `fit_dof` to account for the parameters of a fitted
+/// model when `v` are its residuals.
+///
+/// Corresponds to `boost::math::statistics::ljung_box(v, lags, fit_dof)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/stats/ljung_box.html>
+pub fn ljung_box(v: &[f64], lags: Option<usize>, fit_dof: usize) -> TestResult {
+    // the default ⌊ln n⌋ is only positive for n ≥ 3
+    let min_len = if lags.is_none() { 3 } else { 2 };
+    if v.len() < min_len || lags.is_some_and(|lags| lags == 0 || lags >= v.len()) {
+        return TestResult::NAN;
+    }
+    // `-1` lets Boost choose the default
+    let lags = lags.map_or(-1, |lags| lags as i64);
+    TestResult::from_ffi(|p| unsafe {
+        ffi::math_ljung_box(v.as_ptr(), v.len(), lags, fit_dof as i64, p)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::distributions::{ChiSquared, Distribution};
+
+    /// Reference implementation of the Ljung-Box statistic
+    fn q(v: &[f64], lags: usize) -> f64 {
+        let n = v.len() as f64;
+        let mean = v.iter().sum::<f64>() / n;
+        let c = |k: usize| -> f64 {
+            (k..v.len())
+                .map(|t| (v[t] - mean) * (v[t - k] - mean))
+                .sum()
+        };
+        let c0 = c(0);
+        let sum: f64 = (1..=lags)
+            .map(|k| (c(k) / c0).powi(2) / (n - k as f64))
+            .sum();
+        n * (n + 2.0) * sum
+    }
+
+    #[test]
+    fn test_ljung_box() {
+        let v = [1.0, 3.0, 2.0, 5.0, 4.0, 6.0, 8.0, 7.0, 9.0, 8.0, 10.0, 12.0];
+        let result = ljung_box(&v, Some(3), 0);
+        assert_relative_eq!(result.statistic, q(&v, 3), max_relative = 1e-12);
+        let p = ChiSquared::new(3.0).cdf_complement(result.statistic);
+        assert_relative_eq!(result.p_value, p, max_relative = 1e-12);
+
+        let result = ljung_box(&v, Some(3), 1);
+        let p = ChiSquared::new(2.0).cdf_complement(result.statistic);
+        assert_relative_eq!(result.p_value, p, max_relative = 1e-12);
+
+        assert!(ljung_box(&v, Some(12), 0).statistic.is_nan());
+        assert!(ljung_box(&v, Some(0), 0).statistic.is_nan());
+        assert!(ljung_box(&[1.0], Some(1), 0).p_value.is_nan());
+        assert!(ljung_box(&[1.0, 2.0], None, 0).p_value.is_nan());
+    }
+}
@@ -0,0 +1,132 @@
+//! Statistics
+//!
+//! Corresponds to the Boost Math `boost/math/statistics` C++ headers.
+//! <https://boost.org/doc/libs/latest/libs/math/doc/html/statistics.html>
+//!
+//! ## Univariate Statistics
+//!
+//! - [`mean`], [`variance`], [`sample_variance`], and [`mean_and_sample_variance`]
+//! - [`skewness`], [`kurtosis`], and [`excess_kurtosis`]
+//! - [`median`], [`median_absolute_deviation`], and [`interquartile_range`]
+//! - [`gini_coefficient`] and [`sample_gini_coefficient`]
+//! - [`mode`]
+//!
+//! Functions that take a `&mut [f64]` reorder the samples in-place, like their C++ counterparts.
+//!
+//! ## Bivariate Statistics
+//!
+//! - [`covariance`] and [`means_and_covariance`]
+//! - [`correlation_coefficient`]
+//! - [`chatterjee_correlation`]
+//!
+//! These return a [`LengthMismatch`] error if the two samples have different lengths.
+//!
+//! ## Linear Regression
+//!
+//! - [`simple_ordinary_least_squares`] and [`simple_ordinary_least_squares_with_r_squared`]
+//! - [`simple_linear_regression`], which also provides standard errors and confidence intervals
+//!
+//! ## Signal Statistics
+//!
+//! - [`absolute_gini_coefficient`], [`sample_absolute_gini_coefficient`], and [`hoyer_sparsity`]
+//! - [`oracle_snr`] and [`oracle_snr_db`]
+//! - [`m2m4_snr_estimator`] and [`m2m4_snr_estimator_db`]
+//!
+//! These accept real signals of `f64`, and complex signals of `num_complex::Complex<f64>` if the
+//! `num-complex` feature is enabled.
+//!
+//! ## Hypothesis Tests
+//!
+//! - [`one_sample_t_test`], [`two_sample_t_test`], and [`paired_samples_t_test`]
+//! - [`one_sample_z_test`] and [`two_sample_z_test`]
+//! - [`anderson_darling_normality_statistic`]
+//! - [`ljung_box`]
+//! - [`runs_above_and_below_threshold`] and [`runs_above_and_below_median`]
+//!
+//! Unless noted otherwise, the tests return a [`TestResult`] with the test statistic and its
+//! p-value. Samples that are too small for a test result in NaN, and [`paired_samples_t_test`]
+//! returns a [`LengthMismatch`] error if the two samples have different lengths.
+
+mod anderson_darling;
+mod bivariate_statistics;
+mod chatterjee_correlation;
+mod linear_regression;
+mod ljung_box;
+mod runs_test;
+mod signal_statistics;
+mod t_test;
+mod univariate_statistics;
+mod z_test;
+
+pub use anderson_darling::*;
+pub use bivariate_statistics::*;
+pub use chatterjee_correlation::*;
+pub use linear_regression::*;
+pub use ljung_box::*;
+pub use runs_test::*;
+pub use signal_statistics::*;
+pub use t_test::*;
+pub use univariate_statistics::*;
+pub use z_test::*;
+
+use core::fmt;
+
+/// Test statistic and p-value of a statistical hypothesis test
+///
+/// Corresponds to the `std::pair` returned by the Boost Math hypothesis tests in C++.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TestResult {
+    /// Value of the test statistic
+    pub statistic: f64,
+    /// Probability of observing a test statistic at least as extreme under the null hypothesis
+    pub p_value: f64,
+}
+
+impl TestResult {
+    const NAN: Self = Self {
+        statistic: f64::NAN,
+        p_value: f64::NAN,
+    };
+
+    /// Calls a wrapper function that returns the statistic and writes the p-value to a pointer.
+    fn from_ffi(f: impl FnOnce(*mut f64) -> f64) -> Self {
+        let mut p_value = f64::NAN;
+        let statistic = f(&mut p_value);
+        Self { statistic, p_value }
+    }
+}
+
+/// Error for two samples that are required to have the same length, but don't
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LengthMismatch {
+    /// Length of the first sample
+    pub left: usize,
+    /// Length of the second sample
+    pub right: usize,
+}
+
+impl LengthMismatch {
+    /// Returns the common length of `u` and `v`, or an error if they differ.
+    fn check<T>(u: &[T], v: &[T]) -> Result<usize, Self> {
+        if u.len() == v.len() {
+            Ok(u.len())
+        } else {
+            Err(Self {
+                left: u.len(),
+                right: v.len(),
+            })
+        }
+    }
+}
+
+impl fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "samples must have the same length, got {} and {}",
+            self.left, self.right
+        )
+    }
+}
+
+impl core::error::Error for LengthMismatch {}
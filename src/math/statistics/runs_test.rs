@@ -0,0 +1,71 @@
+//! boost/math/statistics/runs_test.hpp
+
+use super::TestResult;
+use crate::ffi;
+
+/// Wald-Wolfowitz runs test of the null hypothesis that the samples above and below `threshold`
+/// occur in random order
+///
+/// Samples equal to the threshold are ignored. The test statistic is the normalized number of
+/// runs, with a two-sided p-value from the standard normal distribution.
+///
+/// Corresponds to `boost::math::statistics::runs_above_and_below_threshold(v, threshold)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/stats/runs_test.html>
+#[doc(alias = "runs_test")]
+#[doc(alias = "wald_wolfowitz")]
+pub fn runs_above_and_below_threshold(v: &[f64], threshold: f64) -> TestResult {
+    if v.len() < 2 {
+        return TestResult::NAN;
+    }
+    TestResult::from_ffi(|p| unsafe {
+        ffi::math_runs_above_and_below_threshold(v.as_ptr(), v.len(), threshold, p)
+    })
+}
+
+/// [`runs_above_and_below_threshold`] with the median of `v` as threshold
+///
+/// Corresponds to `boost::math::statistics::runs_above_and_below_median(v)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/stats/runs_test.html>
+pub fn runs_above_and_below_median(v: &[f64]) -> TestResult {
+    if v.len() < 2 {
+        return TestResult::NAN;
+    }
+    TestResult::from_ffi(|p| unsafe {
+        ffi::math_runs_above_and_below_median(v.as_ptr(), v.len(), p)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::distributions::{Distribution, Normal};
+
+    const RTOL: f64 = 1e-14;
+
+    #[test]
+    fn test_runs_above_and_below_threshold() {
+        // 5 above and 5 below in 8 runs, where 6 are expected with a variance of 20/9
+        let v = [1.0, -1.0, 1.0, -1.0, 1.0, -1.0, 1.0, 1.0, -1.0, -1.0];
+        let result = runs_above_and_below_threshold(&v, 0.0);
+        let z = 2.0 / (20.0f64 / 9.0).sqrt();
+        assert_relative_eq!(result.statistic, z, max_relative = RTOL);
+        let p = 2.0 * Normal::default().cdf_complement(z);
+        assert_relative_eq!(result.p_value, p, max_relative = 1e-12);
+
+        assert!(runs_above_and_below_threshold(&[], 0.0).p_value.is_nan());
+        assert!(
+            runs_above_and_below_threshold(&[1.0], 0.0)
+                .statistic
+                .is_nan()
+        );
+    }
+
+    #[test]
+    fn test_runs_above_and_below_median() {
+        let v = [2.0, -1.0, 3.0, -2.0, 1.0, -3.0, 4.0, 5.0, -4.0, -5.0];
+        let result = runs_above_and_below_median(&v);
+        assert_eq!(result, runs_above_and_below_threshold(&v, 0.0));
+
+        assert!(runs_above_and_below_median(&[1.0]).p_value.is_nan());
+    }
+}
@@ -0,0 +1,380 @@
+//! boost/math/statistics/signal_statistics.hpp
+
+use super::LengthMismatch;
+use crate::ffi;
+
+/// Sample type of a signal, i.e. [`f64`] for real signals, and `num_complex::Complex<f64>` for
+/// complex signals if the `num-complex` feature is enabled
+///
+/// The statistics of complex signals are computed from the moduli of the samples.
+pub trait SignalSample: sealed::Sealed {}
+
+mod sealed {
+    /// Dispatches the signal statistics to the C++ overloads of the sample type.
+    pub trait Sealed: Sized {
+        /// Default kurtosis of the noise in the M2M4 estimator, i.e. that of Gaussian noise
+        const NOISE_KURTOSIS: f64;
+
+        fn absolute_gini_coefficient(v: &mut [Self]) -> f64;
+        fn sample_absolute_gini_coefficient(v: &mut [Self]) -> f64;
+        fn hoyer_sparsity(v: &[Self]) -> f64;
+        fn oracle_snr(signal: &[Self], noisy_signal: &[Self]) -> f64;
+        fn oracle_snr_db(signal: &[Self], noisy_signal: &[Self]) -> f64;
+        fn m2m4_snr_estimator(v: &[Self], signal_kurtosis: f64, noise_kurtosis: f64) -> f64;
+        fn m2m4_snr_estimator_db(v: &[Self], signal_kurtosis: f64, noise_kurtosis: f64) -> f64;
+    }
+}
+
+impl sealed::Sealed for f64 {
+    const NOISE_KURTOSIS: f64 = 3.0;
+
+    fn absolute_gini_coefficient(v: &mut [Self]) -> f64 {
+        unsafe { ffi::math_absolute_gini_coefficient(v.as_mut_ptr(), v.len()) }
+    }
+
+    fn sample_absolute_gini_coefficient(v: &mut [Self]) -> f64 {
+        unsafe { ffi::math_sample_absolute_gini_coefficient(v.as_mut_ptr(), v.len()) }
+    }
+
+    fn hoyer_sparsity(v: &[Self]) -> f64 {
+        unsafe { ffi::math_hoyer_sparsity(v.as_ptr(), v.len()) }
+    }
+
+    fn oracle_snr(signal: &[Self], noisy_signal: &[Self]) -> f64 {
+        unsafe { ffi::math_oracle_snr(signal.as_ptr(), noisy_signal.as_ptr(), signal.len()) }
+    }
+
+    fn oracle_snr_db(signal: &[Self], noisy_signal: &[Self]) -> f64 {
+        unsafe { ffi::math_oracle_snr_db(signal.as_ptr(), noisy_signal.as_ptr(), signal.len()) }
+    }
+
+    fn m2m4_snr_estimator(v: &[Self], signal_kurtosis: f64, noise_kurtosis: f64) -> f64 {
+        unsafe {
+            ffi::math_m2m4_snr_estimator(v.as_ptr(), v.len(), signal_kurtosis, noise_kurtosis)
+        }
+    }
+
+    fn m2m4_snr_estimator_db(v: &[Self], signal_kurtosis: f64, noise_kurtosis: f64) -> f64 {
+        unsafe {
+            ffi::math_m2m4_snr_estimator_db(v.as_ptr(), v.len(), signal_kurtosis, noise_kurtosis)
+        }
+    }
+}
+
+impl SignalSample for f64 {}
+
+#[cfg(feature = "num-complex")]
+impl sealed::Sealed for num_complex::Complex<f64> {
+    const NOISE_KURTOSIS: f64 = 2.0;
+
+    fn absolute_gini_coefficient(v: &mut [Self]) -> f64 {
+        unsafe { ffi::math_absolute_gini_coefficient_complex(v.as_mut_ptr(), v.len()) }
+    }
+
+    fn sample_absolute_gini_coefficient(v: &mut [Self]) -> f64 {
+        unsafe { ffi::math_sample_absolute_gini_coefficient_complex(v.as_mut_ptr(), v.len()) }
+    }
+
+    fn hoyer_sparsity(v: &[Self]) -> f64 {
+        unsafe { ffi::math_hoyer_sparsity_complex(v.as_ptr(), v.len()) }
+    }
+
+    fn oracle_snr(signal: &[Self], noisy_signal: &[Self]) -> f64 {
+        let (s, x, n) = (signal.as_ptr(), noisy_signal.as_ptr(), signal.len());
+        unsafe { ffi::math_oracle_snr_complex(s, x, n) }
+    }
+
+    fn oracle_snr_db(signal: &[Self], noisy_signal: &[Self]) -> f64 {
+        let (s, x, n) = (signal.as_ptr(), noisy_signal.as_ptr(), signal.len());
+        unsafe { ffi::math_oracle_snr_db_complex(s, x, n) }
+    }
+
+    fn m2m4_snr_estimator(v: &[Self], signal_kurtosis: f64, noise_kurtosis: f64) -> f64 {
+        let (ka, kw) = (signal_kurtosis, noise_kurtosis);
+        unsafe { ffi::math_m2m4_snr_estimator_complex(v.as_ptr(), v.len(), ka, kw) }
+    }
+
+    fn m2m4_snr_estimator_db(v: &[Self], signal_kurtosis: f64, noise_kurtosis: f64) -> f64 {
+        let (ka, kw) = (signal_kurtosis, noise_kurtosis);
+        unsafe { ffi::math_m2m4_snr_estimator_db_complex(v.as_ptr(), v.len(), ka, kw) }
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl SignalSample for num_complex::Complex<f64> {}
+
+/// Gini coefficient of the absolute values *|x<sub>i</sub>|* of the samples, as a measure of
+/// sparsity
+///
+/// This is 0 if all samples have the same magnitude, and *(n - 1) / n* if only one sample is
+/// nonzero. See [`sample_absolute_gini_coefficient`] for the unbiased estimator. Requires at least
+/// two samples, which are sorted by magnitude in-place.
+///
+/// Corresponds to `boost::math::statistics::absolute_gini_coefficient(v)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/signal_statistics.html>
+pub fn absolute_gini_coefficient<T: SignalSample>(v: &mut [T]) -> f64 {
+    if v.len() < 2 {
+        return f64::NAN;
+    }
+    T::absolute_gini_coefficient(v)
+}
+
+/// [`absolute_gini_coefficient`] scaled by *n / (n - 1)*, so that it is 1 if only one sample is
+/// nonzero
+///
+/// Requires at least two samples, which are sorted by magnitude in-place.
+///
+/// Corresponds to `boost::math::statistics::sample_absolute_gini_coefficient(v)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/signal_statistics.html>
+pub fn sample_absolute_gini_coefficient<T: SignalSample>(v: &mut [T]) -> f64 {
+    if v.len() < 2 {
+        return f64::NAN;
+    }
+    T::sample_absolute_gini_coefficient(v)
+}
+
+/// Hoyer sparsity *(√n - ‖x‖<sub>1</sub> / ‖x‖<sub>2</sub>) / (√n - 1)* of the samples
+///
+/// This is 0 if all samples have the same magnitude, and 1 if only one sample is nonzero.
+/// Requires at least two samples.
+///
+/// Corresponds to `boost::math::statistics::hoyer_sparsity(v)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/signal_statistics.html>
+pub fn hoyer_sparsity<T: SignalSample>(v: &[T]) -> f64 {
+    if v.len() < 2 {
+        return f64::NAN;
+    }
+    T::hoyer_sparsity(v)
+}
+
+/// Signal-to-noise ratio of a noisy signal *x*, given the true signal *s*
+///
+/// *SNR = Σ|s<sub>i</sub>|<sup>2</sup> / Σ|x<sub>i</sub> - s<sub>i</sub>|<sup>2</sup>*
+///
+/// The ratio is infinite if the signals are identical, and NaN if both are zero.
+///
+/// Corresponds to `boost::math::statistics::oracle_snr(signal, noisy_signal)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/signal_statistics.html>
+pub fn oracle_snr<T: SignalSample>(
+    signal: &[T],
+    noisy_signal: &[T],
+) -> Result<f64, LengthMismatch> {
+    let n = LengthMismatch::check(signal, noisy_signal)?;
+    if n == 0 {
+        return Ok(f64::NAN);
+    }
+    Ok(T::oracle_snr(signal, noisy_signal))
+}
+
+/// [`oracle_snr`] in decibels, i.e. *10 log<sub>10</sub>(SNR)*
+///
+/// Corresponds to `boost::math::statistics::oracle_snr_db(signal, noisy_signal)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/signal_statistics.html>
+pub fn oracle_snr_db<T: SignalSample>(
+    signal: &[T],
+    noisy_signal: &[T],
+) -> Result<f64, LengthMismatch> {
+    let n = LengthMismatch::check(signal, noisy_signal)?;
+    if n == 0 {
+        return Ok(f64::NAN);
+    }
+    Ok(T::oracle_snr_db(signal, noisy_signal))
+}
+
+/// Blind estimate of the signal-to-noise ratio of a noisy signal, from its second and fourth
+/// moments *M<sub>2</sub>* and *M<sub>4</sub>*
+///
+/// The estimator solves *M<sub>2</sub> = S + N* and
+/// *M<sub>4</sub> = k<sub>a</sub>S<sup>2</sup> + cSN + k<sub>w</sub>N<sup>2</sup>* for the signal
+/// and noise powers *S* and *N*, where *c* is 6 for real, and 4 for complex signals. The kurtosis
+/// *k<sub>a</sub>* of the signal defaults to 1, which holds for constant modulus signals such as
+/// BPSK and QPSK. The kurtosis *k<sub>w</sub>* of the noise defaults to that of Gaussian noise,
+/// i.e. 3 for real, and 2 for (circularly symmetric) complex signals. Both must be positive.
+///
+/// The estimate is NaN if the estimated noise power is not positive, e.g. for noise-free signals.
+///
+/// Boost only implements the estimator for real signals; the complex case follows
+/// Pauluzzi and Beaulieu (2000), using the moments of *|x<sub>i</sub>|*.
+///
+/// Corresponds to
+/// `boost::math::statistics::m2m4_snr_estimator(noisy_signal, signal_kurtosis, noise_kurtosis)`
+/// in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/signal_statistics.html>
+///
+/// # Examples
+///
+/// ```
+/// use boost::math::statistics::m2m4_snr_estimator;
+///
+/// // noisy BPSK symbols
+/// let v = [1.2, -0.9, -1.1, 0.8, -1.0, 1.1, 0.9, -1.2];
+/// assert!(m2m4_snr_estimator(&v, None, None) > 0.0);
+///
+/// // without noise, the SNR cannot be estimated
+/// let v = [1.0, -1.0, -1.0, 1.0, -1.0, 1.0, 1.0, -1.0];
+/// assert!(m2m4_snr_estimator(&v, None, None).is_nan());
+/// ```
+#[doc(alias = "m2m4")]
+pub fn m2m4_snr_estimator<T: SignalSample>(
+    noisy_signal: &[T],
+    signal_kurtosis: Option<f64>,
+    noise_kurtosis: Option<f64>,
+) -> f64 {
+    let ka = signal_kurtosis.unwrap_or(1.0);
+    let kw = noise_kurtosis.unwrap_or(T::NOISE_KURTOSIS);
+    if noisy_signal.is_empty() || !(ka > 0.0 && kw > 0.0) {
+        return f64::NAN;
+    }
+    T::m2m4_snr_estimator(noisy_signal, ka, kw)
+}
+
+/// [`m2m4_snr_estimator`] in decibels, i.e. *10 log<sub>10</sub>(SNR)*
+///
+/// Corresponds to
+/// `boost::math::statistics::m2m4_snr_estimator_db(noisy_signal, signal_kurtosis, noise_kurtosis)`
+/// in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/signal_statistics.html>
+pub fn m2m4_snr_estimator_db<T: SignalSample>(
+    noisy_signal: &[T],
+    signal_kurtosis: Option<f64>,
+    noise_kurtosis: Option<f64>,
+) -> f64 {
+    let ka = signal_kurtosis.unwrap_or(1.0);
+    let kw = noise_kurtosis.unwrap_or(T::NOISE_KURTOSIS);
+    if noisy_signal.is_empty() || !(ka > 0.0 && kw > 0.0) {
+        return f64::NAN;
+    }
+    T::m2m4_snr_estimator_db(noisy_signal, ka, kw)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RTOL: f64 = 1e-12;
+
+    #[test]
+    fn test_absolute_gini_coefficient() {
+        let mut v = [0.0, 2.0, 0.0, 0.0];
+        assert_relative_eq!(absolute_gini_coefficient(&mut v), 0.75, max_relative = RTOL);
+        assert_relative_eq!(
+            sample_absolute_gini_coefficient(&mut v),
+            1.0,
+            max_relative = RTOL
+        );
+
+        let mut v = [-2.0, 2.0, -2.0, 2.0];
+        assert_abs_diff_eq!(absolute_gini_coefficient(&mut v), 0.0, epsilon = RTOL);
+
+        assert!(absolute_gini_coefficient(&mut [1.0]).is_nan());
+        assert!(sample_absolute_gini_coefficient::<f64>(&mut []).is_nan());
+    }
+
+    #[test]
+    fn test_hoyer_sparsity() {
+        assert_relative_eq!(
+            hoyer_sparsity(&[0.0, 0.0, -3.0, 0.0]),
+            1.0,
+            max_relative = RTOL
+        );
+        assert_abs_diff_eq!(hoyer_sparsity(&[1.0, -1.0, 1.0, -1.0]), 0.0, epsilon = RTOL);
+        assert!(hoyer_sparsity(&[1.0]).is_nan());
+    }
+
+    #[test]
+    fn test_oracle_snr() {
+        let signal = [1.0, -1.0, 1.0, -1.0];
+        let noisy_signal = [1.1, -0.9, 0.9, -1.1];
+        assert_relative_eq!(
+            oracle_snr(&signal, &noisy_signal).unwrap(),
+            100.0,
+            max_relative = RTOL
+        );
+        assert_relative_eq!(
+            oracle_snr_db(&signal, &noisy_signal).unwrap(),
+            20.0,
+            max_relative = RTOL
+        );
+        assert_eq!(oracle_snr(&signal, &signal).unwrap(), f64::INFINITY);
+        assert_eq!(
+            oracle_snr(&signal, &noisy_signal[1..]),
+            Err(LengthMismatch { left: 4, right: 3 })
+        );
+    }
+
+    #[test]
+    fn test_m2m4_snr_estimator() {
+        let v = [1.0, -1.0, -1.0, 1.0, -1.0, 1.0, 1.0, -1.0];
+        // noise-free
+        assert!(m2m4_snr_estimator(&v, None, None).is_nan());
+
+        let noisy = [1.2, -0.9, -1.1, 0.8, -1.0, 1.1, 0.9, -1.2];
+        let snr = m2m4_snr_estimator(&noisy, None, None);
+        assert!(snr > 0.0);
+        assert_relative_eq!(
+            m2m4_snr_estimator_db(&noisy, None, None),
+            10.0 * snr.log10(),
+            max_relative = RTOL
+        );
+
+        assert!(m2m4_snr_estimator::<f64>(&[], None, None).is_nan());
+        assert!(m2m4_snr_estimator(&v, Some(0.0), None).is_nan());
+        assert!(m2m4_snr_estimator_db(&v, None, Some(-1.0)).is_nan());
+    }
+
+    #[cfg(feature = "num-complex")]
+    #[test]
+    fn test_complex() {
+        use num_complex::Complex;
+
+        let (zero, i) = (Complex::new(0.0, 0.0), Complex::new(0.0, 1.0));
+
+        let mut v = [zero, Complex::new(3.0, 4.0), zero];
+        assert_relative_eq!(
+            absolute_gini_coefficient(&mut v),
+            2.0 / 3.0,
+            max_relative = RTOL
+        );
+        assert_relative_eq!(
+            sample_absolute_gini_coefficient(&mut v),
+            1.0,
+            max_relative = RTOL
+        );
+
+        assert_relative_eq!(hoyer_sparsity(&[zero, zero, i]), 1.0, max_relative = RTOL);
+        let v = [i, Complex::new(1.0, 0.0), -i, Complex::new(-1.0, 0.0)];
+        assert_abs_diff_eq!(hoyer_sparsity(&v), 0.0, epsilon = RTOL);
+
+        let signal = [Complex::new(1.0, 0.0), i];
+        let noisy_signal = [Complex::new(1.0, 0.1), Complex::new(0.0, 0.9)];
+        assert_relative_eq!(
+            oracle_snr(&signal, &noisy_signal).unwrap(),
+            100.0,
+            max_relative = RTOL
+        );
+        assert_relative_eq!(
+            oracle_snr_db(&signal, &noisy_signal).unwrap(),
+            20.0,
+            max_relative = RTOL
+        );
+
+        // noise-free QPSK symbols
+        let v = [
+            Complex::new(1.0, 1.0),
+            Complex::new(-1.0, 1.0),
+            Complex::new(1.0, -1.0),
+        ];
+        assert!(m2m4_snr_estimator(&v, None, None).is_nan());
+
+        // M2 = 2 and M4 = 5, so S = √(2M2² - M4) = √3 and N = 2 - √3
+        let v = [Complex::new(1.0, 0.0), Complex::new(0.0, 3.0f64.sqrt())];
+        let s = 3.0f64.sqrt();
+        let snr = m2m4_snr_estimator(&v, None, None);
+        assert_relative_eq!(snr, s / (2.0 - s), max_relative = RTOL);
+        assert_relative_eq!(
+            m2m4_snr_estimator_db(&v, None, None),
+            10.0 * snr.log10(),
+            max_relative = RTOL
+        );
+    }
+}
@@ -0,0 +1,112 @@
+//! boost/math/statistics/t_test.hpp
+
+use super::{LengthMismatch, TestResult};
+use crate::ffi;
+
+/// One-sample Student's *t*-test of the null hypothesis that the mean of the population is
+/// `assumed_mean`
+///
+/// The test statistic is *t = (x̄ - μ<sub>0</sub>) / (s / √n)*, with a two-sided p-value from
+/// the Student's *t* distribution with *n - 1* degrees of freedom.
+///
+/// Corresponds to `boost::math::statistics::one_sample_t_test(v, assumed_mean)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/stats/t_test.html>
+pub fn one_sample_t_test(v: &[f64], assumed_mean: f64) -> TestResult {
+    if v.len() < 2 {
+        return TestResult::NAN;
+    }
+    TestResult::from_ffi(|p| unsafe {
+        ffi::math_one_sample_t_test(v.as_ptr(), v.len(), assumed_mean, p)
+    })
+}
+
+/// Two-sample Welch's *t*-test of the null hypothesis that `u` and `v` have the same mean
+///
+/// Unlike Student's two-sample *t*-test, this does not assume that the variances are equal.
+///
+/// Corresponds to `boost::math::statistics::two_sample_t_test(u, v)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/stats/t_test.html>
+#[doc(alias = "welchs_t_test")]
+pub fn two_sample_t_test(u: &[f64], v: &[f64]) -> TestResult {
+    if u.len() < 2 || v.len() < 2 {
+        return TestResult::NAN;
+    }
+    TestResult::from_ffi(|p| unsafe {
+        ffi::math_two_sample_t_test(u.as_ptr(), u.len(), v.as_ptr(), v.len(), p)
+    })
+}
+
+/// Paired samples *t*-test of the null hypothesis that the mean of the differences `u - v` is
+/// zero
+///
+/// Returns a [`LengthMismatch`] error if the samples have different lengths.
+///
+/// Corresponds to `boost::math::statistics::paired_samples_t_test(u, v)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/stats/t_test.html>
+pub fn paired_samples_t_test(u: &[f64], v: &[f64]) -> Result<TestResult, LengthMismatch> {
+    let n = LengthMismatch::check(u, v)?;
+    if n < 2 {
+        return Ok(TestResult::NAN);
+    }
+    Ok(TestResult::from_ffi(|p| unsafe {
+        ffi::math_paired_samples_t_test(u.as_ptr(), v.as_ptr(), n, p)
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::distributions::{Distribution, StudentsT};
+
+    const RTOL: f64 = 1e-14;
+
+    #[test]
+    fn test_one_sample_t_test() {
+        // x̄ = 3, s² = 2.5
+        let result = one_sample_t_test(&[1.0, 2.0, 3.0, 4.0, 5.0], 2.0);
+        let t = core::f64::consts::SQRT_2;
+        assert_relative_eq!(result.statistic, t, max_relative = RTOL);
+        let p = 2.0 * StudentsT::new(4.0).cdf_complement(t);
+        assert_relative_eq!(result.p_value, p, max_relative = 1e-12);
+
+        let result = one_sample_t_test(&[1.0, 2.0, 3.0, 4.0, 5.0], 3.0);
+        assert_eq!(result.statistic, 0.0);
+        assert_relative_eq!(result.p_value, 1.0, max_relative = RTOL);
+
+        assert!(one_sample_t_test(&[1.0], 0.0).statistic.is_nan());
+    }
+
+    #[test]
+    fn test_two_sample_t_test() {
+        // equal sizes and variances, so this coincides with Student's t-test with 8 dof
+        let u = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let v = [3.0, 4.0, 5.0, 6.0, 7.0];
+        let result = two_sample_t_test(&u, &v);
+        assert_relative_eq!(result.statistic, -2.0, max_relative = RTOL);
+        let p = 2.0 * StudentsT::new(8.0).cdf(-2.0);
+        assert_relative_eq!(result.p_value, p, max_relative = 1e-12);
+
+        assert!(two_sample_t_test(&u, &[]).p_value.is_nan());
+    }
+
+    #[test]
+    fn test_paired_samples_t_test() {
+        // differences: [-1, 0, -1, -1, 0], with mean -0.6 and variance 0.3
+        let u = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let v = [2.0, 2.0, 4.0, 5.0, 5.0];
+        let result = paired_samples_t_test(&u, &v).unwrap();
+        let t = -0.6 / 0.06f64.sqrt();
+        assert_relative_eq!(result.statistic, t, max_relative = 1e-13);
+        let p = 2.0 * StudentsT::new(4.0).cdf(t);
+        assert_relative_eq!(result.p_value, p, max_relative = 1e-12);
+
+        assert!(
+            paired_samples_t_test(&u[..1], &v[..1])
+                .unwrap()
+                .statistic
+                .is_nan()
+        );
+        let err = LengthMismatch { left: 5, right: 4 };
+        assert_eq!(paired_samples_t_test(&u, &v[1..]), Err(err));
+    }
+}
@@ -0,0 +1,254 @@
+//! boost/math/statistics/univariate_statistics.hpp
+
+use crate::ffi;
+use alloc::{vec, vec::Vec};
+
+/// Arithmetic mean *x̄* of the samples
+///
+/// Corresponds to `boost::math::statistics::mean(v)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/univariate_statistics.html>
+pub fn mean(v: &[f64]) -> f64 {
+    if v.is_empty() {
+        return f64::NAN;
+    }
+    unsafe { ffi::math_mean(v.as_ptr(), v.len()) }
+}
+
+/// Population variance *σ<sup>2</sup> = Σ(x<sub>i</sub> - x̄)<sup>2</sup> / n*
+///
+/// See [`sample_variance`] for the unbiased estimator.
+///
+/// Corresponds to `boost::math::statistics::variance(v)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/univariate_statistics.html>
+pub fn variance(v: &[f64]) -> f64 {
+    if v.is_empty() {
+        return f64::NAN;
+    }
+    unsafe { ffi::math_variance(v.as_ptr(), v.len()) }
+}
+
+/// Sample variance *s<sup>2</sup> = Σ(x<sub>i</sub> - x̄)<sup>2</sup> / (n - 1)*
+///
+/// Requires at least two samples.
+///
+/// Corresponds to `boost::math::statistics::sample_variance(v)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/univariate_statistics.html>
+pub fn sample_variance(v: &[f64]) -> f64 {
+    if v.len() < 2 {
+        return f64::NAN;
+    }
+    unsafe { ffi::math_sample_variance(v.as_ptr(), v.len()) }
+}
+
+/// [`mean`] and [`sample_variance`], computed in a single pass
+///
+/// Corresponds to `boost::math::statistics::mean_and_sample_variance(v)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/univariate_statistics.html>
+pub fn mean_and_sample_variance(v: &[f64]) -> (f64, f64) {
+    if v.len() < 2 {
+        return (mean(v), f64::NAN);
+    }
+    let mut var = f64::NAN;
+    let mean = unsafe { ffi::math_mean_and_sample_variance(v.as_ptr(), v.len(), &mut var) };
+    (mean, var)
+}
+
+/// Population skewness *E\[(X - μ)<sup>3</sup>\] / σ<sup>3</sup>*
+///
+/// Corresponds to `boost::math::statistics::skewness(v)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/univariate_statistics.html>
+pub fn skewness(v: &[f64]) -> f64 {
+    if v.is_empty() {
+        return f64::NAN;
+    }
+    unsafe { ffi::math_skewness(v.as_ptr(), v.len()) }
+}
+
+/// Population kurtosis *E\[(X - μ)<sup>4</sup>\] / σ<sup>4</sup>*
+///
+/// See [`excess_kurtosis`] for the kurtosis minus 3.
+///
+/// Corresponds to `boost::math::statistics::kurtosis(v)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/univariate_statistics.html>
+pub fn kurtosis(v: &[f64]) -> f64 {
+    if v.is_empty() {
+        return f64::NAN;
+    }
+    unsafe { ffi::math_kurtosis(v.as_ptr(), v.len()) }
+}
+
+/// Excess kurtosis, i.e. the [`kurtosis`] minus 3
+///
+/// Corresponds to `boost::math::statistics::excess_kurtosis(v)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/univariate_statistics.html>
+pub fn excess_kurtosis(v: &[f64]) -> f64 {
+    if v.is_empty() {
+        return f64::NAN;
+    }
+    unsafe { ffi::math_excess_kurtosis(v.as_ptr(), v.len()) }
+}
+
+/// Median of the samples
+///
+/// The samples are partially sorted in-place.
+///
+/// Corresponds to `boost::math::statistics::median(v)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/univariate_statistics.html>
+pub fn median(v: &mut [f64]) -> f64 {
+    if v.is_empty() {
+        return f64::NAN;
+    }
+    unsafe { ffi::math_median(v.as_mut_ptr(), v.len()) }
+}
+
+/// Median absolute deviation *median(|x<sub>i</sub> - c|)* from the center *c*
+///
+/// If `center` is `None`, the [`median`] of the samples is used. The samples are reordered
+/// in-place.
+///
+/// Corresponds to `boost::math::statistics::median_absolute_deviation(v, center)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/univariate_statistics.html>
+#[doc(alias = "mad")]
+pub fn median_absolute_deviation(v: &mut [f64], center: Option<f64>) -> f64 {
+    if v.is_empty() {
+        return f64::NAN;
+    }
+    let center = center.unwrap_or(f64::NAN);
+    unsafe { ffi::math_median_absolute_deviation(v.as_mut_ptr(), v.len(), center) }
+}
+
+/// Interquartile range *Q<sub>3</sub> - Q<sub>1</sub>*
+///
+/// The quartiles are the medians of the lower and upper halves of the samples, excluding the
+/// median itself if the number of samples is odd. Requires at least three samples, which are
+/// reordered in-place.
+///
+/// Corresponds to `boost::math::statistics::interquartile_range(v)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/univariate_statistics.html>
+#[doc(alias = "iqr")]
+pub fn interquartile_range(v: &mut [f64]) -> f64 {
+    if v.len() < 3 {
+        return f64::NAN;
+    }
+    unsafe { ffi::math_interquartile_range(v.as_mut_ptr(), v.len()) }
+}
+
+/// Gini coefficient of non-negative samples, a measure of inequality in *[0, 1)*
+///
+/// See [`sample_gini_coefficient`] for the unbiased estimator. Requires at least two samples,
+/// which are sorted in-place.
+///
+/// Corresponds to `boost::math::statistics::gini_coefficient(v)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/univariate_statistics.html>
+pub fn gini_coefficient(v: &mut [f64]) -> f64 {
+    if v.len() < 2 {
+        return f64::NAN;
+    }
+    unsafe { ffi::math_gini_coefficient(v.as_mut_ptr(), v.len()) }
+}
+
+/// Sample Gini coefficient, i.e. the [`gini_coefficient`] scaled by *n / (n - 1)*
+///
+/// Requires at least two samples, which are sorted in-place.
+///
+/// Corresponds to `boost::math::statistics::sample_gini_coefficient(v)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/univariate_statistics.html>
+pub fn sample_gini_coefficient(v: &mut [f64]) -> f64 {
+    if v.len() < 2 {
+        return f64::NAN;
+    }
+    unsafe { ffi::math_sample_gini_coefficient(v.as_mut_ptr(), v.len()) }
+}
+
+/// Most frequent values of the samples, in ascending order
+///
+/// All values that occur most frequently are returned. The samples are sorted in-place.
+///
+/// Corresponds to `boost::math::statistics::mode(v, output)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/univariate_statistics.html>
+pub fn mode(v: &mut [f64]) -> Vec<f64> {
+    if v.is_empty() {
+        return Vec::new();
+    }
+    let mut modes = vec![f64::NAN; v.len()];
+    let len = unsafe { ffi::math_mode(v.as_mut_ptr(), v.len(), modes.as_mut_ptr()) };
+    modes.truncate(len);
+    modes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RTOL: f64 = 4.0 * f64::EPSILON;
+    const ONE_TO_EIGHT: [f64; 8] = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+
+    #[test]
+    fn test_mean_and_variance() {
+        let v = ONE_TO_EIGHT;
+        assert_eq!(mean(&v), 4.5);
+        assert_relative_eq!(variance(&v), 5.25, max_relative = RTOL);
+        assert_relative_eq!(sample_variance(&v), 6.0, max_relative = RTOL);
+        let (m, s2) = mean_and_sample_variance(&v);
+        assert_eq!(m, 4.5);
+        assert_relative_eq!(s2, 6.0, max_relative = RTOL);
+
+        assert!(mean(&[]).is_nan());
+        assert_eq!(variance(&[1.0]), 0.0);
+        assert!(sample_variance(&[1.0]).is_nan());
+        assert!(mean_and_sample_variance(&[1.0]).1.is_nan());
+    }
+
+    #[test]
+    fn test_skewness_and_kurtosis() {
+        let v = ONE_TO_EIGHT;
+        assert_abs_diff_eq!(skewness(&v), 0.0, epsilon = 1e-15);
+        assert_relative_eq!(kurtosis(&v), 37.0 / 21.0, max_relative = RTOL);
+        assert_relative_eq!(excess_kurtosis(&v), -26.0 / 21.0, max_relative = RTOL);
+
+        // central moments m2 = 438/27 and m3 = 3570/81
+        let skew = (3570.0 / 81.0) / (438.0f64 / 27.0).powf(1.5);
+        assert_relative_eq!(skewness(&[1.0, 2.0, 10.0]), skew, max_relative = RTOL);
+    }
+
+    #[test]
+    fn test_median() {
+        assert_eq!(median(&mut [3.0, 1.0, 2.0]), 2.0);
+        assert_eq!(median(&mut [4.0, 1.0, 3.0, 2.0]), 2.5);
+        assert!(median(&mut []).is_nan());
+    }
+
+    #[test]
+    fn test_median_absolute_deviation() {
+        let mut v = [1.0, 1.0, 2.0, 2.0, 4.0, 6.0, 9.0];
+        assert_eq!(median_absolute_deviation(&mut v, None), 1.0);
+        assert_eq!(median_absolute_deviation(&mut v, Some(0.0)), 2.0);
+    }
+
+    #[test]
+    fn test_interquartile_range() {
+        assert_eq!(interquartile_range(&mut [5.0, 4.0, 3.0, 2.0, 1.0]), 3.0);
+        let mut v = ONE_TO_EIGHT;
+        assert_eq!(interquartile_range(&mut v), 4.0);
+        assert!(interquartile_range(&mut [1.0, 2.0]).is_nan());
+    }
+
+    #[test]
+    fn test_gini_coefficient() {
+        assert_eq!(gini_coefficient(&mut [1.0, 1.0, 1.0, 1.0]), 0.0);
+        assert_relative_eq!(gini_coefficient(&mut [1.0, 0.0, 0.0, 0.0]), 0.75);
+        assert_relative_eq!(sample_gini_coefficient(&mut [1.0, 0.0, 0.0, 0.0]), 1.0);
+        assert!(gini_coefficient(&mut [1.0]).is_nan());
+    }
+
+    #[test]
+    fn test_mode() {
+        assert_eq!(mode(&mut [3.0, 2.0, 1.0, 2.0, 3.0, 4.0]), vec![2.0, 3.0]);
+        assert_eq!(mode(&mut [1.0, 2.0, 1.0]), vec![1.0]);
+
+        let mut v = [5.0, 1.0, 5.0, 2.0, 1.0, 5.0];
+        assert_eq!(mode(&mut v), vec![5.0]);
+        assert_eq!(v, [1.0, 1.0, 2.0, 5.0, 5.0, 5.0]);
+        assert_eq!(mode(&mut []), vec![]);
+    }
+}
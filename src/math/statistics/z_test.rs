@@ -0,0 +1,65 @@
+//! boost/math/statistics/z_test.hpp
+
+use super::TestResult;
+use crate::ffi;
+
+/// One-sample *z*-test of the null hypothesis that the mean of the population is `assumed_mean`
+///
+/// Corresponds to `boost::math::statistics::one_sample_z_test(v, assumed_mean)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/stats/z_test.html>
+pub fn one_sample_z_test(v: &[f64], assumed_mean: f64) -> TestResult {
+    if v.len() < 2 {
+        return TestResult::NAN;
+    }
+    TestResult::from_ffi(|p| unsafe {
+        ffi::math_one_sample_z_test(v.as_ptr(), v.len(), assumed_mean, p)
+    })
+}
+
+/// Two-sample *z*-test of the null hypothesis that `u` and `v` have the same mean
+///
+/// Corresponds to `boost::math::statistics::two_sample_z_test(u, v)` in C++.
+/// <https://boost.org/doc/libs/latest/libs/math/doc/html/math_toolkit/stats/z_test.html>
+pub fn two_sample_z_test(u: &[f64], v: &[f64]) -> TestResult {
+    if u.len() < 2 || v.len() < 2 {
+        return TestResult::NAN;
+    }
+    TestResult::from_ffi(|p| unsafe {
+        ffi::math_two_sample_z_test(u.as_ptr(), u.len(), v.as_ptr(), v.len(), p)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_one_sample_z_test() {
+        let v = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_eq!(one_sample_z_test(&v, 3.0).statistic, 0.0);
+
+        let below = one_sample_z_test(&v, 2.0);
+        let above = one_sample_z_test(&v, 4.0);
+        assert!(below.statistic > 0.0);
+        assert_relative_eq!(below.statistic, -above.statistic, max_relative = 1e-15);
+        assert!((0.0..=1.0).contains(&below.p_value));
+
+        assert!(one_sample_z_test(&[], 0.0).statistic.is_nan());
+    }
+
+    #[test]
+    fn test_two_sample_z_test() {
+        let u = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let v = [3.0, 4.0, 5.0, 6.0, 7.0];
+        assert_eq!(two_sample_z_test(&u, &u).statistic, 0.0);
+
+        let result = two_sample_z_test(&u, &v);
+        assert!(result.statistic < 0.0);
+        assert!((0.0..=1.0).contains(&result.p_value));
+        assert_relative_eq!(
+            two_sample_z_test(&v, &u).statistic,
+            -result.statistic,
+            max_relative = 1e-15
+        );
+    }
+}
@@ -12,12 +12,72 @@
     #define BOOST_MATH_DENORM_ERROR_POLICY ignore_error
     // #define BOOST_MATH_PROMOTE_FLOAT_POLICY false
     #define BOOST_MATH_PROMOTE_DOUBLE_POLICY false
-    #define BOOST_MATH_ASSERT_UNDEFINED_POLICY true
+    // `detail::distribution` instantiates every property for every distribution, including the
+    // undefined ones (e.g. the mean of the Cauchy distribution), which would otherwise fail to
+    // compile. They return NaN instead, through the domain error policy.
+    #define BOOST_MATH_ASSERT_UNDEFINED_POLICY false
     #define BOOST_MATH_MAX_ROOT_ITERATION_POLICY 400
     #define BOOST_MATH_DISABLE_FLOAT128
 #endif
 
 #include <boost/math/ccmath/sqrt.hpp>
+#include <boost/math/distributions/arcsine.hpp>
+#include <boost/math/distributions/bernoulli.hpp>
+#include <boost/math/distributions/beta.hpp>
+#include <boost/math/distributions/binomial.hpp>
+#include <boost/math/distributions/cauchy.hpp>
+#include <boost/math/distributions/chi_squared.hpp>
+#include <boost/math/distributions/empirical_cumulative_distribution_function.hpp>
+#include <boost/math/distributions/exponential.hpp>
+#include <boost/math/distributions/extreme_value.hpp>
+#include <boost/math/distributions/find_location.hpp>
+#include <boost/math/distributions/find_scale.hpp>
+#include <boost/math/distributions/fisher_f.hpp>
+#include <boost/math/distributions/gamma.hpp>
+#include <boost/math/distributions/geometric.hpp>
+#include <boost/math/distributions/holtsmark.hpp>
+#include <boost/math/distributions/hyperexponential.hpp>
+#include <boost/math/distributions/hypergeometric.hpp>
+#include <boost/math/distributions/inverse_chi_squared.hpp>
+#include <boost/math/distributions/inverse_gamma.hpp>
+#include <boost/math/distributions/inverse_gaussian.hpp>
+#include <boost/math/distributions/kolmogorov_smirnov.hpp>
+#include <boost/math/distributions/landau.hpp>
+#include <boost/math/distributions/laplace.hpp>
+#include <boost/math/distributions/logistic.hpp>
+#include <boost/math/distributions/lognormal.hpp>
+#include <boost/math/distributions/mapairy.hpp>
+#include <boost/math/distributions/negative_binomial.hpp>
+#include <boost/math/distributions/non_central_beta.hpp>
+#include <boost/math/distributions/non_central_chi_squared.hpp>
+#include <boost/math/distributions/non_central_f.hpp>
+#include <boost/math/distributions/non_central_t.hpp>
+#include <boost/math/distributions/normal.hpp>
+#include <boost/math/distributions/pareto.hpp>
+#include <boost/math/distributions/poisson.hpp>
+#include <boost/math/distributions/rayleigh.hpp>
+#include <boost/math/distributions/saspoint5.hpp>
+#include <boost/math/distributions/skew_normal.hpp>
+#include <boost/math/distributions/students_t.hpp>
+#include <boost/math/distributions/triangular.hpp>
+#include <boost/math/distributions/uniform.hpp>
+#include <boost/math/distributions/weibull.hpp>
+#include <boost/math/interpolators/barycentric_rational.hpp>
+#include <boost/math/interpolators/cardinal_cubic_b_spline.hpp>
+#include <boost/math/interpolators/cardinal_quadratic_b_spline.hpp>
+#include <boost/math/interpolators/cardinal_quintic_b_spline.hpp>
+#include <boost/math/optimization/cma_es.hpp>
+#include <boost/math/optimization/differential_evolution.hpp>
+#include <boost/math/optimization/jso.hpp>
+#include <boost/math/optimization/random_search.hpp>
+#include <boost/math/quadrature/exp_sinh.hpp>
+#include <boost/math/quadrature/gauss.hpp>
+#include <boost/math/quadrature/gauss_kronrod.hpp>
+#include <boost/math/quadrature/naive_monte_carlo.hpp>
+#include <boost/math/quadrature/ooura_fourier_integrals.hpp>
+#include <boost/math/quadrature/sinh_sinh.hpp>
+#include <boost/math/quadrature/tanh_sinh.hpp>
+#include <boost/math/quadrature/trapezoidal.hpp>
 #include <boost/math/special_functions/acosh.hpp>
 #include <boost/math/special_functions/airy.hpp>
 #include <boost/math/special_functions/asinh.hpp>
@@ -73,10 +133,31 @@
 #include <boost/math/special_functions/spherical_harmonic.hpp>
 #include <boost/math/special_functions/sqrt1pm1.hpp>
 #include <boost/math/special_functions/zeta.hpp>
+#include <boost/math/statistics/anderson_darling.hpp>
+#include <boost/math/statistics/bivariate_statistics.hpp>
+#include <boost/math/statistics/chatterjee_correlation.hpp>
+#include <boost/math/statistics/linear_regression.hpp>
+#include <boost/math/statistics/ljung_box.hpp>
+#include <boost/math/statistics/runs_test.hpp>
+#include <boost/math/statistics/signal_statistics.hpp>
+#include <boost/math/statistics/t_test.hpp>
+#include <boost/math/statistics/univariate_statistics.hpp>
+#include <boost/math/statistics/z_test.hpp>
 #include <boost/math/tools/complex.hpp>
+#include <boost/math/tools/cubic_roots.hpp>
+#include <boost/math/tools/minima.hpp>
+#include <boost/math/tools/quartic_roots.hpp>
+#include <boost/math/tools/roots.hpp>
+#include <boost/math/tools/toms748_solve.hpp>
+
+#include <atomic>
+#include <random>
+#include <span>
 
 namespace detail {
 
+namespace policies = boost::math::policies;
+
 inline double polygamma(const int n, double x) noexcept {
     // workaround for incorrect boost::math::polygamma values for infinities and NaNs
     if (std::isinf(x)) {
@@ -120,6 +201,279 @@ inline double polygamma(const int n, double x) noexcept {
     return boost::math::polygamma(n, x);
 }
 
+// keep in sync with `Op` in src/math/distributions/mod.rs
+enum class distribution_op : int {
+    pdf,
+    cdf,
+    cdf_complement,
+    quantile,
+    quantile_complement,
+    hazard,
+    chf,
+    mean,
+    variance,
+    standard_deviation,
+    skewness,
+    kurtosis,
+    kurtosis_excess,
+    mode,
+    median,
+    support_lower,
+    support_upper,
+};
+
+template <class Dist>
+double distribution(const Dist& dist, int op, double x) {
+    switch (static_cast<distribution_op>(op)) {
+        case distribution_op::pdf:
+            return pdf(dist, x);
+        case distribution_op::cdf:
+            return cdf(dist, x);
+        case distribution_op::cdf_complement:
+            return cdf(complement(dist, x));
+        case distribution_op::quantile:
+            return quantile(dist, x);
+        case distribution_op::quantile_complement:
+            return quantile(complement(dist, x));
+        case distribution_op::hazard:
+            return hazard(dist, x);
+        case distribution_op::chf:
+            return chf(dist, x);
+        case distribution_op::mean:
+            return mean(dist);
+        case distribution_op::variance:
+            return variance(dist);
+        case distribution_op::standard_deviation:
+            return standard_deviation(dist);
+        case distribution_op::skewness:
+            return skewness(dist);
+        case distribution_op::kurtosis:
+            return kurtosis(dist);
+        case distribution_op::kurtosis_excess:
+            return kurtosis_excess(dist);
+        case distribution_op::mode:
+            return mode(dist);
+        case distribution_op::median:
+            return median(dist);
+        case distribution_op::support_lower:
+            return support(dist).first;
+        case distribution_op::support_upper:
+            return support(dist).second;
+    }
+    return std::numeric_limits<double>::quiet_NaN();
+}
+
+template <template <class, class> class Dist, policies::discrete_quantile_type Q, class... Args>
+double rounded_distribution(int op, double x, Args... args) {
+    using Policy = policies::policy<policies::discrete_quantile<Q>>;
+    return distribution(Dist<double, Policy>(args...), op, x);
+}
+
+// `rounding` is a `policies::discrete_quantile_type`, i.e. `DiscreteQuantile` in Rust
+template <template <class, class> class Dist, class... Args>
+double discrete_distribution(int rounding, int op, double x, Args... args) {
+    switch (static_cast<policies::discrete_quantile_type>(rounding)) {
+        case policies::real:
+            return rounded_distribution<Dist, policies::real>(op, x, args...);
+        case policies::integer_round_outwards:
+            return rounded_distribution<Dist, policies::integer_round_outwards>(op, x, args...);
+        case policies::integer_round_inwards:
+            return rounded_distribution<Dist, policies::integer_round_inwards>(op, x, args...);
+        case policies::integer_round_down:
+            return rounded_distribution<Dist, policies::integer_round_down>(op, x, args...);
+        case policies::integer_round_up:
+            return rounded_distribution<Dist, policies::integer_round_up>(op, x, args...);
+        case policies::integer_round_nearest:
+            return rounded_distribution<Dist, policies::integer_round_nearest>(op, x, args...);
+    }
+    return std::numeric_limits<double>::quiet_NaN();
+}
+
+// keep in sync with `FindOp` in src/math/distributions/mod.rs
+enum class find_op : int {
+    location,
+    location_complement,
+    scale,
+    scale_complement,
+};
+
+template <class Dist>
+double find_location_scale(int op, double z, double p, double param) {
+    using boost::math::complement;
+    switch (static_cast<find_op>(op)) {
+        case find_op::location:
+            return boost::math::find_location<Dist>(z, p, param);
+        case find_op::location_complement:
+            return boost::math::find_location<Dist>(complement(z, p, param));
+        case find_op::scale:
+            return boost::math::find_scale<Dist>(z, p, param);
+        case find_op::scale_complement:
+            return boost::math::find_scale<Dist>(complement(z, p, param));
+    }
+    return std::numeric_limits<double>::quiet_NaN();
+}
+
+// M2M4 SNR estimator for complex signals, which Boost only implements for real signals.
+// Substituting N = M2 - S in M4 = ka S^2 + 4 S N + kw N^2 (Pauluzzi & Beaulieu, 2000) yields a
+// quadratic equation in the signal power S.
+inline double m2m4_snr_estimator(
+    const boost::math::complex<double> v[],
+    size_t len,
+    double signal_kurtosis,
+    double noise_kurtosis
+) {
+    double M2 = 0;
+    double M4 = 0;
+    for (size_t i = 0; i < len; ++i) {
+        double power = std::norm(v[i]);
+        M2 += power;
+        M4 += power * power;
+    }
+    M2 /= len;
+    M4 /= len;
+
+    double a = signal_kurtosis + noise_kurtosis - 4;
+    double b = (4 - 2 * noise_kurtosis) * M2;
+    double c = noise_kurtosis * M2 * M2 - M4;
+    auto [S0, S1] = boost::math::tools::quadratic_roots(a, b, c);
+    // the signal power must lie in [0, M2), so that the noise power is positive, like Boost's
+    // estimator for real signals
+    for (double S : {S1, S0}) {
+        if (S >= 0 && S < M2) {
+            return S / (M2 - S);
+        }
+    }
+    return std::numeric_limits<double>::quiet_NaN();
+}
+
+// real function implemented in Rust, e.g. an integrand, see `Function` in src/ffi.rs
+using function_callback = double (*)(void*, double);
+
+struct function {
+    function_callback f;
+    void* context;
+
+    double operator()(double x) const { return f(context, x); }
+};
+
+// complex-valued integrand implemented in Rust, see `ComplexIntegrand` in src/ffi.rs
+using complex_integrand_callback = void (*)(void*, double, double*, double*);
+
+struct complex_integrand {
+    complex_integrand_callback f;
+    void* context;
+
+    boost::math::complex<double> operator()(double x) const {
+        double re, im;
+        f(context, x, &re, &im);
+        return {re, im};
+    }
+};
+
+// multivariate integrand implemented in Rust, see `MultivariateIntegrand` in src/ffi.rs
+using multivariate_integrand_callback = double (*)(const void*, const double*, size_t);
+
+struct multivariate_integrand {
+    multivariate_integrand_callback f;
+    const void* context;
+
+    double operator()(const std::vector<double>& x) const { return f(context, x.data(), x.size()); }
+};
+
+// function and its first derivative implemented in Rust, see `Derivatives` in src/ffi.rs
+using derivatives_callback = void (*)(void*, double, double*);
+
+struct function_with_derivative {
+    derivatives_callback f;
+    void* context;
+
+    std::pair<double, double> operator()(double x) const {
+        double out[2];
+        f(context, x, out);
+        return {out[0], out[1]};
+    }
+};
+
+// function and its first two derivatives implemented in Rust, see `Derivatives` in src/ffi.rs
+struct function_with_derivatives {
+    derivatives_callback f;
+    void* context;
+
+    std::tuple<double, double, double> operator()(double x) const {
+        double out[3];
+        f(context, x, out);
+        return {out[0], out[1], out[2]};
+    }
+};
+
+using naive_monte_carlo =
+    boost::math::quadrature::naive_monte_carlo<double, multivariate_integrand>;
+
+// objective function implemented in Rust, see `Objective` in src/ffi.rs
+using objective_callback = double (*)(const void*, const double*, size_t);
+
+struct objective {
+    objective_callback f;
+    const void* context;
+
+    double operator()(const std::vector<double>& x) const { return f(context, x.data(), x.size()); }
+};
+
+// the cancellation flag is an `AtomicBool` in Rust, which has the same representation
+static_assert(sizeof(std::atomic<bool>) == sizeof(bool) && std::atomic<bool>::is_always_lock_free);
+
+// the objective function evaluations, as recorded by the optimizers
+using queries = std::vector<std::pair<std::vector<double>, double>>;
+
+// random number generator of the optimizers, randomly seeded if `seed` is 0
+inline std::mt19937_64 random_engine(uint64_t seed) {
+    return std::mt19937_64(seed ? seed : std::random_device{}());
+}
+
+// the hardware concurrency if `threads` is 0, and at least 1
+inline unsigned thread_count(size_t threads) {
+    if (threads == 0) {
+        return std::max(std::thread::hardware_concurrency(), 1u);
+    }
+    return static_cast<unsigned>(threads);
+}
+
+// writes the lowest cost after each generation of `generation_size` queries to `history`, and
+// returns the number of generations
+inline size_t generation_minima(
+    const queries& evaluations,
+    size_t generation_size,
+    double* history,
+    size_t history_len
+) {
+    size_t generations = 0;
+    double best = std::numeric_limits<double>::infinity();
+    for (size_t i = 0; i < evaluations.size() && generations < history_len; ++i) {
+        best = std::min(best, evaluations[i].second);
+        if ((i + 1) % generation_size == 0 || i + 1 == evaluations.size()) {
+            history[generations++] = best;
+        }
+    }
+    return generations;
+}
+
+// calls `op` with `std::integral_constant<unsigned, N>` for the `N` in `Points` that equals
+// `points`, if any
+template <unsigned... Points, class Op>
+void with_points(unsigned points, Op op) {
+    ((points == Points ? op(std::integral_constant<unsigned, Points>{}) : void()), ...);
+}
+
+// returns the first element, and writes the second one (e.g. a p-value) to `second`
+inline double unpack(const std::pair<double, double>& result, double* second) {
+    *second = result.second;
+    return result.first;
+}
+
+// the B-spline interpolators, which are owned by Rust through opaque pointers
+using cardinal_cubic_b_spline = boost::math::interpolators::cardinal_cubic_b_spline<double>;
+using cardinal_quadratic_b_spline = boost::math::interpolators::cardinal_quadratic_b_spline<double>;
+using cardinal_quintic_b_spline = boost::math::interpolators::cardinal_quintic_b_spline<double>;
 } // namespace detail
 
 using namespace boost::math;
@@ -131,6 +485,981 @@ extern "C" {
 double math_ccmath_sqrt(double x) { return ccmath::sqrt(x); }
 }
 
+// distributions
+extern "C" {
+// boost/math/distributions/arcsine.hpp
+double math_arcsine_distribution(double x_min, double x_max, int op, double x) {
+    return ::detail::distribution(arcsine_distribution<double>(x_min, x_max), op, x);
+}
+// boost/math/distributions/bernoulli.hpp
+double math_bernoulli_distribution(double p, int op, double x) {
+    return ::detail::distribution(bernoulli_distribution<double>(p), op, x);
+}
+// boost/math/distributions/beta.hpp
+double math_beta_distribution(double alpha, double beta, int op, double x) {
+    return ::detail::distribution(beta_distribution<double>(alpha, beta), op, x);
+}
+// boost/math/distributions/binomial.hpp
+double math_binomial_distribution(double n, double p, int rounding, int op, double x) {
+    return ::detail::discrete_distribution<binomial_distribution>(rounding, op, x, n, p);
+}
+double math_binomial_find_lower_bound_on_p(
+    double trials,
+    double successes,
+    double alpha,
+    int interval
+) {
+    using dist = binomial_distribution<double>;
+    auto t = static_cast<dist::interval_type>(interval);
+    return dist::find_lower_bound_on_p(trials, successes, alpha, t);
+}
+double math_binomial_find_upper_bound_on_p(
+    double trials,
+    double successes,
+    double alpha,
+    int interval
+) {
+    using dist = binomial_distribution<double>;
+    auto t = static_cast<dist::interval_type>(interval);
+    return dist::find_upper_bound_on_p(trials, successes, alpha, t);
+}
+double math_binomial_find_minimum_number_of_trials(double k, double p, double alpha) {
+    return binomial_distribution<double>::find_minimum_number_of_trials(k, p, alpha);
+}
+// boost/math/distributions/cauchy.hpp
+double math_cauchy_distribution(double location, double scale, int op, double x) {
+    return ::detail::distribution(cauchy_distribution<double>(location, scale), op, x);
+}
+double math_cauchy_find(int op, double z, double p, double param) {
+    return ::detail::find_location_scale<cauchy_distribution<double>>(op, z, p, param);
+}
+// boost/math/distributions/chi_squared.hpp
+double math_chi_squared_distribution(double df, int op, double x) {
+    return ::detail::distribution(chi_squared_distribution<double>(df), op, x);
+}
+double math_chi_squared_find_degrees_of_freedom(
+    double difference_from_variance,
+    double alpha,
+    double beta,
+    double variance
+) {
+    return chi_squared_distribution<double>::find_degrees_of_freedom(
+        difference_from_variance,
+        alpha,
+        beta,
+        variance
+    );
+}
+// boost/math/distributions/empirical_cumulative_distribution_function.hpp
+double math_empirical_cumulative_distribution_function(
+    const double sorted[],
+    size_t len,
+    double x
+) {
+    // `sorted = true` skips the in-place sort, so the samples are never modified
+    auto samples = std::span<double>(const_cast<double*>(sorted), len);
+    using ecdf = empirical_cumulative_distribution_function<std::span<double>>;
+    return ecdf(std::move(samples), true)(x);
+}
+// boost/math/distributions/exponential.hpp
+double math_exponential_distribution(double lambda, int op, double x) {
+    return ::detail::distribution(exponential_distribution<double>(lambda), op, x);
+}
+// boost/math/distributions/extreme_value.hpp
+double math_extreme_value_distribution(double location, double scale, int op, double x) {
+    return ::detail::distribution(extreme_value_distribution<double>(location, scale), op, x);
+}
+double math_extreme_value_find(int op, double z, double p, double param) {
+    return ::detail::find_location_scale<extreme_value_distribution<double>>(op, z, p, param);
+}
+// boost/math/distributions/fisher_f.hpp
+double math_fisher_f_distribution(double df1, double df2, int op, double x) {
+    return ::detail::distribution(fisher_f_distribution<double>(df1, df2), op, x);
+}
+// boost/math/distributions/gamma.hpp
+double math_gamma_distribution(double shape, double scale, int op, double x) {
+    return ::detail::distribution(gamma_distribution<double>(shape, scale), op, x);
+}
+// boost/math/distributions/geometric.hpp
+double math_geometric_distribution(double p, int rounding, int op, double x) {
+    return ::detail::discrete_distribution<geometric_distribution>(rounding, op, x, p);
+}
+// boost/math/distributions/holtsmark.hpp
+double math_holtsmark_distribution(double location, double scale, int op, double x) {
+    return ::detail::distribution(holtsmark_distribution<double>(location, scale), op, x);
+}
+// boost/math/distributions/hyperexponential.hpp
+double math_hyperexponential_distribution(
+    const double probabilities[],
+    const double rates[],
+    size_t len,
+    int op,
+    double x
+) {
+    auto dist = hyperexponential_distribution<double>(
+        probabilities,
+        probabilities + len,
+        rates,
+        rates + len
+    );
+    return ::detail::distribution(dist, op, x);
+}
+// boost/math/distributions/hypergeometric.hpp
+double math_hypergeometric_distribution(
+    unsigned r,
+    unsigned n,
+    unsigned total,
+    int rounding,
+    int op,
+    double x
+) {
+    return ::detail::discrete_distribution<hypergeometric_distribution>(
+        rounding,
+        op,
+        x,
+        r,
+        n,
+        total
+    );
+}
+// boost/math/distributions/inverse_chi_squared.hpp
+double math_inverse_chi_squared_distribution(double df, double scale, int op, double x) {
+    return ::detail::distribution(inverse_chi_squared_distribution<double>(df, scale), op, x);
+}
+// boost/math/distributions/inverse_gamma.hpp
+double math_inverse_gamma_distribution(double shape, double scale, int op, double x) {
+    return ::detail::distribution(inverse_gamma_distribution<double>(shape, scale), op, x);
+}
+// boost/math/distributions/inverse_gaussian.hpp
+double math_inverse_gaussian_distribution(double mean, double scale, int op, double x) {
+    return ::detail::distribution(inverse_gaussian_distribution<double>(mean, scale), op, x);
+}
+// boost/math/distributions/kolmogorov_smirnov.hpp
+double math_kolmogorov_smirnov_distribution(double n, int op, double x) {
+    return ::detail::distribution(kolmogorov_smirnov_distribution<double>(n), op, x);
+}
+// boost/math/distributions/landau.hpp
+double math_landau_distribution(double location, double scale, int op, double x) {
+    return ::detail::distribution(landau_distribution<double>(location, scale), op, x);
+}
+// boost/math/distributions/laplace.hpp
+double math_laplace_distribution(double location, double scale, int op, double x) {
+    return ::detail::distribution(laplace_distribution<double>(location, scale), op, x);
+}
+double math_laplace_find(int op, double z, double p, double param) {
+    return ::detail::find_location_scale<laplace_distribution<double>>(op, z, p, param);
+}
+// boost/math/distributions/logistic.hpp
+double math_logistic_distribution(double location, double scale, int op, double x) {
+    return ::detail::distribution(logistic_distribution<double>(location, scale), op, x);
+}
+double math_logistic_find(int op, double z, double p, double param) {
+    return ::detail::find_location_scale<logistic_distribution<double>>(op, z, p, param);
+}
+// boost/math/distributions/lognormal.hpp
+double math_lognormal_distribution(double location, double scale, int op, double x) {
+    return ::detail::distribution(lognormal_distribution<double>(location, scale), op, x);
+}
+// boost/math/distributions/mapairy.hpp
+double math_mapairy_distribution(double location, double scale, int op, double x) {
+    return ::detail::distribution(mapairy_distribution<double>(location, scale), op, x);
+}
+// boost/math/distributions/negative_binomial.hpp
+double math_negative_binomial_distribution(double r, double p, int rounding, int op, double x) {
+    return ::detail::discrete_distribution<negative_binomial_distribution>(rounding, op, x, r, p);
+}
+double math_negative_binomial_find_minimum_number_of_trials(double k, double p, double alpha) {
+    return negative_binomial_distribution<double>::find_minimum_number_of_trials(k, p, alpha);
+}
+// boost/math/distributions/non_central_beta.hpp
+double math_non_central_beta_distribution(
+    double alpha,
+    double beta,
+    double lambda,
+    int op,
+    double x
+) {
+    auto dist = non_central_beta_distribution<double>(alpha, beta, lambda);
+    return ::detail::distribution(dist, op, x);
+}
+// boost/math/distributions/non_central_chi_squared.hpp
+double math_non_central_chi_squared_distribution(double df, double lambda, int op, double x) {
+    return ::detail::distribution(non_central_chi_squared_distribution<double>(df, lambda), op, x);
+}
+double math_non_central_chi_squared_find_degrees_of_freedom(double lambda, double x, double p) {
+    return non_central_chi_squared_distribution<double>::find_degrees_of_freedom(lambda, x, p);
+}
+double math_non_central_chi_squared_find_degrees_of_freedom_c(double lambda, double x, double q) {
+    return non_central_chi_squared_distribution<double>::find_degrees_of_freedom(
+        complement(lambda, x, q)
+    );
+}
+double math_non_central_chi_squared_find_non_centrality(double df, double x, double p) {
+    return non_central_chi_squared_distribution<double>::find_non_centrality(df, x, p);
+}
+double math_non_central_chi_squared_find_non_centrality_c(double df, double x, double q) {
+    return non_central_chi_squared_distribution<double>::find_non_centrality(complement(df, x, q));
+}
+// boost/math/distributions/non_central_f.hpp
+double math_non_central_f_distribution(double df1, double df2, double lambda, int op, double x) {
+    return ::detail::distribution(non_central_f_distribution<double>(df1, df2, lambda), op, x);
+}
+// boost/math/distributions/non_central_t.hpp
+double math_non_central_t_distribution(double df, double delta, int op, double x) {
+    return ::detail::distribution(non_central_t_distribution<double>(df, delta), op, x);
+}
+// boost/math/distributions/normal.hpp
+double math_normal_distribution(double mean, double sd, int op, double x) {
+    return ::detail::distribution(normal_distribution<double>(mean, sd), op, x);
+}
+double math_normal_find(int op, double z, double p, double param) {
+    return ::detail::find_location_scale<normal_distribution<double>>(op, z, p, param);
+}
+// boost/math/distributions/pareto.hpp
+double math_pareto_distribution(double scale, double shape, int op, double x) {
+    return ::detail::distribution(pareto_distribution<double>(scale, shape), op, x);
+}
+// boost/math/distributions/poisson.hpp
+double math_poisson_distribution(double lambda, int rounding, int op, double x) {
+    return ::detail::discrete_distribution<poisson_distribution>(rounding, op, x, lambda);
+}
+// boost/math/distributions/rayleigh.hpp
+double math_rayleigh_distribution(double sigma, int op, double x) {
+    return ::detail::distribution(rayleigh_distribution<double>(sigma), op, x);
+}
+// boost/math/distributions/saspoint5.hpp
+double math_saspoint5_distribution(double location, double scale, int op, double x) {
+    return ::detail::distribution(saspoint5_distribution<double>(location, scale), op, x);
+}
+// boost/math/distributions/skew_normal.hpp
+double math_skew_normal_distribution(
+    double location,
+    double scale,
+    double shape,
+    int op,
+    double x
+) {
+    return ::detail::distribution(skew_normal_distribution<double>(location, scale, shape), op, x);
+}
+// boost/math/distributions/students_t.hpp
+double math_students_t_distribution(double df, int op, double x) {
+    return ::detail::distribution(students_t_distribution<double>(df), op, x);
+}
+double math_students_t_find_degrees_of_freedom(
+    double difference_from_mean,
+    double alpha,
+    double beta,
+    double sd
+) {
+    return students_t_distribution<double>::find_degrees_of_freedom(
+        difference_from_mean,
+        alpha,
+        beta,
+        sd
+    );
+}
+// boost/math/distributions/triangular.hpp
+double math_triangular_distribution(double lower, double mode, double upper, int op, double x) {
+    return ::detail::distribution(triangular_distribution<double>(lower, mode, upper), op, x);
+}
+// boost/math/distributions/uniform.hpp
+double math_uniform_distribution(double lower, double upper, int op, double x) {
+    return ::detail::distribution(uniform_distribution<double>(lower, upper), op, x);
+}
+// boost/math/distributions/weibull.hpp
+double math_weibull_distribution(double shape, double scale, int op, double x) {
+    return ::detail::distribution(weibull_distribution<double>(shape, scale), op, x);
+}
+} // extern "C"
+
+// interpolators
+extern "C" {
+// boost/math/interpolators/barycentric_rational.hpp
+void* math_barycentric_rational_new(const double x[], const double y[], size_t n, size_t order) {
+    return new interpolators::barycentric_rational<double>(x, y, n, order);
+}
+void math_barycentric_rational_delete(void* interpolator) {
+    delete static_cast<interpolators::barycentric_rational<double>*>(interpolator);
+}
+double math_barycentric_rational(const void* interpolator, double x) {
+    return (*static_cast<const interpolators::barycentric_rational<double>*>(interpolator))(x);
+}
+double math_barycentric_rational_prime(const void* interpolator, double x) {
+    return static_cast<const interpolators::barycentric_rational<double>*>(interpolator)->prime(x);
+}
+// boost/math/interpolators/cardinal_cubic_b_spline.hpp
+void* math_cardinal_cubic_b_spline_new(
+    const double y[],
+    size_t n,
+    double t0,
+    double h,
+    double left_derivative,
+    double right_derivative
+) {
+    return new ::detail::cardinal_cubic_b_spline(y, n, t0, h, left_derivative, right_derivative);
+}
+void* math_cardinal_cubic_b_spline_clone(const void* interpolator) {
+    auto& spline = *static_cast<const ::detail::cardinal_cubic_b_spline*>(interpolator);
+    return new ::detail::cardinal_cubic_b_spline(spline);
+}
+void math_cardinal_cubic_b_spline_delete(void* interpolator) {
+    delete static_cast<::detail::cardinal_cubic_b_spline*>(interpolator);
+}
+double math_cardinal_cubic_b_spline(const void* interpolator, double t) {
+    return (*static_cast<const ::detail::cardinal_cubic_b_spline*>(interpolator))(t);
+}
+double math_cardinal_cubic_b_spline_prime(const void* interpolator, double t) {
+    return static_cast<const ::detail::cardinal_cubic_b_spline*>(interpolator)->prime(t);
+}
+double math_cardinal_cubic_b_spline_double_prime(const void* interpolator, double t) {
+    return static_cast<const ::detail::cardinal_cubic_b_spline*>(interpolator)->double_prime(t);
+}
+// boost/math/interpolators/cardinal_quadratic_b_spline.hpp
+void* math_cardinal_quadratic_b_spline_new(
+    const double y[],
+    size_t n,
+    double t0,
+    double h,
+    double left_derivative,
+    double right_derivative
+) {
+    return new ::detail::cardinal_quadratic_b_spline(
+        y,
+        n,
+        t0,
+        h,
+        left_derivative,
+        right_derivative
+    );
+}
+void* math_cardinal_quadratic_b_spline_clone(const void* interpolator) {
+    auto& spline = *static_cast<const ::detail::cardinal_quadratic_b_spline*>(interpolator);
+    return new ::detail::cardinal_quadratic_b_spline(spline);
+}
+void math_cardinal_quadratic_b_spline_delete(void* interpolator) {
+    delete static_cast<::detail::cardinal_quadratic_b_spline*>(interpolator);
+}
+double math_cardinal_quadratic_b_spline(const void* interpolator, double t) {
+    return (*static_cast<const ::detail::cardinal_quadratic_b_spline*>(interpolator))(t);
+}
+double math_cardinal_quadratic_b_spline_prime(const void* interpolator, double t) {
+    return static_cast<const ::detail::cardinal_quadratic_b_spline*>(interpolator)->prime(t);
+}
+// boost/math/interpolators/cardinal_quintic_b_spline.hpp
+void* math_cardinal_quintic_b_spline_new(
+    const double y[],
+    size_t n,
+    double t0,
+    double h,
+    double left_prime,
+    double left_double_prime,
+    double right_prime,
+    double right_double_prime
+) {
+    return new ::detail::cardinal_quintic_b_spline(
+        y,
+        n,
+        t0,
+        h,
+        std::pair{left_prime, left_double_prime},
+        std::pair{right_prime, right_double_prime}
+    );
+}
+void* math_cardinal_quintic_b_spline_clone(const void* interpolator) {
+    auto& spline = *static_cast<const ::detail::cardinal_quintic_b_spline*>(interpolator);
+    return new ::detail::cardinal_quintic_b_spline(spline);
+}
+void math_cardinal_quintic_b_spline_delete(void* interpolator) {
+    delete static_cast<::detail::cardinal_quintic_b_spline*>(interpolator);
+}
+double math_cardinal_quintic_b_spline(const void* interpolator, double t) {
+    return (*static_cast<const ::detail::cardinal_quintic_b_spline*>(interpolator))(t);
+}
+double math_cardinal_quintic_b_spline_prime(const void* interpolator, double t) {
+    return static_cast<const ::detail::cardinal_quintic_b_spline*>(interpolator)->prime(t);
+}
+double math_cardinal_quintic_b_spline_double_prime(const void* interpolator, double t) {
+    return static_cast<const ::detail::cardinal_quintic_b_spline*>(interpolator)->double_prime(t);
+}
+} // extern "C"
+
+// optimization
+extern "C" {
+// boost/math/optimization/cma_es.hpp
+size_t math_cma_es(
+    ::detail::objective_callback f,
+    const void* context,
+    const double lower[],
+    const double upper[],
+    size_t dimension,
+    size_t population_size,
+    size_t max_generations,
+    double learning_rate,
+    uint64_t seed,
+    double target_value,
+    std::atomic<bool>* cancellation,
+    double* x,
+    double* value,
+    double* history,
+    size_t history_len
+) {
+    optimization::cma_es_parameters<std::vector<double>> params;
+    params.lower_bounds.assign(lower, lower + dimension);
+    params.upper_bounds.assign(upper, upper + dimension);
+    params.max_generations = max_generations;
+    // the default of the reference, so that the generation size is known
+    params.population_size =
+        population_size ? population_size : static_cast<size_t>(4 + 3 * std::log(dimension));
+    params.learning_rate = learning_rate;
+    auto gen = ::detail::random_engine(seed);
+    auto queries = ::detail::queries{};
+    std::atomic<double> cost{std::numeric_limits<double>::infinity()};
+    auto best = optimization::cma_es(
+        ::detail::objective{f, context},
+        params,
+        gen,
+        target_value,
+        cancellation,
+        &cost,
+        history_len ? &queries : nullptr
+    );
+    std::copy(best.begin(), best.end(), x);
+    *value = cost;
+    return ::detail::generation_minima(queries, params.population_size, history, history_len);
+}
+// boost/math/optimization/differential_evolution.hpp
+size_t math_differential_evolution(
+    ::detail::objective_callback f,
+    const void* context,
+    const double lower[],
+    const double upper[],
+    size_t dimension,
+    double mutation_factor,
+    double crossover_probability,
+    size_t population_size,
+    size_t max_generations,
+    size_t threads,
+    uint64_t seed,
+    double target_value,
+    std::atomic<bool>* cancellation,
+    double* x,
+    double* value,
+    double* history,
+    size_t history_len
+) {
+    optimization::differential_evolution_parameters<std::vector<double>> params;
+    params.lower_bounds.assign(lower, lower + dimension);
+    params.upper_bounds.assign(upper, upper + dimension);
+    params.mutation_factor = mutation_factor;
+    params.crossover_probability = crossover_probability;
+    params.NP = population_size;
+    params.max_generations = max_generations;
+    params.threads = ::detail::thread_count(threads);
+    auto gen = ::detail::random_engine(seed);
+    auto queries = ::detail::queries{};
+    std::atomic<double> cost{std::numeric_limits<double>::infinity()};
+    auto best = optimization::differential_evolution(
+        ::detail::objective{f, context},
+        params,
+        gen,
+        target_value,
+        cancellation,
+        history_len ? &queries : nullptr,
+        &cost
+    );
+    std::copy(best.begin(), best.end(), x);
+    *value = cost;
+    return ::detail::generation_minima(queries, population_size, history, history_len);
+}
+// boost/math/optimization/jso.hpp
+void math_jso(
+    ::detail::objective_callback f,
+    const void* context,
+    const double lower[],
+    const double upper[],
+    size_t dimension,
+    size_t initial_population_size,
+    size_t max_function_evaluations,
+    size_t threads,
+    uint64_t seed,
+    double target_value,
+    std::atomic<bool>* cancellation,
+    double* x,
+    double* value
+) {
+    optimization::jso_parameters<std::vector<double>> params;
+    params.lower_bounds.assign(lower, lower + dimension);
+    params.upper_bounds.assign(upper, upper + dimension);
+    // the defaults of the reference
+    auto d = static_cast<double>(dimension);
+    params.initial_population_size = initial_population_size
+        ? initial_population_size
+        : static_cast<size_t>(std::ceil(25 * std::log(d + 1) * std::sqrt(d)));
+    params.max_function_evaluations =
+        max_function_evaluations ? max_function_evaluations : 10000 * dimension;
+    params.threads = ::detail::thread_count(threads);
+    auto gen = ::detail::random_engine(seed);
+    auto objective = ::detail::objective{f, context};
+    std::atomic<double> cost{std::numeric_limits<double>::infinity()};
+    auto best = optimization::jso(objective, params, gen, target_value, cancellation, &cost);
+    std::copy(best.begin(), best.end(), x);
+    *value = cost;
+}
+// boost/math/optimization/random_search.hpp
+void math_random_search(
+    ::detail::objective_callback f,
+    const void* context,
+    const double lower[],
+    const double upper[],
+    size_t dimension,
+    size_t max_function_calls,
+    size_t threads,
+    uint64_t seed,
+    double target_value,
+    std::atomic<bool>* cancellation,
+    double* x,
+    double* value
+) {
+    optimization::random_search_parameters<std::vector<double>> params;
+    params.lower_bounds.assign(lower, lower + dimension);
+    params.upper_bounds.assign(upper, upper + dimension);
+    params.max_function_calls = max_function_calls ? max_function_calls : 10000 * dimension;
+    params.threads = ::detail::thread_count(threads);
+    auto gen = ::detail::random_engine(seed);
+    auto objective = ::detail::objective{f, context};
+    std::atomic<double> cost{std::numeric_limits<double>::infinity()};
+    auto best =
+        optimization::random_search(objective, params, gen, target_value, cancellation, &cost);
+    std::copy(best.begin(), best.end(), x);
+    *value = cost;
+}
+} // extern "C"
+
+// quadrature
+extern "C" {
+// boost/math/quadrature/exp_sinh.hpp
+void* math_exp_sinh_new(size_t max_refinements) {
+    return new quadrature::exp_sinh<double>(max_refinements);
+}
+void math_exp_sinh_delete(void* integrator) {
+    delete static_cast<quadrature::exp_sinh<double>*>(integrator);
+}
+double math_exp_sinh(
+    void* integrator,
+    ::detail::function_callback f,
+    void* context,
+    double a,
+    double b,
+    double tolerance,
+    double* error,
+    double* L1,
+    size_t* levels
+) {
+    auto& exp_sinh = *static_cast<quadrature::exp_sinh<double>*>(integrator);
+    auto integrand = ::detail::function{f, context};
+    return exp_sinh.integrate(integrand, a, b, tolerance, error, L1, levels);
+}
+void math_exp_sinh_complex(
+    void* integrator,
+    ::detail::complex_integrand_callback f,
+    void* context,
+    double a,
+    double b,
+    double tolerance,
+    double* out_re,
+    double* out_im,
+    double* error,
+    double* L1,
+    size_t* levels
+) {
+    auto& exp_sinh = *static_cast<quadrature::exp_sinh<double>*>(integrator);
+    auto integrand = ::detail::complex_integrand{f, context};
+    cdouble out = exp_sinh.integrate(integrand, a, b, tolerance, error, L1, levels);
+    *out_re = out.real();
+    *out_im = out.imag();
+}
+// boost/math/quadrature/gauss.hpp
+double math_gauss(
+    unsigned points,
+    ::detail::function_callback f,
+    void* context,
+    double a,
+    double b,
+    double* L1
+) {
+    auto integrand = ::detail::function{f, context};
+    double result = std::numeric_limits<double>::quiet_NaN();
+    ::detail::with_points<7, 10, 15, 20, 25, 30>(points, [&](auto n) {
+        result = quadrature::gauss<double, decltype(n)::value>::integrate(integrand, a, b, L1);
+    });
+    return result;
+}
+const double* math_gauss_abscissa(unsigned points) {
+    const double* abscissa = nullptr;
+    ::detail::with_points<7, 10, 15, 20, 25, 30>(points, [&](auto n) {
+        abscissa = quadrature::gauss<double, decltype(n)::value>::abscissa().data();
+    });
+    return abscissa;
+}
+const double* math_gauss_weights(unsigned points) {
+    const double* weights = nullptr;
+    ::detail::with_points<7, 10, 15, 20, 25, 30>(points, [&](auto n) {
+        weights = quadrature::gauss<double, decltype(n)::value>::weights().data();
+    });
+    return weights;
+}
+// boost/math/quadrature/gauss_kronrod.hpp
+double math_gauss_kronrod(
+    unsigned points,
+    ::detail::function_callback f,
+    void* context,
+    double a,
+    double b,
+    unsigned max_depth,
+    double tolerance,
+    double* error,
+    double* L1
+) {
+    auto integrand = ::detail::function{f, context};
+    double result = std::numeric_limits<double>::quiet_NaN();
+    ::detail::with_points<15, 21, 31, 41, 51, 61>(points, [&](auto n) {
+        using rule = quadrature::gauss_kronrod<double, decltype(n)::value>;
+        result = rule::integrate(integrand, a, b, max_depth, tolerance, error, L1);
+    });
+    return result;
+}
+const double* math_gauss_kronrod_abscissa(unsigned points) {
+    const double* abscissa = nullptr;
+    ::detail::with_points<15, 21, 31, 41, 51, 61>(points, [&](auto n) {
+        abscissa = quadrature::gauss_kronrod<double, decltype(n)::value>::abscissa().data();
+    });
+    return abscissa;
+}
+const double* math_gauss_kronrod_weights(unsigned points) {
+    const double* weights = nullptr;
+    ::detail::with_points<15, 21, 31, 41, 51, 61>(points, [&](auto n) {
+        weights = quadrature::gauss_kronrod<double, decltype(n)::value>::weights().data();
+    });
+    return weights;
+}
+const double* math_gauss_kronrod_gauss_weights(unsigned points) {
+    const double* weights = nullptr;
+    ::detail::with_points<15, 21, 31, 41, 51, 61>(points, [&](auto n) {
+        weights = quadrature::gauss_kronrod<double, decltype(n)::value>::gauss_weights().data();
+    });
+    return weights;
+}
+// boost/math/quadrature/naive_monte_carlo.hpp
+void* math_naive_monte_carlo_new(
+    ::detail::multivariate_integrand_callback f,
+    const void* context,
+    const double lower[],
+    const double upper[],
+    size_t dimension,
+    double error_goal,
+    size_t threads,
+    uint64_t seed
+) {
+    std::vector<std::pair<double, double>> bounds(dimension);
+    for (size_t i = 0; i < dimension; ++i) {
+        bounds[i] = {lower[i], upper[i]};
+    }
+    if (threads == 0) {
+        threads = std::max(std::thread::hardware_concurrency(), 1u);
+    }
+    auto integrand = ::detail::multivariate_integrand{f, context};
+    return new ::detail::naive_monte_carlo(integrand, bounds, error_goal, true, threads, seed);
+}
+void math_naive_monte_carlo_delete(void* integrator) {
+    delete static_cast<::detail::naive_monte_carlo*>(integrator);
+}
+double math_naive_monte_carlo_integrate(void* integrator) {
+    return static_cast<::detail::naive_monte_carlo*>(integrator)->integrate().get();
+}
+void math_naive_monte_carlo_cancel(void* integrator) {
+    static_cast<::detail::naive_monte_carlo*>(integrator)->cancel();
+}
+void math_naive_monte_carlo_update_target_error(void* integrator, double error) {
+    static_cast<::detail::naive_monte_carlo*>(integrator)->update_target_error(error);
+}
+double math_naive_monte_carlo_current_estimate(void* integrator) {
+    return static_cast<::detail::naive_monte_carlo*>(integrator)->current_estimate();
+}
+double math_naive_monte_carlo_current_error_estimate(void* integrator) {
+    return static_cast<::detail::naive_monte_carlo*>(integrator)->current_error_estimate();
+}
+double math_naive_monte_carlo_progress(void* integrator) {
+    return static_cast<::detail::naive_monte_carlo*>(integrator)->progress();
+}
+uint64_t math_naive_monte_carlo_calls(void* integrator) {
+    return static_cast<::detail::naive_monte_carlo*>(integrator)->calls();
+}
+// boost/math/quadrature/ooura_fourier_integrals.hpp
+void* math_ooura_fourier_sin_new(double tolerance, size_t levels) {
+    return new quadrature::ooura_fourier_sin<double>(tolerance, levels);
+}
+void math_ooura_fourier_sin_delete(void* integrator) {
+    delete static_cast<quadrature::ooura_fourier_sin<double>*>(integrator);
+}
+double math_ooura_fourier_sin(
+    void* integrator,
+    ::detail::function_callback f,
+    void* context,
+    double omega,
+    double* error
+) {
+    auto& ooura = *static_cast<quadrature::ooura_fourier_sin<double>*>(integrator);
+    return ::detail::unpack(ooura.integrate(::detail::function{f, context}, omega), error);
+}
+void* math_ooura_fourier_cos_new(double tolerance, size_t levels) {
+    return new quadrature::ooura_fourier_cos<double>(tolerance, levels);
+}
+void math_ooura_fourier_cos_delete(void* integrator) {
+    delete static_cast<quadrature::ooura_fourier_cos<double>*>(integrator);
+}
+double math_ooura_fourier_cos(
+    void* integrator,
+    ::detail::function_callback f,
+    void* context,
+    double omega,
+    double* error
+) {
+    auto& ooura = *static_cast<quadrature::ooura_fourier_cos<double>*>(integrator);
+    return ::detail::unpack(ooura.integrate(::detail::function{f, context}, omega), error);
+}
+// boost/math/quadrature/sinh_sinh.hpp
+void* math_sinh_sinh_new(size_t max_refinements) {
+    return new quadrature::sinh_sinh<double>(max_refinements);
+}
+void math_sinh_sinh_delete(void* integrator) {
+    delete static_cast<quadrature::sinh_sinh<double>*>(integrator);
+}
+double math_sinh_sinh(
+    void* integrator,
+    ::detail::function_callback f,
+    void* context,
+    double tolerance,
+    double* error,
+    double* L1,
+    size_t* levels
+) {
+    auto& sinh_sinh = *static_cast<quadrature::sinh_sinh<double>*>(integrator);
+    auto integrand = ::detail::function{f, context};
+    return sinh_sinh.integrate(integrand, tolerance, error, L1, levels);
+}
+void math_sinh_sinh_complex(
+    void* integrator,
+    ::detail::complex_integrand_callback f,
+    void* context,
+    double tolerance,
+    double* out_re,
+    double* out_im,
+    double* error,
+    double* L1,
+    size_t* levels
+) {
+    auto& sinh_sinh = *static_cast<quadrature::sinh_sinh<double>*>(integrator);
+    auto integrand = ::detail::complex_integrand{f, context};
+    cdouble out = sinh_sinh.integrate(integrand, tolerance, error, L1, levels);
+    *out_re = out.real();
+    *out_im = out.imag();
+}
+// boost/math/quadrature/tanh_sinh.hpp
+void* math_tanh_sinh_new(size_t max_refinements) {
+    return new quadrature::tanh_sinh<double>(max_refinements);
+}
+void math_tanh_sinh_delete(void* integrator) {
+    delete static_cast<quadrature::tanh_sinh<double>*>(integrator);
+}
+double math_tanh_sinh(
+    void* integrator,
+    ::detail::function_callback f,
+    void* context,
+    double a,
+    double b,
+    double tolerance,
+    double* error,
+    double* L1,
+    size_t* levels
+) {
+    auto& tanh_sinh = *static_cast<quadrature::tanh_sinh<double>*>(integrator);
+    auto integrand = ::detail::function{f, context};
+    return tanh_sinh.integrate(integrand, a, b, tolerance, error, L1, levels);
+}
+void math_tanh_sinh_complex(
+    void* integrator,
+    ::detail::complex_integrand_callback f,
+    void* context,
+    double a,
+    double b,
+    double tolerance,
+    double* out_re,
+    double* out_im,
+    double* error,
+    double* L1,
+    size_t* levels
+) {
+    auto& tanh_sinh = *static_cast<quadrature::tanh_sinh<double>*>(integrator);
+    auto integrand = ::detail::complex_integrand{f, context};
+    cdouble out = tanh_sinh.integrate(integrand, a, b, tolerance, error, L1, levels);
+    *out_re = out.real();
+    *out_im = out.imag();
+}
+// boost/math/quadrature/trapezoidal.hpp
+double math_trapezoidal(
+    ::detail::function_callback f,
+    void* context,
+    double a,
+    double b,
+    double tolerance,
+    size_t max_refinements,
+    double* error,
+    double* L1
+) {
+    auto integrand = ::detail::function{f, context};
+    return quadrature::trapezoidal(integrand, a, b, tolerance, max_refinements, error, L1);
+}
+} // extern "C"
+
+// roots
+extern "C" {
+// boost/math/tools/cubic_roots.hpp
+void math_cubic_roots(double a, double b, double c, double d, double* roots) {
+    auto out = tools::cubic_roots(a, b, c, d);
+    std::copy(out.begin(), out.end(), roots);
+}
+double math_cubic_root_residual(
+    double a,
+    double b,
+    double c,
+    double d,
+    double root,
+    double* bound
+) {
+    auto [residual, expected] = tools::cubic_root_residual(a, b, c, d, root);
+    *bound = expected;
+    return residual;
+}
+double math_cubic_root_condition_number(double a, double b, double c, double d, double root) {
+    return tools::cubic_root_condition_number(a, b, c, d, root);
+}
+// boost/math/tools/minima.hpp
+double math_brent_find_minima(
+    ::detail::function_callback f,
+    void* context,
+    double min,
+    double max,
+    int bits,
+    size_t* max_iter,
+    double* value
+) {
+    std::uintmax_t iterations = *max_iter;
+    auto function = ::detail::function{f, context};
+    auto result = tools::brent_find_minima(function, min, max, bits, iterations);
+    *max_iter = iterations;
+    return ::detail::unpack(result, value);
+}
+// boost/math/tools/quartic_roots.hpp
+void math_quartic_roots(double a, double b, double c, double d, double e, double* roots) {
+    auto out = tools::quartic_roots(a, b, c, d, e);
+    std::copy(out.begin(), out.end(), roots);
+}
+// boost/math/tools/roots.hpp
+double math_bisect(
+    ::detail::function_callback f,
+    void* context,
+    double min,
+    double max,
+    unsigned bits,
+    size_t* max_iter,
+    double* upper
+) {
+    std::uintmax_t iterations = *max_iter;
+    auto tol = tools::eps_tolerance<double>(bits);
+    auto result = tools::bisect(::detail::function{f, context}, min, max, tol, iterations);
+    *max_iter = iterations;
+    return ::detail::unpack(result, upper);
+}
+double math_newton_raphson_iterate(
+    ::detail::derivatives_callback f,
+    void* context,
+    double guess,
+    double min,
+    double max,
+    int digits,
+    size_t* max_iter
+) {
+    std::uintmax_t iterations = *max_iter;
+    auto function = ::detail::function_with_derivative{f, context};
+    double result = tools::newton_raphson_iterate(function, guess, min, max, digits, iterations);
+    *max_iter = iterations;
+    return result;
+}
+double math_halley_iterate(
+    ::detail::derivatives_callback f,
+    void* context,
+    double guess,
+    double min,
+    double max,
+    int digits,
+    size_t* max_iter
+) {
+    std::uintmax_t iterations = *max_iter;
+    auto function = ::detail::function_with_derivatives{f, context};
+    double result = tools::halley_iterate(function, guess, min, max, digits, iterations);
+    *max_iter = iterations;
+    return result;
+}
+double math_schroder_iterate(
+    ::detail::derivatives_callback f,
+    void* context,
+    double guess,
+    double min,
+    double max,
+    int digits,
+    size_t* max_iter
+) {
+    std::uintmax_t iterations = *max_iter;
+    auto function = ::detail::function_with_derivatives{f, context};
+    double result = tools::schroder_iterate(function, guess, min, max, digits, iterations);
+    *max_iter = iterations;
+    return result;
+}
+// boost/math/tools/toms748_solve.hpp
+double math_toms748_solve(
+    ::detail::function_callback f,
+    void* context,
+    double a,
+    double b,
+    unsigned bits,
+    size_t* max_iter,
+    double* upper
+) {
+    std::uintmax_t iterations = *max_iter;
+    auto tol = tools::eps_tolerance<double>(bits);
+    auto result = tools::toms748_solve(::detail::function{f, context}, a, b, tol, iterations);
+    *max_iter = iterations;
+    return ::detail::unpack(result, upper);
+}
+double math_bracket_and_solve_root(
+    ::detail::function_callback f,
+    void* context,
+    double guess,
+    double factor,
+    bool rising,
+    unsigned bits,
+    size_t* max_iter,
+    double* upper
+) {
+    std::uintmax_t iterations = *max_iter;
+    auto tol = tools::eps_tolerance<double>(bits);
+    auto function = ::detail::function{f, context};
+    auto result = tools::bracket_and_solve_root(function, guess, factor, rising, tol, iterations);
+    *max_iter = iterations;
+    return ::detail::unpack(result, upper);
+}
+} // extern "C"
+
 // special_functions
 extern "C" {
 // boost/math/special_functions/acosh.hpp
@@ -436,3 +1765,251 @@ double math_sqrt1pm1(double x) { return sqrt1pm1(x); }
 double math_zeta(double s) { return zeta(s); }
 
 } // extern "C"
+
+// statistics
+extern "C" {
+// boost/math/statistics/anderson_darling.hpp
+double math_anderson_darling_normality_statistic(
+    const double sorted[],
+    size_t len,
+    double mu,
+    double sd
+) {
+    return statistics::anderson_darling_normality_statistic(std::span(sorted, len), mu, sd);
+}
+
+// boost/math/statistics/bivariate_statistics.hpp
+double math_covariance(const double u[], const double v[], size_t len) {
+    return statistics::covariance(std::span(u, len), std::span(v, len));
+}
+double math_means_and_covariance(
+    const double u[],
+    const double v[],
+    size_t len,
+    double* mu_u,
+    double* mu_v
+) {
+    auto [mean_u, mean_v, cov] = statistics::means_and_covariance(
+        std::span(u, len),
+        std::span(v, len)
+    );
+    *mu_u = mean_u;
+    *mu_v = mean_v;
+    return cov;
+}
+double math_correlation_coefficient(const double u[], const double v[], size_t len) {
+    return statistics::correlation_coefficient(std::span(u, len), std::span(v, len));
+}
+
+// boost/math/statistics/chatterjee_correlation.hpp
+double math_chatterjee_correlation(const double sorted_u[], const double v[], size_t len) {
+    return statistics::chatterjee_correlation(std::span(sorted_u, len), std::span(v, len));
+}
+
+// boost/math/statistics/linear_regression.hpp
+double math_simple_ordinary_least_squares(
+    const double x[],
+    const double y[],
+    size_t len,
+    double* c1
+) {
+    auto result = statistics::simple_ordinary_least_squares(std::span(x, len), std::span(y, len));
+    return ::detail::unpack(result, c1);
+}
+double math_simple_ordinary_least_squares_with_R_squared(
+    const double x[],
+    const double y[],
+    size_t len,
+    double* c1,
+    double* r_squared
+) {
+    auto [c0, slope, r2] = statistics::simple_ordinary_least_squares_with_R_squared(
+        std::span(x, len),
+        std::span(y, len)
+    );
+    *c1 = slope;
+    *r_squared = r2;
+    return c0;
+}
+
+// boost/math/statistics/ljung_box.hpp
+double math_ljung_box(
+    const double v[],
+    size_t len,
+    int64_t lags,
+    int64_t fit_dof,
+    double* p_value
+) {
+    return ::detail::unpack(statistics::ljung_box(std::span(v, len), lags, fit_dof), p_value);
+}
+
+// boost/math/statistics/runs_test.hpp
+double math_runs_above_and_below_threshold(
+    const double v[],
+    size_t len,
+    double threshold,
+    double* p_value
+) {
+    auto result = statistics::runs_above_and_below_threshold(std::span(v, len), threshold);
+    return ::detail::unpack(result, p_value);
+}
+double math_runs_above_and_below_median(const double v[], size_t len, double* p_value) {
+    // the median partially sorts its input, so it must be a mutable copy
+    auto w = std::vector<double>(v, v + len);
+    return ::detail::unpack(statistics::runs_above_and_below_median(w), p_value);
+}
+
+// boost/math/statistics/signal_statistics.hpp
+double math_absolute_gini_coefficient(double v[], size_t len) {
+    return statistics::absolute_gini_coefficient(v, v + len);
+}
+double math_sample_absolute_gini_coefficient(double v[], size_t len) {
+    return statistics::sample_absolute_gini_coefficient(v, v + len);
+}
+double math_hoyer_sparsity(const double v[], size_t len) {
+    return statistics::hoyer_sparsity(v, v + len);
+}
+double math_oracle_snr(const double signal[], const double noisy_signal[], size_t len) {
+    return statistics::oracle_snr(std::span(signal, len), std::span(noisy_signal, len));
+}
+double math_oracle_snr_db(const double signal[], const double noisy_signal[], size_t len) {
+    return statistics::oracle_snr_db(std::span(signal, len), std::span(noisy_signal, len));
+}
+double math_m2m4_snr_estimator(
+    const double v[],
+    size_t len,
+    double signal_kurtosis,
+    double noise_kurtosis
+) {
+    auto samples = std::span(v, len);
+    return statistics::m2m4_snr_estimator(samples, signal_kurtosis, noise_kurtosis);
+}
+double math_m2m4_snr_estimator_db(
+    const double v[],
+    size_t len,
+    double signal_kurtosis,
+    double noise_kurtosis
+) {
+    auto samples = std::span(v, len);
+    return statistics::m2m4_snr_estimator_db(samples, signal_kurtosis, noise_kurtosis);
+}
+double math_absolute_gini_coefficient_complex(cdouble v[], size_t len) {
+    return statistics::absolute_gini_coefficient(v, v + len);
+}
+double math_sample_absolute_gini_coefficient_complex(cdouble v[], size_t len) {
+    return statistics::sample_absolute_gini_coefficient(v, v + len);
+}
+double math_hoyer_sparsity_complex(const cdouble v[], size_t len) {
+    return statistics::hoyer_sparsity(v, v + len);
+}
+double math_oracle_snr_complex(const cdouble signal[], const cdouble noisy_signal[], size_t len) {
+    return statistics::oracle_snr(std::span(signal, len), std::span(noisy_signal, len));
+}
+double math_oracle_snr_db_complex(
+    const cdouble signal[],
+    const cdouble noisy_signal[],
+    size_t len
+) {
+    return statistics::oracle_snr_db(std::span(signal, len), std::span(noisy_signal, len));
+}
+double math_m2m4_snr_estimator_complex(
+    const cdouble v[],
+    size_t len,
+    double signal_kurtosis,
+    double noise_kurtosis
+) {
+    return ::detail::m2m4_snr_estimator(v, len, signal_kurtosis, noise_kurtosis);
+}
+double math_m2m4_snr_estimator_db_complex(
+    const cdouble v[],
+    size_t len,
+    double signal_kurtosis,
+    double noise_kurtosis
+) {
+    return 10 * std::log10(::detail::m2m4_snr_estimator(v, len, signal_kurtosis, noise_kurtosis));
+}
+
+// boost/math/statistics/t_test.hpp
+double math_one_sample_t_test(const double v[], size_t len, double assumed_mean, double* p_value) {
+    auto result = statistics::one_sample_t_test(std::span(v, len), assumed_mean);
+    return ::detail::unpack(result, p_value);
+}
+double math_two_sample_t_test(
+    const double u[],
+    size_t u_len,
+    const double v[],
+    size_t v_len,
+    double* p_value
+) {
+    auto result = statistics::two_sample_t_test(std::span(u, u_len), std::span(v, v_len));
+    return ::detail::unpack(result, p_value);
+}
+double math_paired_samples_t_test(const double u[], const double v[], size_t len, double* p_value) {
+    auto result = statistics::paired_samples_t_test(std::span(u, len), std::span(v, len));
+    return ::detail::unpack(result, p_value);
+}
+
+// boost/math/statistics/univariate_statistics.hpp
+double math_mean(const double v[], size_t len) { return statistics::mean(std::span(v, len)); }
+double math_variance(const double v[], size_t len) {
+    return statistics::variance(std::span(v, len));
+}
+double math_sample_variance(const double v[], size_t len) {
+    return statistics::sample_variance(std::span(v, len));
+}
+double math_mean_and_sample_variance(const double v[], size_t len, double* var) {
+    return ::detail::unpack(statistics::mean_and_sample_variance(std::span(v, len)), var);
+}
+double math_skewness(const double v[], size_t len) {
+    return statistics::skewness(std::span(v, len));
+}
+double math_kurtosis(const double v[], size_t len) {
+    return statistics::kurtosis(std::span(v, len));
+}
+double math_excess_kurtosis(const double v[], size_t len) {
+    return statistics::excess_kurtosis(std::span(v, len));
+}
+double math_median(double v[], size_t len) {
+    auto samples = std::span(v, len);
+    return statistics::median(samples);
+}
+double math_median_absolute_deviation(double v[], size_t len, double center) {
+    auto samples = std::span(v, len);
+    return statistics::median_absolute_deviation(samples, center);
+}
+double math_interquartile_range(double v[], size_t len) {
+    auto samples = std::span(v, len);
+    return statistics::interquartile_range(samples);
+}
+double math_gini_coefficient(double v[], size_t len) {
+    auto samples = std::span(v, len);
+    return statistics::gini_coefficient(samples);
+}
+double math_sample_gini_coefficient(double v[], size_t len) {
+    auto samples = std::span(v, len);
+    return statistics::sample_gini_coefficient(samples);
+}
+size_t math_mode(double v[], size_t len, double out[]) {
+    // Boost counts the runs of equal values, so they must be adjacent
+    auto samples = std::span(v, len);
+    std::sort(samples.begin(), samples.end());
+    return statistics::mode(samples, out) - out;
+}
+
+// boost/math/statistics/z_test.hpp
+double math_one_sample_z_test(const double v[], size_t len, double assumed_mean, double* p_value) {
+    auto result = statistics::one_sample_z_test(std::span(v, len), assumed_mean);
+    return ::detail::unpack(result, p_value);
+}
+double math_two_sample_z_test(
+    const double u[],
+    size_t u_len,
+    const double v[],
+    size_t v_len,
+    double* p_value
+) {
+    auto result = statistics::two_sample_z_test(std::span(u, u_len), std::span(v, v_len));
+    return ::detail::unpack(result, p_value);
+}
+
+} // extern "C"